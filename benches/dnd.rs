@@ -0,0 +1,163 @@
+//! Benchmarks for [`hcegui::dnd`].
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use hcegui::dnd::Dnd;
+
+const LIST_SIZES: [usize; 3] = [10, 100, 1000];
+
+/// Headless egui context that feeds simulated pointer input.
+struct Frames {
+    ctx: egui::Context,
+    time: f64,
+}
+
+impl Frames {
+    fn new() -> Self {
+        Self {
+            ctx: egui::Context::default(),
+            time: 0.0,
+        }
+    }
+
+    /// Runs one frame with `events` as input.
+    fn run(&mut self, events: Vec<egui::Event>, mut add_contents: impl FnMut(&mut egui::Ui)) {
+        self.time += 1.0 / 60.0;
+        let input = egui::RawInput {
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                egui::vec2(800.0, 100_000.0),
+            )),
+            time: Some(self.time),
+            events,
+            ..Default::default()
+        };
+        let _ = self.ctx.run_ui(input, |ui| add_contents(ui));
+    }
+}
+
+fn pointer_button(pos: egui::Pos2, pressed: bool) -> egui::Event {
+    egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    }
+}
+
+/// Shows a reorderable list of `items`, returning the rectangle of the first
+/// item and whether an item is being dragged.
+fn reorderable_list(ui: &mut egui::Ui, items: &mut [usize]) -> (egui::Rect, bool) {
+    let mut first_rect = egui::Rect::NOTHING;
+    let mut dnd = Dnd::new(ui.ctx(), "list");
+    let is_dragging = dnd.is_dragging();
+    for (i, &item) in items.iter().enumerate() {
+        let r = dnd.reorderable(ui, i, |ui, _| (ui.label(item.to_string()), ()));
+        if i == 0 {
+            first_rect = r.response.rect;
+        }
+    }
+    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+        r.reorder(items);
+    }
+    (first_rect, is_dragging)
+}
+
+/// Per-frame overhead of a reorderable list when nothing is being dragged.
+fn bench_idle(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dnd/idle");
+    for n in LIST_SIZES {
+        let mut frames = Frames::new();
+        let mut items: Vec<usize> = (0..n).collect();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter(|| frames.run(vec![], |ui| _ = reorderable_list(ui, &mut items)));
+        });
+    }
+    group.finish();
+}
+
+/// Per-frame overhead of a reorderable list while dragging, including the
+/// search for the nearest reorder line.
+fn bench_dragging(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dnd/dragging");
+    for n in LIST_SIZES {
+        let mut frames = Frames::new();
+        let mut items: Vec<usize> = (0..n).collect();
+
+        // Lay out the list, then start dragging the first item.
+        let mut first_rect = egui::Rect::NOTHING;
+        frames.run(vec![], |ui| first_rect = reorderable_list(ui, &mut items).0);
+        let start = first_rect.center();
+        frames.run(vec![egui::Event::PointerMoved(start)], |ui| {
+            _ = reorderable_list(ui, &mut items);
+        });
+        frames.run(vec![pointer_button(start, true)], |ui| {
+            _ = reorderable_list(ui, &mut items);
+        });
+        for dy in [10.0, 20.0, 30.0] {
+            let pos = start + egui::vec2(0.0, dy);
+            frames.run(vec![egui::Event::PointerMoved(pos)], |ui| {
+                _ = reorderable_list(ui, &mut items);
+            });
+        }
+
+        let mut is_dragging = false;
+        frames.run(vec![], |ui| {
+            is_dragging = reorderable_list(ui, &mut items).1;
+        });
+        assert!(is_dragging, "drag did not start");
+
+        let mut dy = 0.0;
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, _| {
+            b.iter(|| {
+                // Move the pointer around so that the target changes.
+                dy = (dy + 7.0) % (n as f32 * first_rect.height());
+                let pos = start + egui::vec2(0.0, dy);
+                frames.run(vec![egui::Event::PointerMoved(pos)], |ui| {
+                    _ = reorderable_list(ui, &mut items);
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+/// Per-frame overhead of non-reorder drop zones while dragging.
+fn bench_drop_zones(c: &mut Criterion) {
+    let mut group = c.benchmark_group("dnd/drop_zones");
+    for m in LIST_SIZES {
+        let mut frames = Frames::new();
+        let show = |ui: &mut egui::Ui| {
+            let mut dnd = Dnd::<(), usize>::new(ui.ctx(), "drop_zones");
+            let r = dnd.draggable(ui, (), |ui, _| (ui.label("payload"), ()));
+            for i in 0..m {
+                let r = ui.label(i.to_string());
+                dnd.drop_zone(ui, &r, i);
+            }
+            _ = dnd.finish(ui);
+            r.response.rect
+        };
+
+        let mut payload_rect = egui::Rect::NOTHING;
+        frames.run(vec![], |ui| payload_rect = show(ui));
+        let start = payload_rect.center();
+        frames.run(vec![egui::Event::PointerMoved(start)], |ui| _ = show(ui));
+        frames.run(vec![pointer_button(start, true)], |ui| _ = show(ui));
+        for dy in [10.0, 20.0, 30.0] {
+            let pos = start + egui::vec2(0.0, dy);
+            frames.run(vec![egui::Event::PointerMoved(pos)], |ui| _ = show(ui));
+        }
+
+        let mut dy = 0.0;
+        group.bench_with_input(BenchmarkId::from_parameter(m), &m, |b, _| {
+            b.iter(|| {
+                dy = (dy + 7.0) % (m as f32 * payload_rect.height());
+                let pos = start + egui::vec2(0.0, dy);
+                frames.run(vec![egui::Event::PointerMoved(pos)], |ui| _ = show(ui));
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_idle, bench_dragging, bench_drop_zones);
+criterion_main!(benches);
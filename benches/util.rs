@@ -0,0 +1,85 @@
+//! Benchmarks for the sizing-pass helpers in [`hcegui::util`].
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use hcegui::util;
+
+const WIDGET_COUNTS: [usize; 3] = [10, 100, 1000];
+
+/// Runs one frame in a headless egui context.
+fn run_frame(ctx: &egui::Context, mut add_contents: impl FnMut(&mut egui::Ui)) {
+    let input = egui::RawInput {
+        screen_rect: Some(egui::Rect::from_min_size(
+            egui::Pos2::ZERO,
+            egui::vec2(800.0, 600.0),
+        )),
+        ..Default::default()
+    };
+    let _ = ctx.run_ui(input, |ui| add_contents(ui));
+}
+
+fn buttons(ui: &mut egui::Ui, n: usize) {
+    for i in 0..n {
+        _ = ui.button(format!("Button {i}"));
+    }
+}
+
+fn bench_non_wrapping_size_of_ui(c: &mut Criterion) {
+    let mut group = c.benchmark_group("util/non_wrapping_size_of_ui");
+    for n in WIDGET_COUNTS {
+        let ctx = egui::Context::default();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| {
+                run_frame(&ctx, |ui| {
+                    _ = util::non_wrapping_size_of_ui(ui, |ui| buttons(ui, n));
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_show_on_one_line(c: &mut Criterion) {
+    let mut group = c.benchmark_group("util/show_on_one_line");
+    for n in WIDGET_COUNTS {
+        let ctx = egui::Context::default();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| {
+                run_frame(&ctx, |ui| {
+                    ui.horizontal_wrapped(|ui| {
+                        for _ in 0..10 {
+                            util::show_on_one_line(ui, |ui| buttons(ui, n / 10));
+                        }
+                    });
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_wrap_group(c: &mut Criterion) {
+    let mut group = c.benchmark_group("util/wrap_group");
+    for n in WIDGET_COUNTS {
+        let ctx = egui::Context::default();
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, &n| {
+            b.iter(|| {
+                run_frame(&ctx, |ui| {
+                    ui.horizontal_wrapped(|ui| {
+                        for _ in 0..10 {
+                            util::wrap_group(ui, |ui| buttons(ui, n / 10));
+                        }
+                    });
+                });
+            });
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_non_wrapping_size_of_ui,
+    bench_show_on_one_line,
+    bench_wrap_group,
+);
+criterion_main!(benches);
@@ -0,0 +1,176 @@
+//! Displaying log output containing ANSI escape codes using
+//! [`hcegui::ansi::AnsiLabel`].
+//!
+//! Run with `cargo run -F demo --example ansi_log`.
+
+use hcegui::ansi::AnsiLabel;
+
+/// Lines emitted by the simulated process, in order.
+const PROCESS_OUTPUT: &[&str] = &[
+    "\x1b[38;5;10m   Compiling\x1b[0m proc-macro2 v1.0.95",
+    "\x1b[38;5;10m   Compiling\x1b[0m unicode-ident v1.0.18",
+    "\x1b[38;5;10m   Compiling\x1b[0m quote v1.0.40",
+    "\x1b[38;5;10m   Compiling\x1b[0m syn v2.0.104",
+    "\x1b[33mwarning\x1b[0m: unused variable: `x`",
+    "\x1b[38;5;12m --> \x1b[0msrc/main.rs:4:9",
+    "\x1b[38;5;12m  |\x1b[0m",
+    "\x1b[38;5;12m4 |\x1b[0m     let x = 5;",
+    "\x1b[38;5;12m  |\x1b[0m         \x1b[33m^\x1b[0m \x1b[33mhelp: if this is intentional, prefix it with an underscore: `_x`\x1b[0m",
+    "\x1b[38;5;10m   Compiling\x1b[0m serde v1.0.219",
+    "\x1b[38;5;10m   Compiling\x1b[0m my-app v0.1.0",
+    "\x1b[31merror\x1b[0m: mismatched types",
+    "\x1b[38;5;12m --> \x1b[0msrc/lib.rs:10:5",
+    "\x1b[38;5;12m   |\x1b[0m",
+    "\x1b[38;5;12m10 |\x1b[0m     \"hello\"",
+    "\x1b[38;5;12m   |\x1b[0m     \x1b[31m^^^^^^^ expected `i32`, found `&str`\x1b[0m",
+    "\x1b[38;5;244mgrayscale\x1b[0m \x1b[38;5;232m█\x1b[38;5;240m█\x1b[38;5;248m█\x1b[38;5;255m█\x1b[0m",
+    "\x1b[31merror\x1b[0m: could not compile `my-app` (lib) due to 1 previous error",
+];
+
+/// Seconds between lines of simulated process output.
+const LINE_INTERVAL: f64 = 0.25;
+
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+enum Palette {
+    #[default]
+    FollowApp,
+    Dark,
+    Light,
+}
+
+/// State of the ANSI log example.
+pub struct AnsiLogExample {
+    pasted: String,
+    feed: Vec<&'static str>,
+    feed_running: bool,
+    last_line_time: Option<f64>,
+    palette: Palette,
+    wrap: bool,
+    stick_to_bottom: bool,
+}
+
+impl Default for AnsiLogExample {
+    fn default() -> Self {
+        Self {
+            pasted: "Paste a log here, or type \\x1b[31mred\\x1b[0m text.\n\
+                     \x1b[31mred\x1b[0m \x1b[33myellow\x1b[0m \x1b[38;5;208morange\x1b[0m"
+                .to_owned(),
+            feed: vec![],
+            feed_running: false,
+            last_line_time: None,
+            palette: Palette::default(),
+            wrap: true,
+            stick_to_bottom: true,
+        }
+    }
+}
+
+impl AnsiLogExample {
+    /// Displays the example.
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Palette:");
+            ui.selectable_value(&mut self.palette, Palette::FollowApp, "Follow app");
+            ui.selectable_value(&mut self.palette, Palette::Dark, "Dark");
+            ui.selectable_value(&mut self.palette, Palette::Light, "Light");
+            ui.separator();
+            ui.checkbox(&mut self.wrap, "Wrap lines");
+            ui.checkbox(&mut self.stick_to_bottom, "Stick to bottom");
+        });
+
+        ui.separator();
+
+        ui.columns(2, |uis| {
+            let ui = &mut uis[0];
+            ui.heading("Paste a log");
+            // Allow typing escape codes as `\x1b`.
+            let rendered = self.pasted.replace("\\x1b", "\x1b");
+            egui::ScrollArea::vertical()
+                .id_salt("pasted_input")
+                .max_height(ui.available_height() / 2.0)
+                .show(ui, |ui| {
+                    ui.add(
+                        egui::TextEdit::multiline(&mut self.pasted)
+                            .code_editor()
+                            .desired_width(f32::INFINITY),
+                    );
+                });
+            self.show_output(ui, "pasted_output", |ui| {
+                ui.add(AnsiLabel::new(&rendered));
+            });
+
+            let ui = &mut uis[1];
+            ui.heading("Simulated process");
+            ui.horizontal(|ui| {
+                let text = if self.feed_running { "Pause" } else { "Start" };
+                if ui.button(text).clicked() {
+                    self.feed_running = !self.feed_running;
+                    self.last_line_time = None;
+                }
+                if ui.button("Clear").clicked() {
+                    self.feed.clear();
+                }
+            });
+            if self.feed_running {
+                self.step_feed(ui.ctx());
+            }
+            let feed = &self.feed;
+            self.show_output(ui, "process_output", |ui| {
+                for line in feed {
+                    ui.add(AnsiLabel::new(line));
+                }
+            });
+        });
+    }
+
+    fn step_feed(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|input| input.time);
+        let last = *self.last_line_time.get_or_insert(now);
+        if now - last >= LINE_INTERVAL {
+            self.feed
+                .push(PROCESS_OUTPUT[self.feed.len() % PROCESS_OUTPUT.len()]);
+            self.last_line_time = Some(now);
+        }
+        ctx.request_repaint_after_secs(LINE_INTERVAL as f32);
+    }
+
+    fn show_output(
+        &self,
+        ui: &mut egui::Ui,
+        id_salt: &str,
+        add_contents: impl FnOnce(&mut egui::Ui),
+    ) {
+        ui.scope(|ui| {
+            match self.palette {
+                Palette::FollowApp => (),
+                Palette::Dark => *ui.visuals_mut() = egui::Visuals::dark(),
+                Palette::Light => *ui.visuals_mut() = egui::Visuals::light(),
+            }
+            ui.style_mut().wrap_mode = Some(if self.wrap {
+                egui::TextWrapMode::Wrap
+            } else {
+                egui::TextWrapMode::Extend
+            });
+
+            egui::Frame::canvas(ui.style()).show(ui, |ui| {
+                egui::ScrollArea::both()
+                    .id_salt(id_salt)
+                    .auto_shrink(false)
+                    .stick_to_bottom(self.stick_to_bottom)
+                    .show(ui, add_contents);
+            });
+        });
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result {
+    let mut example = AnsiLogExample::default();
+    eframe::run_ui_native(
+        "ansi_log example",
+        eframe::NativeOptions::default(),
+        move |ui, _frame| {
+            egui::CentralPanel::default().show_inside(ui, |ui| example.show(ui));
+        },
+    )
+}
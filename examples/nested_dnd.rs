@@ -0,0 +1,154 @@
+//! Dragging items between lists that can themselves be reordered, using two
+//! nested [`hcegui::dnd::Dnd`]s, and the same thing as a kanban board using
+//! [`hcegui::dnd::kanban::Kanban`].
+//!
+//! Run with `cargo run -F demo --example nested_dnd`.
+
+use egui::Widget;
+use hcegui::dnd;
+
+/// State of the nested drag-and-drop example.
+pub struct NestedDndExample {
+    lists: Vec<Vec<&'static str>>,
+    board: Vec<Column>,
+}
+
+/// Column of a kanban board.
+struct Column {
+    title: &'static str,
+    cards: Vec<&'static str>,
+}
+impl AsRef<[&'static str]> for Column {
+    fn as_ref(&self) -> &[&'static str] {
+        &self.cards
+    }
+}
+impl AsMut<Vec<&'static str>> for Column {
+    fn as_mut(&mut self) -> &mut Vec<&'static str> {
+        &mut self.cards
+    }
+}
+
+impl Default for NestedDndExample {
+    fn default() -> Self {
+        Self {
+            lists: vec![
+                vec!["akesi", "soweli", "kala", "waso"],
+                vec!["reptile", "dog", "fish", "bird"],
+                vec!["snek", "doggo", "fishy", "birb"],
+                vec![],
+                vec!["The quick, brown fox jumps over the lazy dog."],
+                vec!["The horse is a noble animal."],
+                vec![],
+            ],
+            board: vec![
+                Column {
+                    title: "To do",
+                    cards: vec!["Write docs", "Fix scrolling"],
+                },
+                Column {
+                    title: "Doing",
+                    cards: vec!["Kanban board"],
+                },
+                Column {
+                    title: "Done",
+                    cards: vec![],
+                },
+            ],
+        }
+    }
+}
+
+impl NestedDndExample {
+    /// Displays the example.
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.style_mut().interaction.selectable_labels = false;
+        ui.style_mut().spacing.scroll = egui::style::ScrollStyle::solid();
+
+        let lists = &mut self.lists;
+        let mut row_dnd = dnd::Dnd::new(ui.ctx(), "rows");
+        let mut item_dnd = dnd::CrossListDnd::new(ui.ctx(), "items");
+        let mut index_to_delete = None;
+
+        // Display items
+        for (i, list) in lists.iter_mut().enumerate() {
+            row_dnd.reorderable_with_handle(ui, i, |ui, _| {
+                let r = egui::ScrollArea::horizontal()
+                    .auto_shrink(false)
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            for (j, &item) in list.iter().enumerate() {
+                                let r = item_dnd.draggable(ui, (i, j), |ui, _| {
+                                    (egui::Label::new(item).sense(egui::Sense::drag()).ui(ui), ())
+                                });
+                                item_dnd.reorder_drop_zone_before_after(
+                                    ui,
+                                    &r.response,
+                                    (i, Some(j)),
+                                );
+                            }
+
+                            // Delete button
+                            if list.is_empty()
+                                && !item_dnd.is_dragging()
+                                && ui.button(egui::RichText::new("🗑").small()).clicked()
+                            {
+                                index_to_delete = Some(i);
+                            }
+                        });
+                    });
+                let r = ui.interact(r.inner_rect, r.id.with(1), egui::Sense::empty());
+                if list.is_empty() {
+                    item_dnd.drop_zone(ui, &r, ((i, None), dnd::BeforeOrAfter::Before));
+                }
+            });
+        }
+        if ui.button("Add list").clicked() {
+            lists.push(vec![]);
+        }
+
+        // Reorder individual items
+        if let Some(r) = item_dnd.finish(ui).if_done_dragging() {
+            r.move_between(lists);
+        }
+
+        // Reorder whole lists
+        if let Some(r) = row_dnd.finish(ui).if_done_dragging() {
+            r.reorder(lists);
+        }
+
+        // Delete empty list
+        if let Some(i) = index_to_delete {
+            lists.remove(i);
+        }
+
+        ui.separator();
+
+        // Kanban board
+        dnd::kanban::Kanban::new("board")
+            .column_width(120.0)
+            .show(
+                ui,
+                &self.board,
+                |ui, _, column| {
+                    ui.strong(column.title);
+                },
+                |ui, &card| {
+                    ui.label(card);
+                },
+            )
+            .apply(&mut self.board);
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result {
+    let mut example = NestedDndExample::default();
+    eframe::run_ui_native(
+        "nested_dnd example",
+        eframe::NativeOptions::default(),
+        move |ui, _frame| {
+            egui::CentralPanel::default().show_inside(ui, |ui| example.show(ui));
+        },
+    )
+}
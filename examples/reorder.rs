@@ -0,0 +1,316 @@
+//! Reordering lists using [`hcegui::dnd`], with and without drag handles.
+//!
+//! Run with `cargo run -F demo --example reorder`.
+
+use std::collections::HashMap;
+
+use hcegui::dnd;
+
+/// State of the reordering example.
+pub struct ReorderExample {
+    poem: Vec<&'static str>,
+    reorder_gap: bool,
+    settle_animation: bool,
+    snap_back_animation: bool,
+    live_preview: bool,
+    keyboard_layouts: Vec<(&'static str, &'static str)>,
+    planets: Vec<&'static str>,
+    selected_planets: Vec<usize>,
+    months: Vec<&'static str>,
+    hotbar: Vec<&'static str>,
+    tabs: Vec<&'static str>,
+    active_tab: usize,
+    elements: Vec<(&'static str, &'static str)>,
+    /// Children of each node, with `None` for the root.
+    outline: HashMap<Option<&'static str>, Vec<&'static str>>,
+}
+
+impl Default for ReorderExample {
+    fn default() -> Self {
+        Self {
+            poem: vec![
+                "Pointless machines",
+                "Resurrections",
+                "Scattered and lost",
+                "Eye of the storm",
+                "Heavy and frail",
+                "Quiet and falling",
+                "Pink sunrise",
+                "Heart of the mountain",
+                "Sever the skyline",
+                "Black moonrise",
+                "Good karma",
+                "Golden feather",
+                "Mirror magic",
+                "Center of the earth",
+                "No more running",
+                "Say goodbye",
+            ],
+            reorder_gap: true,
+            settle_animation: true,
+            snap_back_animation: true,
+            live_preview: false,
+
+            keyboard_layouts: vec![
+                ("QWERTY", "QWERTYUIOP\nASDFGHJKL;\nZXCVBNM,./"),
+                ("Colemak", "QWFPGJLUY;\nARSTDHNEIO\nZXCVBKM,./"),
+                ("Dvorak", "',.PYFGCRL\nAOEUIDHTNS\n;QJKXBMWVZ"),
+                ("Workman", "QDRWBJFUP;\nASHTGYNEOI\nZXMCVKL,./"),
+            ],
+
+            planets: vec![
+                "Mercury", "Venus", "Earth", "Mars", "Jupiter", "Saturn", "Uranus", "Neptune",
+            ],
+            selected_planets: vec![1, 3],
+
+            months: vec![
+                "January",
+                "February",
+                "March",
+                "April",
+                "May",
+                "June",
+                "July",
+                "August",
+                "September",
+                "October",
+                "November",
+                "December",
+            ],
+
+            hotbar: vec!["Sword", "Bow", "Torch", "Map", "Bread"],
+
+            tabs: vec!["Overview", "Details", "History", "Settings"],
+            active_tab: 0,
+
+            elements: vec![
+                ("H", "Hydrogen"),
+                ("He", "Helium"),
+                ("Li", "Lithium"),
+                ("Be", "Beryllium"),
+            ],
+
+            outline: HashMap::from([
+                (None, vec!["Fruits", "Vegetables"]),
+                (Some("Fruits"), vec!["Apple", "Banana", "Cherry"]),
+                (Some("Vegetables"), vec!["Carrot", "Potato"]),
+            ]),
+        }
+    }
+}
+
+impl ReorderExample {
+    /// Displays the example.
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.style_mut().interaction.selectable_labels = false;
+        ui.visuals_mut().collapsing_header_frame = true;
+
+        // Reordering with handles, in a scroll area that scrolls automatically
+        // when dragging near its edges
+        ui.heading("Reorder with handles");
+        ui.checkbox(&mut self.reorder_gap, "Open a gap at the insertion point");
+        ui.checkbox(&mut self.settle_animation, "Animate items into place");
+        ui.checkbox(
+            &mut self.snap_back_animation,
+            "Animate back when cancelled with Escape",
+        );
+        ui.checkbox(
+            &mut self.live_preview,
+            "Move items out of the way while dragging",
+        );
+        let style = dnd::DndStyle {
+            reorder_gap: self.reorder_gap,
+            settle_animation: self.settle_animation,
+            snap_back_animation: self.snap_back_animation,
+            ..Default::default()
+        };
+        egui::ScrollArea::vertical()
+            .id_salt("poem")
+            .max_height(160.0)
+            .show(ui, |ui| {
+                let mut dnd = dnd::Dnd::new(ui.ctx(), "poem")
+                    .with_style(style)
+                    .with_live_preview(self.live_preview);
+                for i in dnd.preview_order(self.poem.len()) {
+                    dnd.reorderable_with_handle(ui, i, |ui, _| ui.label(self.poem[i]));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(&mut self.poem);
+                }
+            });
+
+        ui.separator();
+
+        // Reordering with no handles
+        ui.heading("Reorder with no handles");
+        let mut dnd = dnd::Dnd::new(ui.ctx(), "keyboard_layouts");
+        let is_dragging = dnd.is_dragging();
+        for (i, &(name, details)) in self.keyboard_layouts.iter().enumerate() {
+            dnd.reorderable(ui, i, |ui, _| {
+                let r = egui::CollapsingHeader::new(name)
+                    .open(is_dragging.then_some(false))
+                    .show(ui, |ui| ui.code(details));
+                (r.header_response, ())
+            });
+        }
+        if let Some(r) = dnd.finish(ui).if_done_dragging() {
+            r.reorder(&mut self.keyboard_layouts);
+        }
+
+        ui.separator();
+
+        // Reordering several selected items at once
+        ui.heading("Reorder selection");
+        let mut dnd = dnd::MultiReorderDnd::new(ui.ctx(), "planets");
+        let mut toggled = None;
+        for (i, &planet) in self.planets.iter().enumerate() {
+            let selection = &self.selected_planets;
+            dnd.reorderable_multi(ui, i, selection, |ui, _| {
+                ui.horizontal(|ui| {
+                    let handle = ui.add(dnd::ReorderHandle::new());
+                    let mut is_selected = selection.contains(&i);
+                    if ui.checkbox(&mut is_selected, planet).changed() {
+                        toggled = Some(i);
+                    }
+                    (handle, ())
+                })
+                .inner
+            });
+        }
+        if let Some(i) = toggled {
+            if let Some(pos) = self.selected_planets.iter().position(|&j| j == i) {
+                self.selected_planets.remove(pos);
+            } else {
+                self.selected_planets.push(i);
+            }
+        }
+        if let Some(r) = dnd.finish(ui).if_done_dragging() {
+            self.selected_planets = r.reorder(&mut self.planets).collect();
+        }
+
+        ui.separator();
+
+        // Reordering in a wrapped layout
+        ui.heading("Reorder in a grid");
+        ui.horizontal_wrapped(|ui| {
+            let mut dnd = dnd::Dnd::new(ui.ctx(), "months");
+            for (i, &month) in self.months.iter().enumerate() {
+                dnd.grid_reorderable(ui, i, |ui, _| {
+                    let r = egui::Frame::group(ui.style())
+                        .show(ui, |ui| ui.add(egui::Label::new(month).extend()));
+                    (r.response, ())
+                });
+            }
+            if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                r.reorder(&mut self.months);
+            }
+        });
+
+        ui.separator();
+
+        // Swapping items in fixed slots
+        ui.heading("Swap slots");
+        ui.horizontal(|ui| {
+            let mut dnd = dnd::SwapDnd::new(ui.ctx(), "hotbar");
+            for (i, &item) in self.hotbar.iter().enumerate() {
+                dnd.swappable(ui, i, |ui, _| {
+                    let r = egui::Frame::group(ui.style()).show(ui, |ui| {
+                        ui.add_sized([48.0, 24.0], egui::Label::new(item).extend())
+                    });
+                    (r.response, ())
+                });
+            }
+            if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                r.swap(&mut self.hotbar);
+            }
+        });
+
+        ui.separator();
+
+        // Tabs that can be selected, reordered, and closed
+        ui.heading("Reorder tabs");
+        let r = dnd::tab_bar::TabBar::new("tabs").closable(true).show(
+            ui,
+            &self.tabs,
+            self.active_tab,
+            |&tab| tab.into(),
+        );
+        r.apply(&mut self.tabs, &mut self.active_tab);
+        match self.tabs.get(self.active_tab) {
+            Some(tab) => ui.label(format!("Contents of {tab}")),
+            None => ui.weak("No tabs open"),
+        };
+
+        ui.separator();
+
+        // Reordering rows of a table
+        ui.heading("Reorder table rows");
+        let mut dnd = dnd::Dnd::new(ui.ctx(), "elements");
+        egui_extras::TableBuilder::new(ui)
+            .striped(true)
+            .column(egui_extras::Column::auto())
+            .columns(egui_extras::Column::auto().at_least(60.0), 2)
+            .body(|mut body| {
+                for (i, &(symbol, name)) in self.elements.iter().enumerate() {
+                    body.row(20.0, |mut row| {
+                        dnd.reorderable_table_row(&mut row, i, |row| {
+                            row.col(|ui| {
+                                ui.strong(symbol);
+                            });
+                            row.col(|ui| {
+                                ui.label(name);
+                            });
+                        });
+                    });
+                }
+            });
+        if let Some(r) = dnd.finish(ui).if_done_dragging() {
+            r.reorder(&mut self.elements);
+        }
+
+        ui.separator();
+
+        // Reordering and nesting nodes in a tree
+        ui.heading("Reorder a tree");
+        let mut dnd = dnd::TreeDnd::new(ui.ctx(), "outline");
+        show_outline(ui, &mut dnd, &self.outline, None);
+        if let Some(r) = dnd.finish(ui).if_done_dragging() {
+            let m = r.tree_move();
+            self.outline
+                .entry(m.old_parent)
+                .or_default()
+                .remove(m.old_index);
+            (self.outline.entry(m.new_parent).or_default()).insert(m.index, m.node);
+        }
+    }
+}
+
+/// Displays the children of `parent` in the tree example, recursively.
+fn show_outline(
+    ui: &mut egui::Ui,
+    dnd: &mut dnd::TreeDnd<&'static str>,
+    outline: &HashMap<Option<&'static str>, Vec<&'static str>>,
+    parent: Option<&'static str>,
+) {
+    for (index, &node) in outline.get(&parent).into_iter().flatten().enumerate() {
+        let tree_node = dnd::TreeNode {
+            node,
+            parent,
+            index,
+        };
+        dnd.tree_node(ui, tree_node, |ui, _| (ui.label(node), ()));
+        ui.indent(node, |ui| show_outline(ui, dnd, outline, Some(node)));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result {
+    let mut example = ReorderExample::default();
+    eframe::run_ui_native(
+        "reorder example",
+        eframe::NativeOptions::default(),
+        move |ui, _frame| {
+            egui::CentralPanel::default().show_inside(ui, |ui| example.show(ui));
+        },
+    )
+}
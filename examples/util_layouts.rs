@@ -0,0 +1,180 @@
+//! Layout helpers from [`hcegui::util`]. Resize each section to see how its
+//! contents wrap.
+//!
+//! Run with `cargo run -F demo --example util_layouts`.
+
+use hcegui::util::{self, AutoFlowGrid};
+
+/// State of the layout helpers example.
+pub struct UtilLayoutsExample {
+    scale: f64,
+    options: [bool; 4],
+}
+
+impl Default for UtilLayoutsExample {
+    fn default() -> Self {
+        Self {
+            scale: 1.0,
+            options: [false; 4],
+        }
+    }
+}
+
+impl UtilLayoutsExample {
+    /// Displays the example.
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        egui::ScrollArea::vertical()
+            .auto_shrink(false)
+            .show(ui, |ui| {
+                section(ui, "show_on_one_line()", show_on_one_line_demo);
+                section(ui, "wrap_group()", |ui| self.wrap_group_demo(ui));
+                section(ui, "wrapped_separator()", wrapped_separator_demo);
+                section(ui, "baseline_aligned()", |ui| {
+                    self.baseline_aligned_demo(ui);
+                });
+                section(ui, "kv_table()", kv_table_demo);
+                section(ui, "AutoFlowGrid", auto_flow_grid_demo);
+            });
+    }
+
+    fn wrap_group_demo(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            for (i, checked) in self.options.iter_mut().enumerate() {
+                util::wrap_group(ui, |ui| {
+                    ui.checkbox(checked, format!("Option {i}"));
+                    ui.weak("(explanation of this option)");
+                });
+            }
+            util::wrap_group(ui, |ui| {
+                ui.label("Nested:");
+                util::wrap_group(ui, |ui| {
+                    ui.label("🔔");
+                    #[allow(unused_must_use)]
+                    ui.button("Notify");
+                });
+            });
+        });
+    }
+
+    fn baseline_aligned_demo(&mut self, ui: &mut egui::Ui) {
+        ui.label("Vertically centered:");
+        ui.horizontal(|ui| {
+            ui.label("Scale");
+            ui.add(egui::DragValue::new(&mut self.scale));
+            if ui.small_button("reset").clicked() {
+                self.scale = 1.0;
+            }
+            ui.label(egui::RichText::new("(big)").heading());
+        });
+
+        ui.label("Baseline aligned:");
+        util::baseline_aligned(ui, |row| {
+            row.label("Scale");
+            row.add(
+                egui::TextStyle::Button,
+                egui::DragValue::new(&mut self.scale),
+            );
+            if row.small_button("reset").clicked() {
+                self.scale = 1.0;
+            }
+            row.add(
+                egui::TextStyle::Heading,
+                egui::Label::new(egui::RichText::new("(big)").heading()),
+            );
+        });
+    }
+}
+
+/// Shows a resizable section with a heading.
+fn section(ui: &mut egui::Ui, title: &str, add_contents: impl FnOnce(&mut egui::Ui)) {
+    ui.heading(title);
+    egui::Resize::default()
+        .id_salt(title)
+        .default_width(ui.available_width())
+        .max_height(0.0)
+        .show(ui, add_contents);
+    ui.separator();
+}
+
+fn show_on_one_line_demo(ui: &mut egui::Ui) {
+    ui.horizontal_wrapped(|ui| {
+        #[allow(unused_must_use)]
+        util::show_on_one_line(ui, |ui| {
+            ui.button("This was a triumph");
+            ui.button("I'm making a note here; \"Huge success\"");
+            ui.button("It's hard to overstate");
+            ui.button("My satisfaction");
+        });
+        #[allow(unused_must_use)]
+        util::show_on_one_line(ui, |ui| {
+            ui.button("Aperture Science:");
+            ui.button("We do what we must");
+            ui.button("Because we can");
+        });
+        #[allow(unused_must_use)]
+        util::show_on_one_line(ui, |ui| {
+            ui.button("For the good of all of us");
+            ui.button("Except the ones who are dead");
+        });
+    });
+}
+
+fn wrapped_separator_demo(ui: &mut egui::Ui) {
+    ui.horizontal_wrapped(|ui| {
+        for word in "Fruits: apple banana cherry durian".split(' ') {
+            ui.label(word);
+        }
+        util::wrapped_separator(ui);
+        for word in "Vegetables: carrot potato squash".split(' ') {
+            ui.label(word);
+        }
+        ui.separator();
+        ui.label("(normal separator)");
+    });
+}
+
+fn kv_table_demo(ui: &mut egui::Ui) {
+    util::kv_table(
+        ui,
+        [
+            ("Name", env!("CARGO_PKG_NAME")),
+            ("Version", env!("CARGO_PKG_VERSION")),
+            ("License", env!("CARGO_PKG_LICENSE")),
+            ("Repository", env!("CARGO_PKG_REPOSITORY")),
+            ("Description", env!("CARGO_PKG_DESCRIPTION")),
+        ],
+    );
+}
+
+fn auto_flow_grid_demo(ui: &mut egui::Ui) {
+    const EMOJI: &[&str] = &[
+        "🍎", "🍌", "🍒", "🍇", "🍉", "🍋", "🍑", "🍍", "🍓", "🍅", "🥑", "🥕", "🌽", "🥔",
+    ];
+
+    ui.label("Fixed cell width:");
+    AutoFlowGrid::new(64.0).show(ui, EMOJI.len(), |ui, i| {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            ui.set_width(ui.available_width());
+            ui.vertical_centered(|ui| ui.heading(EMOJI[i]));
+            ui.vertical_centered(|ui| ui.label(format!("#{i}")));
+        });
+    });
+
+    ui.label("Measured cell width:");
+    AutoFlowGrid::measured().show(ui, 12, |ui, i| {
+        #[allow(unused_must_use)]
+        ui.button(format!("Button {}", i * i));
+    });
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result {
+    let mut example = UtilLayoutsExample::default();
+    eframe::run_ui_native(
+        "util_layouts example",
+        eframe::NativeOptions::default(),
+        move |ui, _frame| {
+            egui::CentralPanel::default().show_inside(ui, |ui| example.show(ui));
+        },
+    )
+}
@@ -0,0 +1,106 @@
+//! Accessibility helpers.
+//!
+//! Preferences such as [reduced motion](set_reduced_motion) and [high
+//! contrast](set_high_contrast) are not detected automatically; set them from
+//! your app's settings and hcegui widgets will respect them.
+//!
+//! [`announce()`] reaches screen readers through AccessKit, which must be
+//! enabled in your integration. eframe enables it when a screen reader is
+//! running.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::a11y;
+//!
+//! let (rect, r) = ui.allocate_exact_size(egui::vec2(16.0, 16.0), egui::Sense::click());
+//! a11y::describe(&r, egui::WidgetType::Button, "Toggle sidebar");
+//! ui.painter().circle_filled(rect.center(), 6.0, ui.visuals().text_color());
+//! a11y::paint_focus_outline(ui, &r);
+//! if r.clicked() {
+//!     a11y::announce(ui.ctx(), "Sidebar hidden");
+//! }
+//! # });
+//! ```
+
+pub use crate::anim::{reduced_motion, set_reduced_motion};
+
+fn high_contrast_id() -> egui::Id {
+    egui::Id::new("hcegui::a11y::high_contrast")
+}
+
+/// Sets whether widgets should use stronger colors and thicker outlines, such
+/// as for users with low vision. Default: `false`.
+pub fn set_high_contrast(ctx: &egui::Context, high_contrast: bool) {
+    ctx.data_mut(|data| data.insert_temp(high_contrast_id(), high_contrast));
+}
+
+/// Returns whether widgets should use stronger colors and thicker outlines. See
+/// [`set_high_contrast()`].
+pub fn high_contrast(ctx: &egui::Context) -> bool {
+    ctx.data(|data| data.get_temp(high_contrast_id()))
+        .unwrap_or(false)
+}
+
+fn announcement_id() -> egui::Id {
+    egui::Id::new("hcegui::a11y::announcement")
+}
+
+/// Announces `text` to screen readers without moving focus, such as after an
+/// item is moved or deleted.
+///
+/// The announcement is added to the AccessKit tree as a polite live region,
+/// which native screen readers read once it appears, and multiple
+/// announcements in the same frame are read together. It is also added to
+/// [`egui::PlatformOutput::events`], which is read by the text-to-speech in
+/// eframe on the web (see [`egui::Options::screen_reader`]).
+pub fn announce(ctx: &egui::Context, text: impl ToString) {
+    let text = text.to_string();
+    ctx.accesskit_node_builder(announcement_id(), |node| {
+        let value = match node.value() {
+            Some(previous) => format!("{previous}\n{text}"),
+            None => text.clone(),
+        };
+        node.set_role(egui::accesskit::Role::Label);
+        node.set_live(egui::accesskit::Live::Polite);
+        node.set_label(value.clone());
+        node.set_value(value);
+    });
+
+    let info = egui::WidgetInfo::labeled(egui::WidgetType::Label, true, text);
+    let event = egui::output::OutputEvent::ValueChanged(info);
+    ctx.output_mut(|output| output.events.push(event));
+}
+
+/// Sets the role and label that screen readers use to describe a
+/// custom-painted widget.
+pub fn describe(r: &egui::Response, typ: egui::WidgetType, label: impl ToString) {
+    let label = label.to_string();
+    r.widget_info(|| egui::WidgetInfo::labeled(typ, r.enabled(), &label));
+}
+
+/// Returns the stroke used for focus outlines by [`paint_focus_outline()`].
+pub fn focus_outline_stroke(ui: &egui::Ui) -> egui::Stroke {
+    let mut stroke = ui.visuals().selection.stroke;
+    if high_contrast(ui.ctx()) {
+        stroke.width *= 2.0;
+        stroke.color = ui.visuals().strong_text_color();
+    }
+    stroke
+}
+
+/// Paints an outline around a custom-painted widget if it has keyboard focus.
+///
+/// Built-in egui widgets already indicate focus; use this for widgets that
+/// paint themselves, such as [`crate::dnd::ReorderHandle`].
+pub fn paint_focus_outline(ui: &egui::Ui, r: &egui::Response) {
+    if r.has_focus() {
+        ui.painter().rect_stroke(
+            r.rect,
+            ui.visuals().widgets.active.corner_radius,
+            focus_outline_stroke(ui),
+            egui::StrokeKind::Outside,
+        );
+    }
+}
@@ -0,0 +1,204 @@
+//! Animation and easing utilities.
+//!
+//! All animations respect a global [reduced motion](set_reduced_motion)
+//! setting, which makes them finish instantly.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::anim;
+//!
+//! let target = if ui.ui_contains_pointer() { 1.0 } else { 0.0 };
+//! let t = anim::animate_toward(ui.ctx(), ui.id().with("fade"), target, anim::easing::cubic_out, 0.2);
+//! ui.label(egui::RichText::new("Hover me").color(egui::Color32::GRAY.gamma_multiply(0.5 + t / 2.0)));
+//! # });
+//! ```
+
+/// Easing curves that map `0.0..=1.0` to `0.0..=1.0` (possibly overshooting in
+/// between).
+///
+/// This includes everything from [`egui::emath::easing`] plus [`spring()`].
+pub mod easing {
+    pub use egui::emath::easing::*;
+
+    /// Underdamped spring that overshoots the target and oscillates briefly
+    /// before settling.
+    pub fn spring(t: f32) -> f32 {
+        const DECAY: f32 = 6.0;
+        const OSCILLATIONS: f32 = 1.5;
+
+        if t >= 1.0 {
+            return 1.0;
+        }
+        let t = t.max(0.0);
+        1.0 - (-DECAY * t).exp() * (std::f32::consts::TAU * OSCILLATIONS * t).cos()
+    }
+}
+
+/// Easing curve, such as one from [`easing`].
+pub type Curve = fn(f32) -> f32;
+
+/// Value that can be smoothly interpolated.
+pub trait Animatable: Copy + PartialEq + Send + Sync + 'static {
+    /// Linearly interpolates between `a` and `b`, where `t` may be outside the
+    /// range `0.0..=1.0`.
+    fn lerp(a: Self, b: Self, t: f32) -> Self;
+}
+
+impl Animatable for f32 {
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        egui::lerp(a..=b, t)
+    }
+}
+
+impl Animatable for egui::Pos2 {
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a.lerp(b, t)
+    }
+}
+
+impl Animatable for egui::Vec2 {
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        a + (b - a) * t
+    }
+}
+
+impl Animatable for egui::Color32 {
+    fn lerp(a: Self, b: Self, t: f32) -> Self {
+        // `lerp_to_gamma()` clamps `t`, which is fine because overshooting a
+        // color doesn't make sense anyway.
+        a.lerp_to_gamma(b, t)
+    }
+}
+
+fn reduced_motion_id() -> egui::Id {
+    egui::Id::new("hcegui::anim::reduced_motion")
+}
+
+/// Sets whether animations should be skipped, such as for users who are
+/// sensitive to motion. Default: `false`.
+pub fn set_reduced_motion(ctx: &egui::Context, reduced_motion: bool) {
+    ctx.data_mut(|data| data.insert_temp(reduced_motion_id(), reduced_motion));
+}
+
+/// Returns whether animations should be skipped. See [`set_reduced_motion()`].
+pub fn reduced_motion(ctx: &egui::Context) -> bool {
+    ctx.data(|data| data.get_temp(reduced_motion_id()))
+        .unwrap_or(false)
+}
+
+/// Value that animates toward a target whenever the target changes.
+///
+/// Unlike [`animate_toward()`], this is stored by the caller instead of in egui
+/// memory.
+#[derive(Debug, Clone, Copy)]
+pub struct AnimatedValue<T> {
+    from: T,
+    to: T,
+    start_time: f64,
+    duration: f32,
+    curve: Curve,
+}
+
+impl<T: Animatable> AnimatedValue<T> {
+    /// Constructs a value that is not animating.
+    ///
+    /// The default duration is 0.2 seconds and the default curve is
+    /// [`easing::cubic_out()`].
+    pub fn new(value: T) -> Self {
+        Self {
+            from: value,
+            to: value,
+            start_time: f64::NEG_INFINITY,
+            duration: 0.2,
+            curve: easing::cubic_out,
+        }
+    }
+
+    /// Sets the duration of animations, in seconds.
+    #[must_use]
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the easing curve of animations.
+    #[must_use]
+    pub fn with_curve(mut self, curve: Curve) -> Self {
+        self.curve = curve;
+        self
+    }
+
+    /// Returns the value that the animation is moving toward.
+    pub fn target(&self) -> T {
+        self.to
+    }
+
+    /// Starts animating toward `target` from the current value, unless it is
+    /// already the target.
+    pub fn set_target(&mut self, ctx: &egui::Context, target: T) {
+        if target != self.to {
+            self.from = self.get(ctx);
+            self.to = target;
+            self.start_time = ctx.input(|input| input.time);
+        }
+    }
+
+    /// Jumps to `value` without animating.
+    pub fn set_immediate(&mut self, value: T) {
+        *self = Self {
+            from: value,
+            to: value,
+            start_time: f64::NEG_INFINITY,
+            ..*self
+        };
+    }
+
+    /// Returns whether the value is still animating.
+    pub fn is_animating(&self, ctx: &egui::Context) -> bool {
+        self.progress(ctx) < 1.0
+    }
+
+    /// Returns the current value, requesting a repaint if it is still
+    /// animating.
+    pub fn get(&self, ctx: &egui::Context) -> T {
+        let progress = self.progress(ctx);
+        if progress >= 1.0 {
+            return self.to;
+        }
+        ctx.request_repaint();
+        T::lerp(self.from, self.to, (self.curve)(progress))
+    }
+
+    /// Returns the progress of the animation from `0.0` to `1.0`.
+    fn progress(&self, ctx: &egui::Context) -> f32 {
+        if self.from == self.to || self.duration <= 0.0 || reduced_motion(ctx) {
+            return 1.0;
+        }
+        let elapsed = ctx.input(|input| input.time) - self.start_time;
+        (elapsed as f32 / self.duration).clamp(0.0, 1.0)
+    }
+}
+
+/// Animates a value stored in egui memory toward `target` over `duration`
+/// seconds, restarting the animation from the current value whenever `target`
+/// changes. Returns the current value.
+///
+/// On the first call for a given `id`, this returns `target` immediately.
+pub fn animate_toward<T: Animatable>(
+    ctx: &egui::Context,
+    id: egui::Id,
+    target: T,
+    curve: Curve,
+    duration: f32,
+) -> T {
+    let mut value = ctx
+        .data(|data| data.get_temp::<AnimatedValue<T>>(id))
+        .unwrap_or_else(|| AnimatedValue::new(target));
+    value.duration = duration;
+    value.curve = curve;
+    value.set_target(ctx, target);
+    ctx.data_mut(|data| data.insert_temp(id, value));
+    value.get(ctx)
+}
@@ -5,28 +5,90 @@
 
 use egui::Color32;
 
+/// Colors used by [`AnsiLabel`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct AnsiPalette {
+    /// Color for code `31` (red). If `None`, [`egui::Visuals::error_fg_color`]
+    /// is used.
+    pub red: Option<Color32>,
+    /// Color for code `33` (yellow). If `None`,
+    /// [`egui::Visuals::warn_fg_color`] is used.
+    pub yellow: Option<Color32>,
+    /// Brightness multiplier for 256-color codes in light mode, so that they
+    /// are readable on a light background.
+    pub light_mode_brightness: f32,
+}
+impl Default for AnsiPalette {
+    fn default() -> Self {
+        Self {
+            red: None,
+            yellow: None,
+            light_mode_brightness: 2.0 / 3.0,
+        }
+    }
+}
+
+fn palette_id() -> egui::Id {
+    egui::Id::new("hcegui::ansi::palette")
+}
+
+/// Sets the palette used by [`AnsiLabel`]s that don't have their own palette.
+/// Default: [`AnsiPalette::default()`].
+pub fn set_palette(ctx: &egui::Context, palette: AnsiPalette) {
+    ctx.data_mut(|data| data.insert_temp(palette_id(), palette));
+}
+
+/// Returns the palette used by [`AnsiLabel`]s that don't have their own
+/// palette. See [`set_palette()`].
+pub fn palette(ctx: &egui::Context) -> AnsiPalette {
+    ctx.data(|data| data.get_temp(palette_id()))
+        .unwrap_or_default()
+}
+
 /// Label that supports ANSI escape codes.
-pub struct AnsiLabel<S>(S);
+pub struct AnsiLabel<S> {
+    text: S,
+    palette: Option<AnsiPalette>,
+}
 
 impl<S: AsRef<str>> AnsiLabel<S> {
     /// Constructs a new ANSI label.
     pub fn new(s: S) -> Self {
-        Self(s)
+        Self {
+            text: s,
+            palette: None,
+        }
+    }
+
+    /// Sets the palette for this label. Default: the palette from
+    /// [`palette()`].
+    #[must_use]
+    pub fn palette(mut self, palette: AnsiPalette) -> Self {
+        self.palette = Some(palette);
+        self
     }
 }
 
 impl<S: AsRef<str>> egui::Widget for AnsiLabel<S> {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
-        let ansi_str = self.0.as_ref();
+        profile_scope!("hcegui::ansi::AnsiLabel");
+        let ansi_str = self.text.as_ref();
+        let palette = self.palette.unwrap_or_else(|| palette(ui.ctx()));
+        let red = palette.red.unwrap_or(ui.visuals().error_fg_color);
+        let yellow = palette.yellow.unwrap_or(ui.visuals().warn_fg_color);
 
         let mut text_job = egui::text::LayoutJob::default();
+        text_job.text.reserve(ansi_str.len());
 
         let themed = |color32: egui::Color32| {
             if ui.visuals().dark_mode {
                 color32
             } else {
                 let [r, g, b, _] = color32.to_array();
-                Color32::from_rgb(r / 3 * 2, g / 3 * 2, b / 3 * 2)
+                let scale = |c: u8| (c as f32 * palette.light_mode_brightness) as u8;
+                Color32::from_rgb(scale(r), scale(g), scale(b))
             }
         };
 
@@ -54,8 +116,8 @@ impl<S: AsRef<str>> egui::Widget for AnsiLabel<S> {
             let escape_code = &remaining[2..escape_end - 1];
             match escape_code {
                 "0" => format = default_format.clone(),
-                "31" => format.color = ui.visuals().error_fg_color,
-                "33" => format.color = ui.visuals().warn_fg_color,
+                "31" => format.color = red,
+                "33" => format.color = yellow,
                 _ => {
                     if let Some(color_index_str) = escape_code.strip_prefix("38;5;") {
                         match color_index_str.parse() {
@@ -71,6 +133,14 @@ impl<S: AsRef<str>> egui::Widget for AnsiLabel<S> {
             remaining = &remaining[escape_end..];
         }
 
+        #[cfg(feature = "inspector")]
+        crate::inspector::record_ansi_label(
+            ui.ctx(),
+            ansi_str.len(),
+            text_job.sections.len(),
+            display_errors.len(),
+        );
+
         // Append warnings to the end.
         format = default_format;
         format.color = ui.visuals().error_fg_color;
@@ -82,6 +152,20 @@ impl<S: AsRef<str>> egui::Widget for AnsiLabel<S> {
     }
 }
 
+/// Returns `s` with all ANSI escape codes removed.
+pub fn strip_ansi(s: &str) -> String {
+    let mut ret = String::with_capacity(s.len());
+    let mut remaining = s;
+    while let Some(escape_start) = remaining.find("\x1b[") {
+        ret.push_str(&remaining[..escape_start]);
+        remaining = &remaining[escape_start..];
+        let escape_end = remaining.find('m').map_or(remaining.len(), |i| i + 1);
+        remaining = &remaining[escape_end..];
+    }
+    ret.push_str(remaining);
+    ret
+}
+
 fn term_color_256(i: u8) -> egui::Color32 {
     let [r, g, b] = if i < 16 {
         [
@@ -106,7 +190,7 @@ fn term_color_256(i: u8) -> egui::Color32 {
         [[
             0x08, 0x12, 0x1c, 0x26, 0x30, 0x3a, 0x44, 0x4e, 0x58, 0x60, 0x66, 0x76, 0x80, 0x8a,
             0x94, 0x9e, 0xa8, 0xb2, 0xbc, 0xc6, 0xd0, 0xda, 0xe4, 0xee,
-        ][i as usize - 231]; 3]
+        ][i as usize - 232]; 3]
     } else {
         let b = [0x00, 0x5f, 0x87, 0xaf, 0xd7, 0xff];
         let q = i as usize - 16;
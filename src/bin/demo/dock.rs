@@ -0,0 +1,43 @@
+use hcegui::dock::{DockArea, DockLayout, DockNode};
+
+pub struct DockDemo {
+    layout: DockLayout<&'static str>,
+}
+
+impl Default for DockDemo {
+    fn default() -> Self {
+        Self {
+            layout: DockLayout::new(
+                DockNode::left_right(
+                    DockNode::Panel("Files"),
+                    DockNode::top_bottom(
+                        DockNode::left_right(DockNode::Panel("Editor"), DockNode::Panel("Outline"))
+                            .with_fraction(0.7),
+                        DockNode::Panel("Terminal"),
+                    )
+                    .with_fraction(0.7),
+                )
+                .with_fraction(0.25),
+            ),
+        }
+    }
+}
+
+impl DockDemo {
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.label("Drag a panel by its title onto the docking hints of another panel.");
+        if ui.button("Reset layout").clicked() {
+            *self = Self::default();
+        }
+        ui.separator();
+
+        DockArea::new("dock_demo").show(
+            ui,
+            &mut self.layout,
+            |panel| panel.to_string(),
+            |ui, panel| {
+                ui.label(format!("Contents of {panel}"));
+            },
+        );
+    }
+}
@@ -0,0 +1,73 @@
+use hcegui::log_console::{LogBuffer, LogConsole, LogLevel};
+
+pub struct LogConsoleDemo {
+    console: LogConsole,
+    message_count: usize,
+}
+
+impl Default for LogConsoleDemo {
+    fn default() -> Self {
+        let buffer = LogBuffer::new(10_000);
+        if let Err(e) = buffer.install_logger(log::LevelFilter::Trace) {
+            buffer.push(
+                LogLevel::Error,
+                "demo",
+                format!("error installing logger: {e}"),
+            );
+        }
+        buffer.push(
+            LogLevel::Info,
+            "demo",
+            "Messages may contain \x1b[33mANSI\x1b[0m \x1b[38;5;208mescape codes\x1b[0m.",
+        );
+        buffer.push(
+            LogLevel::Warn,
+            "demo",
+            "This message has multiple lines.\nHover over it to see the rest.",
+        );
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let console = LogConsole::new(buffer).save_path(std::env::temp_dir().join("hcegui.log"));
+        #[cfg(target_arch = "wasm32")]
+        let console = LogConsole::new(buffer);
+
+        Self {
+            console,
+            message_count: 0,
+        }
+    }
+}
+
+impl LogConsoleDemo {
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal_wrapped(|ui| {
+            ui.label("Emit using the log crate:");
+            for level in [
+                log::Level::Error,
+                log::Level::Warn,
+                log::Level::Info,
+                log::Level::Debug,
+                log::Level::Trace,
+            ] {
+                if ui.button(level.as_str()).clicked() {
+                    self.message_count += 1;
+                    log::log!(level, "Message #{} at {level} level", self.message_count);
+                }
+            }
+
+            #[cfg(not(target_arch = "wasm32"))]
+            if ui.button("Log from background thread").clicked() {
+                std::thread::spawn(|| {
+                    for i in 1..=20 {
+                        std::thread::sleep(std::time::Duration::from_millis(100));
+                        log::info!(target: "demo::worker", "Background message {i}/20");
+                    }
+                });
+            }
+        });
+
+        ui.separator();
+
+        self.console.show(ui);
+    }
+}
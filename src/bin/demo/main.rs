@@ -1,42 +1,186 @@
 //! Demo crate.
 
+use hcegui::command_palette::{Command, CommandPalette};
+
 mod dnd;
+mod dock;
+mod log_console;
+mod style_editor;
 mod util;
 
+// The examples are small standalone apps; the demo composes them. Their `main`
+// functions are unused here.
+#[allow(dead_code)]
+#[path = "../../../examples/ansi_log.rs"]
+mod ansi_log;
+#[allow(dead_code)]
+#[path = "../../../examples/nested_dnd.rs"]
+mod nested_dnd;
+#[allow(dead_code)]
+#[path = "../../../examples/reorder.rs"]
+mod reorder;
+#[allow(dead_code)]
+#[path = "../../../examples/util_layouts.rs"]
+mod util_layouts;
+
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
 enum Panel {
     #[default]
     Dnd,
+    Dock,
     Util,
+    Ansi,
+    LogConsole,
+    StyleEditor,
 }
 
-fn main() -> eframe::Result {
-    let mut current_panel = Panel::default();
+const PANELS: &[(Panel, &str)] = &[
+    (Panel::Dnd, "dnd"),
+    (Panel::Dock, "dock"),
+    (Panel::Util, "util"),
+    (Panel::Ansi, "ansi"),
+    (Panel::LogConsole, "log_console"),
+    (Panel::StyleEditor, "style_editor"),
+];
 
-    let mut dnd_demo = dnd::DndDemo::default();
-    let mut util_demo = util::UtilDemo::default();
+#[derive(Default)]
+struct DemoApp {
+    palette: CommandPalette<DemoApp>,
+    current_panel: Panel,
+    mirrored: bool,
+    show_inspector: bool,
+    dnd_demo: dnd::DndDemo,
+    dock_demo: dock::DockDemo,
+    util_demo: util::UtilDemo,
+    ansi_demo: ansi_log::AnsiLogExample,
+    log_console_demo: log_console::LogConsoleDemo,
+    style_editor_demo: style_editor::StyleEditorDemo,
+}
 
-    eframe::run_ui_native(
-        "egui_reorder demo",
-        eframe::NativeOptions::default(),
-        move |ui, _frame| {
-            egui::CentralPanel::default().show_inside(ui, |ui| {
-                egui::Sides::new().show(
-                    ui,
-                    |ui| {
-                        ui.selectable_value(&mut current_panel, Panel::Dnd, "dnd");
-                        ui.selectable_value(&mut current_panel, Panel::Util, "util");
-                    },
-                    |ui| egui::global_theme_preference_buttons(ui),
-                );
-
-                ui.separator();
-
-                match current_panel {
-                    Panel::Dnd => dnd_demo.show(ui),
-                    Panel::Util => util_demo.show(ui),
+impl eframe::App for DemoApp {
+    fn ui(&mut self, ui: &mut egui::Ui, _frame: &mut eframe::Frame) {
+        if self.palette.commands().is_empty() {
+            add_commands(&mut self.palette);
+        }
+
+        egui::CentralPanel::default().show_inside(ui, |ui| {
+            egui::Sides::new().show(
+                ui,
+                |ui| {
+                    for &(panel, name) in PANELS {
+                        ui.selectable_value(&mut self.current_panel, panel, name);
+                    }
+                },
+                |ui| {
+                    egui::global_theme_preference_buttons(ui);
+                    ui.separator();
+                    ui.toggle_value(&mut self.mirrored, "Mirror (RTL)")
+                        .on_hover_text("Display the demo using right-to-left layouts");
+                    ui.toggle_value(&mut self.show_inspector, "Inspector")
+                        .on_hover_text("Show hcegui's internal state");
+                    ui.separator();
+                    if ui
+                        .button("Commands")
+                        .on_hover_text(ui.ctx().format_shortcut(&OPEN_PALETTE))
+                        .clicked()
+                    {
+                        self.palette.open();
+                    }
+                },
+            );
+
+            ui.separator();
+
+            let align = if self.mirrored {
+                egui::Align::Max
+            } else {
+                egui::Align::Min
+            };
+            ui.with_layout(egui::Layout::top_down(align), |ui| {
+                match self.current_panel {
+                    Panel::Dnd => self.dnd_demo.show(ui),
+                    Panel::Dock => self.dock_demo.show(ui),
+                    Panel::Util => self.util_demo.show(ui),
+                    Panel::Ansi => self.ansi_demo.show(ui),
+                    Panel::LogConsole => self.log_console_demo.show(ui),
+                    Panel::StyleEditor => self.style_editor_demo.show(ui),
                 }
             });
-        },
+        });
+
+        hcegui::inspector::inspector_window(ui.ctx(), &mut self.show_inspector);
+
+        // Commands take `&mut DemoApp`, which includes the palette itself.
+        let mut palette = std::mem::take(&mut self.palette);
+        palette.show(ui.ctx(), self);
+        self.palette = palette;
+    }
+}
+
+const OPEN_PALETTE: egui::KeyboardShortcut = egui::KeyboardShortcut::new(
+    egui::Modifiers::COMMAND.plus(egui::Modifiers::SHIFT),
+    egui::Key::P,
+);
+
+fn add_commands(palette: &mut CommandPalette<DemoApp>) {
+    *palette = CommandPalette::new().open_shortcut(OPEN_PALETTE);
+    for &(panel, name) in PANELS {
+        palette.add(Command::new(
+            format!("Show {name} demo"),
+            move |app: &mut DemoApp| {
+                app.current_panel = panel;
+            },
+        ));
+    }
+    palette.add(
+        Command::new("Toggle mirrored layout", |app: &mut DemoApp| {
+            app.mirrored ^= true;
+        })
+        .shortcut(egui::KeyboardShortcut::new(
+            egui::Modifiers::COMMAND,
+            egui::Key::M,
+        )),
+    );
+    palette.add(
+        Command::new("Toggle inspector", |app: &mut DemoApp| {
+            app.show_inspector ^= true;
+        })
+        .shortcut(egui::KeyboardShortcut::new(
+            egui::Modifiers::COMMAND,
+            egui::Key::I,
+        )),
+    );
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn main() -> eframe::Result {
+    eframe::run_native(
+        "hcegui demo",
+        eframe::NativeOptions::default(),
+        Box::new(|_cc| Ok(Box::<DemoApp>::default())),
     )
 }
+
+#[cfg(target_arch = "wasm32")]
+fn main() {
+    use eframe::wasm_bindgen::JsCast;
+    use eframe::web_sys;
+
+    wasm_bindgen_futures::spawn_local(async {
+        let canvas = web_sys::window()
+            .and_then(|window| window.document())
+            .and_then(|document| document.get_element_by_id("the_canvas_id"))
+            .expect("missing canvas element")
+            .dyn_into::<web_sys::HtmlCanvasElement>()
+            .expect("canvas element is not a canvas");
+
+        eframe::WebRunner::new()
+            .start(
+                canvas,
+                eframe::WebOptions::default(),
+                Box::new(|_cc| Ok(Box::<DemoApp>::default())),
+            )
+            .await
+            .expect("failed to start eframe");
+    });
+}
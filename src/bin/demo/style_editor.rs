@@ -0,0 +1,74 @@
+use hcegui::ansi::AnsiPalette;
+use hcegui::dnd::DndStyle;
+use hcegui::style_editor::StyleEditor;
+use hcegui::theme::{Theme, ThemeWatcher};
+
+pub struct StyleEditorDemo {
+    dnd_style: DndStyle,
+    ansi_palette: AnsiPalette,
+    theme_path: String,
+    watcher: Option<ThemeWatcher>,
+}
+
+impl Default for StyleEditorDemo {
+    fn default() -> Self {
+        Self {
+            dnd_style: DndStyle::default(),
+            ansi_palette: AnsiPalette::default(),
+            theme_path: "theme.toml".to_owned(),
+            watcher: None,
+        }
+    }
+}
+
+impl StyleEditorDemo {
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        ui.label("Changes here apply to all the other demos.");
+        self.show_theme_file(ui);
+        ui.separator();
+
+        egui::ScrollArea::vertical().show(ui, |ui| {
+            let changed = StyleEditor::new()
+                .dnd_style(&mut self.dnd_style)
+                .ansi_palette(&mut self.ansi_palette)
+                .show(ui);
+            if changed {
+                self.theme().apply(ui.ctx());
+            }
+        });
+    }
+
+    fn theme(&self) -> Theme {
+        Theme {
+            dnd: self.dnd_style,
+            ansi: self.ansi_palette,
+        }
+    }
+
+    fn show_theme_file(&mut self, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            ui.label("Theme file:");
+            ui.text_edit_singleline(&mut self.theme_path);
+
+            let mut watching = self.watcher.is_some();
+            if ui.checkbox(&mut watching, "Watch").changed() {
+                self.watcher = watching.then(|| ThemeWatcher::new(&self.theme_path));
+            }
+            if ui.button("Save").clicked()
+                && let Err(e) = std::fs::write(&self.theme_path, self.theme().to_toml())
+            {
+                log::error!("error saving theme: {e}");
+            }
+        });
+
+        if let Some(watcher) = &mut self.watcher {
+            if let Some(theme) = watcher.update(ui.ctx()) {
+                self.dnd_style = theme.dnd;
+                self.ansi_palette = theme.ansi;
+            }
+            if let Some(e) = watcher.last_error() {
+                ui.colored_label(ui.visuals().error_fg_color, e.to_string());
+            }
+        }
+    }
+}
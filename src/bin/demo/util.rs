@@ -1,103 +1,444 @@
-#[derive(Debug, Default)]
+use crate::util_layouts::UtilLayoutsExample;
+
+#[derive(Default)]
 pub struct UtilDemo {
     panel: UtilPanel,
+    layouts: UtilLayoutsExample,
 }
 
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
 enum UtilPanel {
     #[default]
-    ShowOnOneLine,
+    Layouts,
+    Icons,
+    Colors,
+    Progress,
+    Skeleton,
+    ClampedText,
+    ListNav,
+    ConfirmButton,
+    Anim,
+    Smoothing,
+    A11y,
+    Clipboard,
 }
 
+const UTIL_PANELS: &[(UtilPanel, &str)] = &[
+    (UtilPanel::Layouts, "Layouts"),
+    (UtilPanel::Icons, "Icon"),
+    (UtilPanel::Colors, "ColorSwatch"),
+    (UtilPanel::Progress, "Progress"),
+    (UtilPanel::Skeleton, "Skeleton"),
+    (UtilPanel::ClampedText, "ClampedText"),
+    (UtilPanel::ListNav, "ListNav"),
+    (UtilPanel::ConfirmButton, "ConfirmButton"),
+    (UtilPanel::Anim, "anim"),
+    (UtilPanel::Smoothing, "smooth_damp()"),
+    (UtilPanel::A11y, "a11y"),
+    (UtilPanel::Clipboard, "clipboard"),
+];
+
 impl UtilDemo {
     pub fn show(&mut self, ui: &mut egui::Ui) {
-        ui.selectable_value(
-            &mut self.panel,
-            UtilPanel::ShowOnOneLine,
-            "show_on_one_line()",
-        );
+        ui.horizontal_wrapped(|ui| {
+            for &(panel, name) in UTIL_PANELS {
+                ui.selectable_value(&mut self.panel, panel, name);
+            }
+        });
 
         ui.separator();
 
         match self.panel {
-            UtilPanel::ShowOnOneLine => show_one_line_demo(ui),
+            UtilPanel::Layouts => self.layouts.show(ui),
+            UtilPanel::Icons => icons_demo(ui),
+            UtilPanel::Colors => colors_demo(ui),
+            UtilPanel::Progress => progress_demo(ui),
+            UtilPanel::Skeleton => skeleton_demo(ui),
+            UtilPanel::ClampedText => clamped_text_demo(ui),
+            UtilPanel::ListNav => list_nav_demo(ui),
+            UtilPanel::ConfirmButton => confirm_button_demo(ui),
+            UtilPanel::Anim => anim_demo(ui),
+            UtilPanel::Smoothing => smoothing_demo(ui),
+            UtilPanel::A11y => a11y_demo(ui),
+            UtilPanel::Clipboard => clipboard_demo(ui),
         }
     }
 }
 
-fn show_one_line_demo(ui: &mut egui::Ui) {
-    egui::ScrollArea::vertical()
-        .auto_shrink(false)
+fn icons_demo(ui: &mut egui::Ui) {
+    use hcegui::util::{Icon, icon_button, icon_label};
+
+    ui.horizontal(|ui| {
+        ui.add(Icon::glyph('⚙'));
+        ui.add(Icon::glyph('⚙').scale(1.5));
+        ui.add(Icon::glyph('⚙').scale(2.0).color(egui::Color32::LIGHT_BLUE));
+    });
+    icon_label(ui, Icon::glyph('🔔'), "Notifications");
+    icon_label(ui, Icon::glyph('🗀'), "Documents");
+    ui.horizontal(|ui| {
+        #[allow(unused_must_use)]
+        {
+            icon_button(ui, Icon::glyph('💾'), "Save");
+            icon_button(ui, Icon::glyph('🗑'), "Delete");
+        }
+    });
+}
+
+fn colors_demo(ui: &mut egui::Ui) {
+    use egui::Color32;
+    use hcegui::util::{ColorSwatch, PaletteStrip};
+
+    let id = egui::Id::new("colors_demo");
+    let mut colors: [Color32; 2] =
+        ui.data(|data| data.get_temp(id).unwrap_or([Color32::RED, Color32::GOLD]));
+
+    ui.horizontal(|ui| {
+        ui.add(ColorSwatch::new(&mut colors[0]));
+        ui.label("Opaque");
+    });
+    ui.horizontal(|ui| {
+        ui.add(ColorSwatch::new(&mut colors[1]).alpha(true));
+        ui.label("With alpha");
+    });
+
+    ui.separator();
+
+    let palette = [
+        Color32::RED,
+        Color32::ORANGE,
+        Color32::YELLOW,
+        Color32::GREEN,
+        Color32::BLUE,
+        Color32::PURPLE,
+    ];
+    ui.add(PaletteStrip::new(&mut colors[0], &palette).show_recent(true));
+
+    ui.data_mut(|data| data.insert_temp(id, colors));
+}
+
+fn progress_demo(ui: &mut egui::Ui) {
+    use hcegui::util::Progress;
+
+    let id = egui::Id::new("progress_demo");
+    let mut fraction: f32 = ui.data(|data| data.get_temp(id).unwrap_or(0.3));
+
+    ui.add(egui::Slider::new(&mut fraction, 0.0..=1.0));
+    let eta = std::time::Duration::from_secs_f32((1.0 - fraction) * 200.0);
+    ui.add(
+        Progress::new(fraction)
+            .text("Downloading")
+            .show_percentage()
+            .eta(eta),
+    );
+    ui.add(Progress::indeterminate().text("Connecting…"));
+
+    ui.data_mut(|data| data.insert_temp(id, fraction));
+}
+
+fn skeleton_demo(ui: &mut egui::Ui) {
+    use hcegui::util::Skeleton;
+
+    for _ in 0..3 {
+        ui.add(
+            Skeleton::row()
+                .avatar(40.0)
+                .child(Skeleton::new().text_line(0.3).text_lines(2)),
+        );
+        ui.add_space(8.0);
+    }
+    ui.add(Skeleton::new().card(120.0));
+}
+
+fn clamped_text_demo(ui: &mut egui::Ui) {
+    use hcegui::util::ClampedText;
+
+    const LOREM: &str = "Lorem ipsum dolor sit amet, consectetur adipiscing elit, sed do \
+                         eiusmod tempor incididunt ut labore et dolore magna aliqua. Ut enim \
+                         ad minim veniam, quis nostrud exercitation ullamco laboris nisi ut \
+                         aliquip ex ea commodo consequat. Duis aute irure dolor in \
+                         reprehenderit in voluptate velit esse cillum dolore eu fugiat nulla \
+                         pariatur. Excepteur sint occaecat cupidatat non proident, sunt in \
+                         culpa qui officia deserunt mollit anim id est laborum.";
+
+    egui::Resize::default()
+        .default_width(300.0)
+        .max_height(0.0)
         .show(ui, |ui| {
-            egui::Resize::default()
-                .default_width(ui.available_width())
-                .max_height(0.0)
-                .show(ui, |ui| {
-                    ui.horizontal_wrapped(|ui| {
-                        #[allow(unused_must_use)]
-                        hcegui::util::show_on_one_line(ui, |ui| {
-                            ui.button("This was a triumph");
-                            ui.button("I'm making a note here; \"Huge success\"");
-                            ui.button("It's hard to overstate");
-                            ui.button("My satisfaction");
-                            ui.button("Aperture Science:");
-                            ui.button("We do what we must");
-                            ui.button("Because we can");
-                            ui.button("For the good of all of us");
-                            ui.button("Except the ones who are dead");
-                            ui.button("But there's no sense crying");
-                            ui.button("Over every mistake");
-                            ui.button("You just keep on trying");
-                            ui.button("Till you run out of cake");
-                            ui.button("And the science gets done");
-                            ui.button("And you make a neat gun");
-                            ui.button("For the people who are");
-                            ui.button("Still alive");
-                            ui.button("I'm not even angry");
-                            ui.button("I'm being so sincere right now");
-                            ui.button("Even though you broke my heart,");
-                            ui.button("And killed me");
-                            ui.button("And tore me to pieces");
-                            ui.button("And threw every piece into a fire");
-                            ui.button("As they burned it hurt because");
-                            ui.button("I was so happy for you");
-                            ui.button("Now, these points of data");
-                            ui.button("Make a beautiful line");
-                            ui.button("And we're out of beta");
-                            ui.button("We're releasing on time");
-                            ui.button("So I'm GLaD I got burned");
-                            ui.button("Think of all the things we learned-");
-                            ui.button("For the people who are");
-                            ui.button("Still alive");
-                            ui.button("Go ahead and leave me");
-                            ui.button("I think I'd prefer to stay inside");
-                            ui.button("Maybe you'll find someone else");
-                            ui.button("To help you?");
-                            ui.button("Maybe Black Mesa?");
-                            ui.button("That was a joke *Haha - Fat Chance*");
-                            ui.button("Anyway this cake is great");
-                            ui.button("It's so delicious and moist");
-                            ui.button("Look at me: still talking");
-                            ui.button("When there's science to do");
-                            ui.button("When I look out there,");
-                            ui.button("It makes me GLaD I'm not you");
-                            ui.button("I've experiments to run");
-                            ui.button("There is research to be done");
-                            ui.button("On the people who are");
-                            ui.button("Still alive");
-                            ui.button("And believe me I am");
-                            ui.button("Still alive");
-                            ui.button("I'm doing science and I'm");
-                            ui.button("Still alive");
-                            ui.button("I feel fantastic and I'm");
-                            ui.button("Still alive");
-                            ui.button("While you're dying I'll be");
-                            ui.button("Still alive");
-                            ui.button("And when you're dead I will be");
-                            ui.button("Still alive");
-                            ui.button("Still alive");
-                        });
-                    });
-                });
+            ui.add(ClampedText::new(LOREM).id_salt("lorem"));
+            ui.separator();
+            ui.add(ClampedText::new(LOREM).max_lines(1).id_salt("lorem 1 line"));
+        });
+}
+
+fn list_nav_demo(ui: &mut egui::Ui) {
+    const FRUITS: &[&str] = &[
+        "Apple",
+        "Apricot",
+        "Avocado",
+        "Banana",
+        "Blackberry",
+        "Blueberry",
+        "Cherry",
+        "Coconut",
+        "Cranberry",
+        "Date",
+        "Durian",
+        "Elderberry",
+        "Fig",
+        "Grape",
+        "Grapefruit",
+        "Guava",
+        "Kiwi",
+        "Lemon",
+        "Lime",
+        "Lychee",
+        "Mango",
+        "Melon",
+        "Nectarine",
+        "Orange",
+        "Papaya",
+        "Peach",
+        "Pear",
+        "Pineapple",
+        "Plum",
+        "Pomegranate",
+        "Raspberry",
+        "Strawberry",
+        "Tangerine",
+        "Watermelon",
+    ];
+
+    let id = egui::Id::new("list_nav_demo");
+    let (mut highlighted, scroll_pending): (Option<usize>, bool) =
+        ui.data(|data| data.get_temp(id).unwrap_or_default());
+
+    ui.label("Click the list, then use the arrow keys, Home/End, Page Up/Down, or type to jump.");
+
+    let r = egui::Frame::group(ui.style()).show(ui, |ui| {
+        egui::ScrollArea::vertical()
+            .max_height(200.0)
+            .auto_shrink([false, true])
+            .show(ui, |ui| {
+                for (i, &fruit) in FRUITS.iter().enumerate() {
+                    let r = ui.selectable_label(highlighted == Some(i), fruit);
+                    if r.clicked() {
+                        highlighted = Some(i);
+                        ui.memory_mut(|mem| mem.request_focus(id));
+                    }
+                    if scroll_pending && highlighted == Some(i) {
+                        r.scroll_to_me(None);
+                    }
+                }
+            });
+    });
+    let list_response = ui.interact(r.response.rect, id, egui::Sense::focusable_noninteractive());
+    hcegui::a11y::paint_focus_outline(ui, &list_response);
+
+    let changed = hcegui::util::ListNav::new().page_size(5).show(
+        ui,
+        &list_response,
+        &mut highlighted,
+        FRUITS.len(),
+        |i| FRUITS[i].to_string(),
+    );
+
+    if changed {
+        // Scroll the newly highlighted item into view on the next frame.
+        ui.ctx().request_repaint();
+    }
+    ui.data_mut(|data| data.insert_temp(id, (highlighted, changed)));
+}
+
+fn confirm_button_demo(ui: &mut egui::Ui) {
+    use hcegui::util::ConfirmButton;
+
+    let id = egui::Id::new("confirm_button_demo");
+    let mut files: Vec<String> = ui.data(|data| {
+        data.get_temp(id)
+            .unwrap_or_else(|| (1..=5).map(|i| format!("file_{i}.txt")).collect())
+    });
+
+    let mut to_delete = None;
+    for (i, file) in files.iter().enumerate() {
+        ui.horizontal(|ui| {
+            let button = ConfirmButton::new("🗑 Delete")
+                .confirm_text("Really delete?")
+                .id_salt(file);
+            if ui.add(button).clicked() {
+                to_delete = Some(i);
+            }
+            ui.label(file);
+        });
+    }
+    if let Some(i) = to_delete {
+        files.remove(i);
+    }
+    if ui.button("Reset").clicked() {
+        files = (1..=5).map(|i| format!("file_{i}.txt")).collect();
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, files));
+}
+
+fn anim_demo(ui: &mut egui::Ui) {
+    use hcegui::anim::{self, easing};
+
+    const CURVES: &[(&str, anim::Curve)] = &[
+        ("linear", easing::linear),
+        ("cubic_in_out", easing::cubic_in_out),
+        ("cubic_out", easing::cubic_out),
+        ("back_out", easing::back_out),
+        ("spring", easing::spring),
+    ];
+
+    let id = egui::Id::new("anim_demo");
+    let mut at_end: bool = ui.data(|data| data.get_temp(id).unwrap_or_default());
+
+    let mut reduced_motion = anim::reduced_motion(ui.ctx());
+    if ui.checkbox(&mut reduced_motion, "Reduced motion").changed() {
+        anim::set_reduced_motion(ui.ctx(), reduced_motion);
+    }
+    if ui.button("Animate").clicked() {
+        at_end = !at_end;
+    }
+
+    egui::Grid::new(id).num_columns(2).show(ui, |ui| {
+        for &(name, curve) in CURVES {
+            ui.label(name);
+            let (rect, _) = ui.allocate_exact_size(
+                egui::vec2(ui.available_width().min(300.0), 16.0),
+                egui::Sense::hover(),
+            );
+            let track = rect.shrink2(egui::vec2(8.0, 0.0)).x_range();
+            let target = if at_end { track.max } else { track.min };
+            let x = anim::animate_toward(ui.ctx(), id.with(name), target, curve, 1.0);
+            let color = anim::animate_toward(
+                ui.ctx(),
+                id.with(name).with("color"),
+                if at_end {
+                    egui::Color32::LIGHT_BLUE
+                } else {
+                    egui::Color32::ORANGE
+                },
+                curve,
+                1.0,
+            );
+            ui.painter()
+                .rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+            ui.painter()
+                .circle_filled(egui::pos2(x, rect.center().y), 6.0, color);
+            ui.end_row();
+        }
+    });
+
+    ui.data_mut(|data| data.insert_temp(id, at_end));
+}
+
+fn smoothing_demo(ui: &mut egui::Ui) {
+    use hcegui::util::{smooth_damp, smooth_toward};
+
+    let id = egui::Id::new("smoothing_demo");
+    let (mut target, mut damped, mut velocity): (f32, f32, f32) =
+        ui.data(|data| data.get_temp(id).unwrap_or_default());
+
+    ui.add(egui::Slider::new(&mut target, 0.0..=1.0).text("Target"));
+
+    let dt = ui.input(|input| input.stable_dt);
+    damped = smooth_damp(damped, target, &mut velocity, 0.3, dt);
+    if damped != target {
+        ui.ctx().request_repaint();
+    }
+    let decayed = smooth_toward(ui.ctx(), id.with("decay"), target, 0.1);
+
+    for (name, value) in [("smooth_damp()", damped), ("smooth_toward()", decayed)] {
+        ui.horizontal(|ui| {
+            let (rect, _) = ui.allocate_exact_size(
+                egui::vec2(ui.available_width().min(300.0), 16.0),
+                egui::Sense::hover(),
+            );
+            let x = egui::lerp(rect.shrink2(egui::vec2(8.0, 0.0)).x_range(), value);
+            ui.painter()
+                .rect_filled(rect, 2.0, ui.visuals().extreme_bg_color);
+            ui.painter().circle_filled(
+                egui::pos2(x, rect.center().y),
+                6.0,
+                ui.visuals().selection.bg_fill,
+            );
+            ui.label(name);
         });
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, (target, damped, velocity)));
+}
+
+fn a11y_demo(ui: &mut egui::Ui) {
+    use hcegui::a11y;
+
+    let mut reduced_motion = a11y::reduced_motion(ui.ctx());
+    if ui.checkbox(&mut reduced_motion, "Reduced motion").changed() {
+        a11y::set_reduced_motion(ui.ctx(), reduced_motion);
+    }
+    let mut high_contrast = a11y::high_contrast(ui.ctx());
+    if ui.checkbox(&mut high_contrast, "High contrast").changed() {
+        a11y::set_high_contrast(ui.ctx(), high_contrast);
+    }
+    let mut screen_reader = ui.ctx().options(|options| options.screen_reader);
+    if ui
+        .checkbox(&mut screen_reader, "Screen reader")
+        .on_hover_text("Use egui's built-in screen reader, if supported on this platform")
+        .changed()
+    {
+        ui.ctx()
+            .options_mut(|options| options.screen_reader = screen_reader);
+    }
+
+    ui.separator();
+
+    ui.label("Press Tab to focus the reorder handle:");
+    ui.horizontal(|ui| {
+        ui.add(hcegui::dnd::ReorderHandle::new());
+        ui.label("Custom-painted widget with a focus outline");
+    });
+
+    if ui.button("Announce").clicked() {
+        a11y::announce(ui.ctx(), "Hello from hcegui");
+    }
+}
+
+fn clipboard_demo(ui: &mut egui::Ui) {
+    use hcegui::clipboard;
+
+    if ui.button("Copy HTML").clicked() {
+        let html = "<p>Copied from <b>hcegui</b></p>";
+        clipboard::copy_html(ui.ctx(), html, "Copied from hcegui");
+    }
+
+    if ui.button("Copy image").clicked() {
+        let size = [64, 64];
+        let pixels = (0..size[0] * size[1])
+            .map(|i| {
+                let (x, y) = (i % size[0], i / size[0]);
+                egui::Color32::from_rgb((x * 4) as u8, (y * 4) as u8, 128)
+            })
+            .collect();
+        let image = egui::ColorImage::new(size, pixels);
+        clipboard::copy_image(ui.ctx(), image);
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let id = ui.id().with("clipboard_formats");
+        let mut formats: Option<clipboard::ClipboardFormats> = ui.data(|data| data.get_temp(id));
+        if ui.button("Inspect clipboard").clicked() {
+            formats = Some(clipboard::available_formats());
+        }
+        if let Some(formats) = formats {
+            if formats.is_empty() {
+                ui.label("Clipboard is empty");
+            } else {
+                ui.label(format!("Available formats: {}", formats.names().join(", ")));
+            }
+            ui.data_mut(|data| data.insert_temp(id, formats));
+        }
+    }
 }
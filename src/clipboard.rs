@@ -0,0 +1,118 @@
+//! Clipboard helpers beyond plain text.
+//!
+//! By default, these use egui's clipboard support, which copies at the end of
+//! the frame and only supports plain text and images. With the `arboard`
+//! feature, they use the system clipboard directly via
+//! [`arboard`](https://docs.rs/arboard), which also supports HTML and
+//! inspecting the clipboard contents. `arboard` is not available on the web, so
+//! the feature does nothing there.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::clipboard;
+//!
+//! if ui.button("Copy").clicked() {
+//!     clipboard::copy_html(ui.ctx(), "<b>bold</b> text", "bold text");
+//! }
+//! # });
+//! ```
+
+/// Returns a handle to the system clipboard, if it is available.
+#[cfg(all(feature = "arboard", not(target_arch = "wasm32")))]
+fn system_clipboard() -> Option<arboard::Clipboard> {
+    arboard::Clipboard::new().ok()
+}
+
+/// Copies HTML to the clipboard, with `alt_text` as a plain-text fallback for
+/// apps that don't accept HTML.
+///
+/// Without the `arboard` feature, only `alt_text` is copied.
+#[cfg_attr(
+    not(all(feature = "arboard", not(target_arch = "wasm32"))),
+    allow(unused_variables)
+)]
+pub fn copy_html(ctx: &egui::Context, html: &str, alt_text: &str) {
+    #[cfg(all(feature = "arboard", not(target_arch = "wasm32")))]
+    if let Some(mut clipboard) = system_clipboard()
+        && clipboard.set_html(html, Some(alt_text)).is_ok()
+    {
+        return;
+    }
+
+    ctx.copy_text(alt_text.to_owned());
+}
+
+/// Copies an image to the clipboard.
+pub fn copy_image(ctx: &egui::Context, image: egui::ColorImage) {
+    #[cfg(all(feature = "arboard", not(target_arch = "wasm32")))]
+    if let Some(mut clipboard) = system_clipboard() {
+        let bytes = image
+            .pixels
+            .iter()
+            .flat_map(|c| c.to_srgba_unmultiplied())
+            .collect::<Vec<u8>>();
+        let [width, height] = image.size;
+        let data = arboard::ImageData {
+            width,
+            height,
+            bytes: bytes.into(),
+        };
+        if clipboard.set_image(data).is_ok() {
+            return;
+        }
+    }
+
+    ctx.copy_image(image);
+}
+
+/// Formats available on the clipboard. See [`available_formats()`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ClipboardFormats {
+    /// Plain text.
+    pub text: bool,
+    /// HTML.
+    pub html: bool,
+    /// Image.
+    pub image: bool,
+    /// List of files, such as from a file manager.
+    pub files: bool,
+}
+impl ClipboardFormats {
+    /// Returns whether no known formats are available.
+    pub fn is_empty(self) -> bool {
+        self == Self::default()
+    }
+
+    /// Returns the names of the available formats, such as for display in a
+    /// tooltip.
+    pub fn names(self) -> Vec<&'static str> {
+        [
+            (self.text, "text"),
+            (self.html, "HTML"),
+            (self.image, "image"),
+            (self.files, "files"),
+        ]
+        .into_iter()
+        .filter_map(|(available, name)| available.then_some(name))
+        .collect()
+    }
+}
+
+/// Returns the formats currently available on the system clipboard, such as
+/// to enable or disable a "Paste" button. **Requires `arboard` feature.**
+///
+/// Returns no formats if the clipboard is unavailable.
+#[cfg(all(feature = "arboard", not(target_arch = "wasm32")))]
+pub fn available_formats() -> ClipboardFormats {
+    let Some(mut clipboard) = system_clipboard() else {
+        return ClipboardFormats::default();
+    };
+    ClipboardFormats {
+        text: clipboard.get().text().is_ok(),
+        html: clipboard.get().html().is_ok(),
+        image: clipboard.get().image().is_ok(),
+        files: clipboard.get().file_list().is_ok(),
+    }
+}
@@ -0,0 +1,316 @@
+//! Searchable list of commands, opened using a keyboard shortcut. **Requires
+//! `command_palette` feature.**
+//!
+//! - Commands are matched using [`crate::util::fuzzy_match()`], with matched
+//!   characters highlighted
+//! - <kbd>↑</kbd> / <kbd>↓</kbd> select a command and <kbd>Enter</kbd> runs it
+//! - Recently used commands are ranked higher, so they come first unless
+//!   another command matches the query much better
+//! - Keyboard shortcuts are shown using
+//!   [`egui::Context::format_shortcut()`], which uses the conventions of the
+//!   current platform, and pressing a command's shortcut runs it even when the
+//!   palette is closed
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::command_palette::{Command, CommandPalette};
+//!
+//! struct App {
+//!     palette: CommandPalette<Vec<String>>,
+//!     notes: Vec<String>,
+//! }
+//!
+//! let mut app = App {
+//!     palette: CommandPalette::new(),
+//!     notes: vec![],
+//! };
+//! app.palette.add(Command::new("New note", |notes: &mut Vec<String>| {
+//!     notes.push(String::new());
+//! }));
+//! app.palette.add(Command::new("Clear notes", Vec::clear));
+//!
+//! // Each frame:
+//! app.palette.show(ui.ctx(), &mut app.notes);
+//! # });
+//! ```
+
+use egui::{Key, KeyboardShortcut, Modifiers};
+
+use crate::util::{FuzzyMatch, fuzzy_match, highlighted_text};
+
+/// Maximum number of recently used commands to remember.
+const MAX_RECENT: usize = 8;
+/// Score added to the most recently used command when searching. Each less
+/// recent command gets one less.
+const RECENT_BONUS: i32 = 10;
+
+/// Command that can be run from a [`CommandPalette`].
+///
+/// `T` is the state passed to the command's action, such as your app.
+pub struct Command<T> {
+    /// Name shown in the palette and used for searching.
+    pub name: String,
+    /// Keyboard shortcut that runs the command.
+    pub shortcut: Option<KeyboardShortcut>,
+    action: Box<dyn FnMut(&mut T)>,
+}
+impl<T> std::fmt::Debug for Command<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Command")
+            .field("name", &self.name)
+            .field("shortcut", &self.shortcut)
+            .finish_non_exhaustive()
+    }
+}
+impl<T> Command<T> {
+    /// Constructs a command.
+    pub fn new(name: impl Into<String>, action: impl FnMut(&mut T) + 'static) -> Self {
+        Self {
+            name: name.into(),
+            shortcut: None,
+            action: Box::new(action),
+        }
+    }
+
+    /// Sets the keyboard shortcut that runs the command.
+    #[must_use]
+    pub fn shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.shortcut = Some(shortcut);
+        self
+    }
+}
+
+/// Modal overlay for searching and running [`Command`]s.
+///
+/// Store this in your app state and call [`CommandPalette::show()`] every
+/// frame.
+#[derive(Debug)]
+pub struct CommandPalette<T> {
+    id: egui::Id,
+    commands: Vec<Command<T>>,
+    open_shortcut: KeyboardShortcut,
+    is_open: bool,
+    query: String,
+    selected: usize,
+    /// Indices of recently used commands, most recent first.
+    recent: Vec<usize>,
+}
+impl<T> Default for CommandPalette<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+impl<T> CommandPalette<T> {
+    /// Constructs an empty command palette, opened using
+    /// <kbd>Ctrl</kbd>+<kbd>Shift</kbd>+<kbd>P</kbd> (or
+    /// <kbd>⌘</kbd>+<kbd>⇧</kbd>+<kbd>P</kbd> on macOS).
+    pub fn new() -> Self {
+        Self {
+            id: egui::Id::new("hcegui::command_palette"),
+            commands: vec![],
+            open_shortcut: KeyboardShortcut::new(Modifiers::COMMAND | Modifiers::SHIFT, Key::P),
+            is_open: false,
+            query: String::new(),
+            selected: 0,
+            recent: vec![],
+        }
+    }
+
+    /// Sets the ID, which must be unique if there are multiple command
+    /// palettes.
+    #[must_use]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id = egui::Id::new(id_salt);
+        self
+    }
+
+    /// Sets the keyboard shortcut that opens the palette.
+    #[must_use]
+    pub fn open_shortcut(mut self, shortcut: KeyboardShortcut) -> Self {
+        self.open_shortcut = shortcut;
+        self
+    }
+
+    /// Adds a command to the palette.
+    pub fn add(&mut self, command: Command<T>) {
+        self.commands.push(command);
+    }
+
+    /// Returns the registered commands.
+    pub fn commands(&self) -> &[Command<T>] {
+        &self.commands
+    }
+
+    /// Returns whether the palette is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the palette with an empty search.
+    pub fn open(&mut self) {
+        self.is_open = true;
+        self.query.clear();
+        self.selected = 0;
+    }
+
+    /// Closes the palette.
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Runs the command at `index` and ranks it first among recent commands.
+    pub fn run(&mut self, index: usize, state: &mut T) {
+        let Some(command) = self.commands.get_mut(index) else {
+            return;
+        };
+        (command.action)(state);
+        self.recent.retain(|&i| i != index);
+        self.recent.insert(0, index);
+        self.recent.truncate(MAX_RECENT);
+    }
+
+    /// Handles keyboard shortcuts and displays the palette if it is open.
+    /// Returns the index of the command that was run, if any.
+    pub fn show(&mut self, ctx: &egui::Context, state: &mut T) -> Option<usize> {
+        if !self.is_open {
+            let index = self.handle_shortcuts(ctx)?;
+            self.run(index, state);
+            return Some(index);
+        }
+
+        let index = self.show_modal(ctx)?;
+        self.close();
+        self.run(index, state);
+        Some(index)
+    }
+
+    /// Opens the palette or returns the index of a command to run when their
+    /// shortcuts are pressed.
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) -> Option<usize> {
+        // Check shortcuts with more modifiers first so that, for example,
+        // Ctrl+Shift+P isn't consumed by Ctrl+P.
+        let modifier_count = |shortcut: &KeyboardShortcut| {
+            let m = shortcut.modifiers;
+            [m.alt, m.ctrl, m.shift, m.mac_cmd, m.command]
+                .into_iter()
+                .filter(|&b| b)
+                .count()
+        };
+        let mut shortcuts: Vec<(Option<usize>, KeyboardShortcut)> = (self.commands.iter())
+            .enumerate()
+            .filter_map(|(i, command)| Some((Some(i), command.shortcut?)))
+            .collect();
+        shortcuts.push((None, self.open_shortcut));
+        shortcuts.sort_by_key(|(_, shortcut)| std::cmp::Reverse(modifier_count(shortcut)));
+
+        let (index, _) = ctx.input_mut(|input| {
+            (shortcuts.into_iter()).find(|(_, shortcut)| input.consume_shortcut(shortcut))
+        })?;
+        if index.is_none() {
+            self.open();
+        }
+        index
+    }
+
+    /// Returns matching commands, best first, with a bonus for recently used
+    /// commands.
+    fn search(&self) -> Vec<(usize, FuzzyMatch)> {
+        let recent_rank = |i: usize| {
+            (self.recent.iter())
+                .position(|&r| r == i)
+                .unwrap_or(usize::MAX)
+        };
+        let score = |i: usize, m: &FuzzyMatch| match recent_rank(i) {
+            usize::MAX => m.score,
+            rank => m.score + RECENT_BONUS - rank as i32,
+        };
+        let mut results: Vec<(usize, FuzzyMatch)> = (self.commands.iter())
+            .enumerate()
+            .filter_map(|(i, command)| Some((i, fuzzy_match(&self.query, &command.name)?)))
+            .collect();
+        results.sort_by_key(|(i, m)| (std::cmp::Reverse(score(*i, m)), recent_rank(*i), *i));
+        results
+    }
+
+    /// Shows the modal and returns the index of a command to run.
+    fn show_modal(&mut self, ctx: &egui::Context) -> Option<usize> {
+        let results = self.search();
+        let mut to_run = None;
+
+        let area = egui::Modal::default_area(self.id)
+            .anchor(egui::Align2::CENTER_TOP, egui::vec2(0.0, 64.0));
+        let modal = egui::Modal::new(self.id).area(area).show(ctx, |ui| {
+            ui.set_width(400.0);
+
+            // Handle navigation before the text edit sees the arrow keys.
+            let (up, down, enter) = ui.input_mut(|input| {
+                (
+                    input.consume_key(Modifiers::NONE, Key::ArrowUp),
+                    input.consume_key(Modifiers::NONE, Key::ArrowDown),
+                    input.consume_key(Modifiers::NONE, Key::Enter),
+                )
+            });
+            let n = results.len();
+            if n > 0 {
+                if up {
+                    self.selected = (self.selected + n - 1) % n;
+                }
+                if down {
+                    self.selected = (self.selected + 1) % n;
+                }
+            }
+
+            let r = ui.add(
+                egui::TextEdit::singleline(&mut self.query)
+                    .hint_text("Type a command…")
+                    .desired_width(f32::INFINITY),
+            );
+            r.request_focus();
+            if r.changed() {
+                self.selected = 0;
+            }
+            self.selected = self.selected.min(n.saturating_sub(1));
+
+            if enter && let Some((i, _)) = results.get(self.selected) {
+                to_run = Some(*i);
+            }
+
+            ui.separator();
+
+            if results.is_empty() {
+                ui.weak("No matching commands");
+                return;
+            }
+            egui::ScrollArea::vertical()
+                .max_height(300.0)
+                .show(ui, |ui| {
+                    for (row, (i, m)) in results.iter().enumerate() {
+                        let command = &self.commands[*i];
+                        let is_selected = row == self.selected;
+                        let text = highlighted_text(ui, &command.name, &m.indices);
+                        let shortcut = (command.shortcut.as_ref())
+                            .map(|s| ctx.format_shortcut(s))
+                            .unwrap_or_default();
+                        let r = ui.add(
+                            egui::Button::selectable(is_selected, text)
+                                .shortcut_text(shortcut)
+                                .min_size(egui::vec2(ui.available_width(), 0.0)),
+                        );
+                        if is_selected && (up || down) {
+                            r.scroll_to_me(None);
+                        }
+                        if r.clicked() {
+                            to_run = Some(*i);
+                        }
+                    }
+                });
+        });
+
+        if modal.should_close() {
+            self.close();
+        }
+        to_run
+    }
+}
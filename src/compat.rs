@@ -0,0 +1,25 @@
+//! Shims for egui APIs that differ between the supported versions of egui.
+
+use std::sync::Arc;
+
+/// Lays out `text` on a single line (except for explicit newlines).
+pub(crate) fn layout_no_wrap(
+    ctx: &egui::Context,
+    text: String,
+    font_id: egui::FontId,
+    color: egui::Color32,
+) -> Arc<egui::Galley> {
+    #[cfg(any(feature = "egui_0_33", feature = "egui_0_34"))]
+    return ctx.fonts_mut(|fonts| fonts.layout_no_wrap(text, font_id, color));
+    #[cfg(not(any(feature = "egui_0_33", feature = "egui_0_34")))]
+    return ctx.fonts(|fonts| fonts.layout_no_wrap(text, font_id, color));
+}
+
+/// Returns the height of a row of text in `font_id`.
+#[cfg(feature = "log_console")]
+pub(crate) fn row_height(ctx: &egui::Context, font_id: &egui::FontId) -> f32 {
+    #[cfg(any(feature = "egui_0_33", feature = "egui_0_34"))]
+    return ctx.fonts_mut(|fonts| fonts.row_height(font_id));
+    #[cfg(not(any(feature = "egui_0_33", feature = "egui_0_34")))]
+    return ctx.fonts(|fonts| fonts.row_height(font_id));
+}
@@ -4,33 +4,58 @@
 //! - Any UI widget or layout can be given a handle for dragging
 //! - Any UI widget or layout can be made a target for dragging
 //! - Any UI widget or layout can be made a target for reordering
+//! - Objects can be reordered in a grid or wrapped layout
 //! - Multiple separate drag-and-drop environments can coexist and even overlap
 //!   in the same UI
+//! - Several selected objects can be dragged at once
+//! - Nodes in a tree can be reordered and nested
 //!
 //! # Examples
 //!
 //! ```
 //! # egui::__run_test_ui(|ui| {
-//! use hcegui::*;
+//! use hcegui::dnd;
 //!
 //! let mut elements = vec!["point", "line", "plane", "space"];
-//! let mut dnd = reorder::Dnd::new(ui.ctx(), ui.next_auto_id());
+//! let mut dnd = dnd::Dnd::new(ui.ctx(), ui.next_auto_id());
 //! for (i, &elem) in elements.iter().enumerate() {
 //!     dnd.reorderable_with_handle(ui, i, |ui, _| ui.label(elem));
 //! }
 //! if let Some(r) = dnd.finish(ui).if_done_dragging() {
-//!     r.reorder_vec(&mut elements);
+//!     r.reorder(&mut elements);
 //! }
 //! # });
 //! ```
 //!
+//! For a simple list like this one, [`reorderable_list()`] does all of this in
+//! one call. For cards in several columns, use [`kanban::Kanban`], and for a
+//! row of tabs, use [`tab_bar::TabBar`].
+//!
 //! For more advanced examples, see
-//! [`bin/demo/reorder.rs`](https://github.com/HactarCE/hcegui/blob/main/src/bin/demo/reorder.rs).
+//! [`bin/demo/dnd.rs`](https://github.com/HactarCE/hcegui/blob/main/src/bin/demo/dnd.rs).
+
+pub mod kanban;
+pub mod tab_bar;
+
+mod group;
+mod swap;
+#[cfg(feature = "extras")]
+mod table;
+mod tree;
+
+pub use swap::{SwapDnd, SwapDndMove};
+pub use tree::{TreeDnd, TreeDndMove, TreeMove, TreeNode, TreeTarget};
 
+use std::borrow::Cow;
 use std::hash::Hash;
 
+use smallvec::SmallVec;
+
+use group::DndGroup;
+
 /// Whether the payload should be placed before or after the target.
 #[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[allow(missing_docs)]
 pub enum BeforeOrAfter {
     Before,
@@ -39,6 +64,8 @@ pub enum BeforeOrAfter {
 
 /// Styling for [`Dnd`].
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct DndStyle {
     /// Rounding of hole left behind by the payload.
     pub payload_hole_rounding: f32,
@@ -46,12 +73,52 @@ pub struct DndStyle {
     pub payload_hole_opacity: f32,
     /// Opacity of dragged payload.
     pub payload_opacity: f32,
+    /// Scale of dragged payload, relative to its center.
+    pub payload_scale: f32,
+    /// Angle in radians by which the dragged payload is rotated clockwise
+    /// around its center.
+    ///
+    /// The payload is tessellated in order to rotate it, so this should only
+    /// be used for payloads that don't contain [`egui::PaintCallback`]s.
+    pub payload_rotation: f32,
+    /// Shadow behind the dragged payload, with the same rounding as the hole
+    /// left behind by it.
+    pub payload_shadow: Option<egui::Shadow>,
+    /// Color painted over the dragged payload while releasing it would not drop
+    /// it anywhere, such as a translucent red. If `None`, the payload is not
+    /// tinted.
+    pub invalid_target_tint: Option<egui::Color32>,
     /// Width of non-reorder drop zone stroke.
     pub drop_zone_stroke_width: f32,
     /// Rounding of non-reorder drop zones.
     pub drop_zone_rounding: f32,
+    /// Animation of the stroke of the drop zone under the payload.
+    pub drop_zone_animation: DropZoneAnimation,
     /// Width of reorder drop zone line stroke.
     pub reorder_stroke_width: f32,
+    /// Whether items added using [`Dnd::reorderable()`] shift apart to open a
+    /// gap where the payload will be inserted, instead of drawing a line.
+    pub reorder_gap: bool,
+    /// Whether items added using [`Dnd::reorderable()`] animate into place
+    /// after the payload is dropped, instead of jumping there.
+    pub settle_animation: bool,
+    /// Whether the payload animates back to where it was picked up from when
+    /// it is dropped outside any target or the drag is cancelled, instead of
+    /// jumping there.
+    pub snap_back_animation: bool,
+    /// Stroke color of the drop zone under the payload. If `None`, the
+    /// [`egui::style::Widgets::active`] stroke color is used.
+    pub drop_zone_active_color: Option<egui::Color32>,
+    /// Stroke color of other drop zones that accept the payload. If `None`, the
+    /// [`egui::style::Widgets::noninteractive`] stroke color is used.
+    pub drop_zone_inactive_color: Option<egui::Color32>,
+    /// Color of the line drawn where the payload will be inserted. If `None`,
+    /// the [`egui::style::Widgets::active`] stroke color is used.
+    pub reorder_line_color: Option<egui::Color32>,
+    /// Background color of the hole left behind by the payload, before
+    /// applying [`DndStyle::payload_hole_opacity`]. If `None`, the
+    /// [`egui::style::Widgets::hovered`] background color is used.
+    pub payload_hole_color: Option<egui::Color32>,
 }
 impl Default for DndStyle {
     fn default() -> Self {
@@ -59,13 +126,215 @@ impl Default for DndStyle {
             payload_hole_rounding: 3.0,
             payload_hole_opacity: 0.25,
             payload_opacity: 1.0,
+            payload_scale: 1.0,
+            payload_rotation: 0.0,
+            payload_shadow: None,
+            invalid_target_tint: None,
             drop_zone_stroke_width: 2.0,
             drop_zone_rounding: 3.0,
+            drop_zone_animation: DropZoneAnimation::None,
             reorder_stroke_width: 2.0,
+            reorder_gap: false,
+            settle_animation: false,
+            snap_back_animation: false,
+            drop_zone_active_color: None,
+            drop_zone_inactive_color: None,
+            reorder_line_color: None,
+            payload_hole_color: None,
+        }
+    }
+}
+impl DndStyle {
+    /// Returns the default style with all colors taken from `visuals`, so that
+    /// they don't change if the visuals change later.
+    pub fn from_visuals(visuals: &egui::Visuals) -> Self {
+        Self {
+            drop_zone_active_color: Some(visuals.widgets.active.bg_stroke.color),
+            drop_zone_inactive_color: Some(visuals.widgets.noninteractive.bg_stroke.color),
+            reorder_line_color: Some(visuals.widgets.active.bg_stroke.color),
+            payload_hole_color: Some(visuals.widgets.hovered.bg_fill),
+            ..Self::default()
+        }
+    }
+
+    fn payload_hole_fill(&self, visuals: &egui::Visuals) -> egui::Color32 {
+        (self
+            .payload_hole_color
+            .unwrap_or(visuals.widgets.hovered.bg_fill))
+        .gamma_multiply(self.payload_hole_opacity)
+    }
+}
+
+/// Text displayed or announced to screen readers by [`Dnd`], such as for
+/// translating it.
+///
+/// In the announcements about positions, `{index}` is replaced by the 1-based
+/// index of an item added using [`Dnd::reorderable()`] and `{count}` is
+/// replaced by the number of such items.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct DndText {
+    /// Label of the trash zone. See [`Dnd::trash_zone()`].
+    pub trash_zone_label: Cow<'static, str>,
+    /// Description of the trash zone for screen readers.
+    pub trash_zone_description: Cow<'static, str>,
+    /// Default description of a [`ReorderHandle`] for screen readers.
+    pub drag_handle_description: Cow<'static, str>,
+    /// Description for screen readers of the drag handles added by
+    /// [`Dnd::reorderable_with_handle()`], which can also be moved using the
+    /// keyboard.
+    pub reorder_handle_description: Cow<'static, str>,
+    /// Announcement when picking up the payload from a reorderable list.
+    pub picked_up_item: Cow<'static, str>,
+    /// Announcement when picking up any other payload.
+    pub picked_up: Cow<'static, str>,
+    /// Announcement when the payload moves before an item in a reorderable
+    /// list.
+    pub moved_before_item: Cow<'static, str>,
+    /// Announcement when the payload moves after an item in a reorderable
+    /// list.
+    pub moved_after_item: Cow<'static, str>,
+    /// Announcement when the payload is dropped on a target.
+    pub dropped: Cow<'static, str>,
+    /// Announcement when the payload is dropped on a trash zone.
+    pub deleted: Cow<'static, str>,
+    /// Announcement when the payload is dropped outside the drop area. See
+    /// [`Dnd::with_drag_out_rect()`].
+    pub dropped_outside: Cow<'static, str>,
+    /// Announcement when the drag is cancelled.
+    pub cancelled: Cow<'static, str>,
+}
+impl Default for DndText {
+    fn default() -> Self {
+        Self {
+            trash_zone_label: "🗑 Delete".into(),
+            trash_zone_description: "Drop here to delete".into(),
+            drag_handle_description: "Drag to move".into(),
+            reorder_handle_description: "Drag to reorder, or press Alt and an arrow key".into(),
+            picked_up_item: "Picked up item {index} of {count}".into(),
+            picked_up: "Picked up item".into(),
+            moved_before_item: "Moved before item {index}".into(),
+            moved_after_item: "Moved after item {index}".into(),
+            dropped: "Dropped".into(),
+            deleted: "Deleted".into(),
+            dropped_outside: "Dropped outside".into(),
+            cancelled: "Cancelled".into(),
+        }
+    }
+}
+impl DndText {
+    /// Replaces `{index}` and `{count}` in `template`.
+    fn format_position(template: &str, index: usize, count: usize) -> String {
+        (template.replace("{index}", &(index + 1).to_string()))
+            .replace("{count}", &count.to_string())
+    }
+}
+
+/// Animation of the stroke of the drop zone under the payload, to make it
+/// easier to notice. See [`DndStyle::drop_zone_animation`].
+///
+/// Animations are disabled while [reduced motion](crate::anim::reduced_motion)
+/// is enabled.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DropZoneAnimation {
+    /// Solid stroke.
+    #[default]
+    None,
+    /// Solid stroke that fades in and out.
+    Pulse,
+    /// Dashed stroke whose dashes move around the drop zone. This ignores
+    /// [`DndStyle::drop_zone_rounding`].
+    MarchingAnts,
+}
+impl DropZoneAnimation {
+    /// Returns the shape of the stroke around `rect` at time `time` in
+    /// seconds.
+    fn stroke_shape(
+        self,
+        rect: egui::Rect,
+        rounding: f32,
+        stroke: egui::Stroke,
+        time: f64,
+    ) -> egui::Shape {
+        /// Time in seconds for one pulse.
+        const PULSE_PERIOD: f64 = 1.2;
+        /// Length of each dash and gap.
+        const DASH: f32 = 6.0;
+        const GAP: f32 = 4.0;
+        /// Speed of the dashes in points per second.
+        const ANTS_SPEED: f64 = 20.0;
+
+        match self {
+            DropZoneAnimation::None => {
+                egui::epaint::RectShape::stroke(rect, rounding, stroke, egui::StrokeKind::Outside)
+                    .into()
+            }
+            DropZoneAnimation::Pulse => {
+                let phase = (time / PULSE_PERIOD).fract() as f32 * std::f32::consts::TAU;
+                let opacity = 0.65 + 0.35 * phase.cos();
+                let stroke = egui::Stroke::new(stroke.width, stroke.color.gamma_multiply(opacity));
+                Self::None.stroke_shape(rect, rounding, stroke, time)
+            }
+            DropZoneAnimation::MarchingAnts => {
+                // Match `StrokeKind::Outside`.
+                let r = rect.expand(stroke.width / 2.0);
+                let path = [
+                    r.left_top(),
+                    r.right_top(),
+                    r.right_bottom(),
+                    r.left_bottom(),
+                    r.left_top(),
+                ];
+                let offset = (time * ANTS_SPEED) as f32 % (DASH + GAP);
+                let dashes = egui::Shape::dashed_line_with_offset(
+                    &path,
+                    stroke,
+                    &[DASH],
+                    &[GAP],
+                    DASH + GAP - offset,
+                );
+                egui::Shape::Vec(dashes)
+            }
         }
     }
 }
 
+fn default_style_id() -> egui::Id {
+    egui::Id::new("hcegui::dnd::default_style")
+}
+
+/// Sets the style used by [`Dnd`]s that don't override it using
+/// [`Dnd::with_style()`]. Default: [`DndStyle::default()`].
+pub fn set_default_style(ctx: &egui::Context, style: DndStyle) {
+    ctx.data_mut(|data| data.insert_temp(default_style_id(), style));
+}
+
+/// Returns the style used by [`Dnd`]s that don't override it. See
+/// [`set_default_style()`].
+pub fn default_style(ctx: &egui::Context) -> DndStyle {
+    ctx.data(|data| data.get_temp(default_style_id()))
+        .unwrap_or_default()
+}
+
+fn debug_paint_id() -> egui::Id {
+    egui::Id::new("hcegui::dnd::debug_paint")
+}
+
+/// Sets whether all [`Dnd`]s paint debug information while dragging. See
+/// [`Dnd::with_debug_paint()`].
+pub fn set_debug_paint(ctx: &egui::Context, debug_paint: bool) {
+    ctx.data_mut(|data| data.insert_temp(debug_paint_id(), debug_paint));
+}
+
+/// Returns whether all [`Dnd`]s paint debug information while dragging. See
+/// [`set_debug_paint()`].
+pub fn debug_paint(ctx: &egui::Context) -> bool {
+    ctx.data(|data| data.get_temp(debug_paint_id()))
+        .unwrap_or(false)
+}
+
 /// Drag-and-drop environment.
 ///
 /// - `Payload` is a type that identifies the things being dragged.
@@ -83,14 +352,121 @@ pub struct Dnd<Payload, Target> {
     id: egui::Id,
     /// Styling
     pub style: DndStyle,
+    /// Distance from the edge of the enclosing [`egui::ScrollArea`] within
+    /// which dragging a payload scrolls it. Set to zero to disable.
+    pub auto_scroll_margin: f32,
+    /// Whether to scroll the enclosing [`egui::ScrollArea`] to show where the
+    /// payload was dropped.
+    pub scroll_to_drop: bool,
+    /// Distance that the pointer must move after pressing a drag handle before
+    /// dragging starts.
+    pub drag_threshold: f32,
+    /// Time in seconds that a drag handle must be held before dragging starts.
+    pub drag_delay: f32,
+    /// Whether clicking a drag handle picks up the payload until the next
+    /// click.
+    pub sticky_drag: bool,
+    /// Axes along which the initial motion of a touch on a drag handle starts
+    /// dragging. Motion along other axes scrolls the enclosing
+    /// [`egui::ScrollArea`] instead, unless the handle is held first.
+    pub touch_drag_axis: egui::Vec2b,
+    /// Pointer button that cancels the drag when pressed while dragging.
+    pub cancel_button: Option<egui::PointerButton>,
+    /// Pointer buttons that can start a drag, indexed by
+    /// [`egui::PointerButton`].
+    drag_buttons: [bool; egui::NUM_POINTER_BUTTONS],
+    /// Rectangle outside which releasing the payload returns
+    /// [`DndResponse::DraggedOut`] instead of cancelling the drag.
+    pub drag_out_rect: Option<egui::Rect>,
+    /// Rectangle to clip the payload to while dragging.
+    pub payload_clip_rect: Option<egui::Rect>,
+    /// Time in seconds that the payload must hover a spring-loaded widget
+    /// before it springs. See [`Dnd::spring_loaded()`].
+    pub spring_load_delay: f32,
+    /// Distance that the pointer must move past the midpoint between two
+    /// reorder drop zones before the target changes, to keep the reorder line
+    /// from flickering.
+    pub reorder_hysteresis: f32,
+    /// Whether to announce picking up, moving, and dropping the payload to
+    /// screen readers using [`crate::a11y::announce()`].
+    pub announce_drags: bool,
+    /// Text displayed or announced to screen readers.
+    pub text: DndText,
+    /// Whether to paint drop zones, reorder drop zones, and the position of
+    /// the payload on top of everything while dragging.
+    pub debug_paint: bool,
     /// State persisted between frames.
     current_drag: Option<DndDragState>,
     /// Payload value being dragged.
     payload: Option<Payload>,
     /// Target where the payload is being hovered.
     target: Option<Target>,
+    /// Location of `target`.
+    hover: Option<DndHover>,
     /// Locations where the payload can be dropped for reordering.
-    reorder_drop_zones: Vec<ReorderTarget<Target>>,
+    reorder_drop_zones: SmallVec<[ReorderTarget<Target>; 8]>,
+    /// Most recent item added using [`Dnd::reorderable()`].
+    last_reorderable: Option<LastReorderable<Payload>>,
+    /// Move made using the keyboard this frame.
+    keyboard_move: Option<DndMove<Payload, Target>>,
+    /// Payload to move after the next item added using
+    /// [`Dnd::reorderable()`].
+    keyboard_move_later: Option<Payload>,
+    /// ID of the draggable object that is being dragged but has not yet passed
+    /// the drag threshold or delay.
+    pending_drag: Option<egui::Id>,
+    /// Distance to scroll the enclosing [`egui::ScrollArea`] this frame, if a
+    /// touch on a drag handle is scrolling instead of dragging. See
+    /// [`Dnd::with_touch_drag_axis()`].
+    touch_scroll: Option<egui::Vec2>,
+    /// Group for dragging between `Dnd`s. See [`Dnd::with_group()`].
+    group: Option<DndGroup<Payload>>,
+    /// Sharing payloads with [`egui::DragAndDrop`]. See
+    /// [`Dnd::with_egui_drag_and_drop()`].
+    egui_dnd: Option<EguiDnd<Payload>>,
+    /// Returns the indices for [`DndDragState::preview`]. See
+    /// [`Dnd::with_live_preview()`].
+    live_preview: Option<fn(&Payload, &Target) -> (usize, usize)>,
+    /// Drop zones added this frame, which are painted once the payload is
+    /// known.
+    drop_zones: SmallVec<[DropZone<Target>; 4]>,
+    /// Whether a payload may be dropped on a target.
+    accept: Option<AcceptFn<Payload, Target>>,
+    /// Functions called when a drag starts or ends.
+    hooks: DndHooks<Payload, Target>,
+    /// Function that paints the hole left behind by the payload. See
+    /// [`Dnd::with_hole_painter()`].
+    paint_hole: Option<HolePaintFn>,
+    /// Function that paints over the UI while dragging. See
+    /// [`Dnd::with_drag_painter()`].
+    paint_drag: Option<DragPaintFn>,
+    /// Rectangles of the dragged object in its original place and of the
+    /// payload following the pointer, if the object was added this frame.
+    payload_rects: Option<(egui::Rect, egui::Rect)>,
+    /// Items added using [`Dnd::reorderable()`] this frame while dragging, in
+    /// order, for [`DndStyle::settle_animation`] and [`Dnd::announce_drags`].
+    reorder_items: SmallVec<[(egui::Id, egui::Rect); 8]>,
+    /// Whether the drag started this frame, in which case
+    /// `reorder_items` is missing the items added before the payload.
+    drag_started_this_frame: bool,
+    /// Animation of items moving into place after the previous drop.
+    settle: Option<SettleAnimation>,
+    /// Number of items added using [`Dnd::reorderable()`] so far this frame.
+    reorder_item_count: usize,
+    /// IDs of the payload and its descendants added using
+    /// [`Dnd::tree_node()`] so far this frame.
+    tree_payload_nodes: SmallVec<[egui::Id; 4]>,
+    /// Files from outside the application hovering or dropped on a drop zone
+    /// added using [`Dnd::os_file_drop_zone()`].
+    os_files: OsFileDndResponse<Target>,
+    /// Section for objects and drop zones added next. See
+    /// [`Dnd::set_section()`].
+    section: Option<egui::Id>,
+    /// Whether a trash zone added using [`Dnd::trash_zone()`] is the target.
+    trash_hovered: bool,
+    /// Number of objects added using [`Dnd::pinned()`] so far in the current
+    /// section.
+    pin_segment: usize,
 }
 impl<Payload, Target> Dnd<Payload, Target> {
     /// Constructs a new drag-and-drop context.
@@ -98,11 +474,33 @@ impl<Payload, Target> Dnd<Payload, Target> {
     pub fn new(ctx: &egui::Context, id: impl Into<egui::Id>) -> Self {
         let id = id.into();
 
-        let (last_frame_was_unfinished, state) = ctx.data_mut(|data| {
+        let (
+            last_frame_was_unfinished,
+            state,
+            style,
+            pending_drag,
+            touch_scroll,
+            settle,
+            debug_paint,
+        ) = ctx.data_mut(|data| {
             let last_frame_was_unfinished = data.remove_temp::<()>(id).is_some();
             data.insert_temp(id, ()); // marker that `finish()` has not been called yet
             let state = data.remove_temp::<DndDragState>(id);
-            (last_frame_was_unfinished, state)
+            let style = data.get_temp(default_style_id()).unwrap_or_default();
+            let pending_drag = data.get_temp::<PendingDrag>(id).map(|p| p.0);
+            data.remove::<PendingDrag>(id);
+            let touch_scroll = data.remove_temp::<TouchScroll>(id).is_some();
+            let settle = data.get_temp::<SettleAnimation>(id);
+            let debug_paint = data.get_temp(debug_paint_id()).unwrap_or(false);
+            (
+                last_frame_was_unfinished,
+                state,
+                style,
+                pending_drag,
+                touch_scroll,
+                settle,
+                debug_paint,
+            )
         });
         assert!(
             !last_frame_was_unfinished,
@@ -113,17 +511,65 @@ impl<Payload, Target> Dnd<Payload, Target> {
             ctx: ctx.clone(),
 
             id,
-            style: DndStyle::default(),
+            style,
+            auto_scroll_margin: 32.0,
+            scroll_to_drop: false,
+            drag_threshold: 0.0,
+            drag_delay: 0.0,
+            sticky_drag: false,
+            touch_drag_axis: egui::Vec2b::TRUE,
+            cancel_button: Some(egui::PointerButton::Secondary),
+            drag_buttons: pointer_buttons(&[egui::PointerButton::Primary]),
+            drag_out_rect: None,
+            payload_clip_rect: None,
+            spring_load_delay: 0.7,
+            reorder_hysteresis: 2.0,
+            announce_drags: true,
+            text: DndText::default(),
+            debug_paint,
             current_drag: state,
             payload: None,
             target: None,
-            reorder_drop_zones: vec![],
+            hover: None,
+            reorder_drop_zones: SmallVec::new(),
+            last_reorderable: None,
+            keyboard_move: None,
+            keyboard_move_later: None,
+            pending_drag,
+            touch_scroll: None,
+            group: None,
+            egui_dnd: None,
+            live_preview: None,
+            drop_zones: SmallVec::new(),
+            accept: None,
+            hooks: DndHooks::default(),
+            paint_hole: None,
+            paint_drag: None,
+            payload_rects: None,
+            tree_payload_nodes: SmallVec::new(),
+            os_files: DndResponse::Inactive,
+            reorder_items: SmallVec::new(),
+            drag_started_this_frame: false,
+            settle,
+            reorder_item_count: 0,
+            section: None,
+            trash_hovered: false,
+            pin_segment: 0,
         };
 
+        let is_click_to_drop =
+            (this.current_drag.as_ref()).is_some_and(|s| s.click_to_drop.is_some());
         ctx.input(|input| {
-            if !(input.pointer.any_down() || input.pointer.any_released()) {
+            if !(input.pointer.any_down() || input.pointer.any_released() || is_click_to_drop) {
                 // Done dragging -> delete payload
                 this.current_drag = None;
+                this.pending_drag = None;
+            } else if touch_scroll {
+                this.touch_scroll = Some(input.pointer.delta());
+            }
+            if let Some(state) = &mut this.current_drag {
+                state.start_time.get_or_insert(input.time);
+                state.distance += input.pointer.delta().length();
             }
         });
 
@@ -137,6 +583,447 @@ impl<Payload, Target> Dnd<Payload, Target> {
         self
     }
 
+    /// Sets the distance from the edge of the enclosing [`egui::ScrollArea`]
+    /// within which dragging a payload scrolls it. Default: 32. Set to zero to
+    /// disable.
+    ///
+    /// The edges of the scroll area are found using the clip rectangle of the
+    /// [`egui::Ui`] passed to [`Dnd::finish()`], so call it from inside the
+    /// scroll area.
+    #[must_use]
+    pub fn with_auto_scroll_margin(mut self, margin: f32) -> Self {
+        self.auto_scroll_margin = margin;
+        self
+    }
+
+    /// Sets whether to scroll the enclosing [`egui::ScrollArea`] after the
+    /// payload is dropped so that it is visible at its new position. Default:
+    /// `false`.
+    ///
+    /// This is useful for long lists, where the payload may be dropped far from
+    /// where the drag started. The scroll area is scrolled to show the target,
+    /// so call [`Dnd::finish()`] from inside the scroll area.
+    #[must_use]
+    pub fn with_scroll_to_drop(mut self, scroll_to_drop: bool) -> Self {
+        self.scroll_to_drop = scroll_to_drop;
+        self
+    }
+
+    /// Shares payloads with [`egui::DragAndDrop`], which is used by
+    /// [`egui::Ui::dnd_drag_source()`], [`egui::Ui::dnd_drop_zone()`], and
+    /// other crates.
+    ///
+    /// While a payload from this `Dnd` is being dragged, it is also the
+    /// `egui::DragAndDrop` payload. If it is dropped on a drop zone that takes
+    /// it from `egui::DragAndDrop`, then this `Dnd` returns
+    /// [`DndResponse::DroppedElsewhere`] with [`egui::Id::NULL`] as the
+    /// target. This only works if the drop zone is shown before calling
+    /// [`Dnd::finish()`].
+    ///
+    /// A payload of type `Payload` dragged using `egui::DragAndDrop` can be
+    /// dropped on the targets of this `Dnd`, which returns
+    /// [`DndResponse::DoneDragging`]. To drag payloads between `Dnd`s, use
+    /// [`Dnd::with_group()`] instead.
+    #[must_use]
+    pub fn with_egui_drag_and_drop(mut self) -> Self
+    where
+        Payload: Clone + Send + Sync + 'static,
+    {
+        // Ignore payloads shared by `Dnd`s.
+        let pass_nr = self.ctx.cumulative_pass_nr();
+        let from_dnd = (self.ctx.data(|data| data.get_temp::<u64>(egui_source_id())))
+            .is_some_and(|source_pass_nr| source_pass_nr + 1 >= pass_nr);
+
+        let mut is_receiving = false;
+        if self.current_drag.is_none()
+            && !from_dnd
+            && let Some(payload) = egui::DragAndDrop::payload::<Payload>(&self.ctx)
+            && let Some(pointer_pos) = self.ctx.pointer_interact_pos()
+        {
+            self.current_drag = Some(DndDragState {
+                payload_id: egui::Id::NULL,
+                drop_pos: pointer_pos,
+                ..Default::default()
+            });
+            self.payload = Some(Payload::clone(&payload));
+            is_receiving = true;
+        }
+
+        self.egui_dnd = Some(EguiDnd {
+            share: |ctx, payload| egui::DragAndDrop::set_payload(ctx, payload.clone()),
+            is_receiving,
+        });
+        self
+    }
+
+    /// Returns whether the payload came from another `Dnd` in the group or
+    /// from [`egui::DragAndDrop`], instead of from this `Dnd`.
+    fn is_receiving(&self) -> bool {
+        self.group.as_ref().is_some_and(|group| group.is_receiving)
+            || self.egui_dnd.as_ref().is_some_and(|e| e.is_receiving)
+    }
+
+    /// Sets a function that returns whether a payload may be dropped on a
+    /// target.
+    ///
+    /// Targets that do not accept the payload are never highlighted and never
+    /// returned in [`DndResponse::MidDrag`] or [`DndResponse::DoneDragging`].
+    #[must_use]
+    pub fn with_accept(mut self, accept: impl Fn(&Payload, &Target) -> bool + 'static) -> Self {
+        self.accept = Some(AcceptFn(Box::new(accept)));
+        self
+    }
+
+    /// Sets a function that paints the hole left behind by the payload while
+    /// it is dragged, given the rectangle of the hole, instead of filling it
+    /// using [`DndStyle::payload_hole_color`].
+    ///
+    /// This is useful for drawing a dashed outline or a hint such as "Drop here
+    /// to cancel".
+    #[must_use]
+    pub fn with_hole_painter(
+        mut self,
+        paint: impl Fn(&egui::Painter, egui::Rect) + 'static,
+    ) -> Self {
+        self.paint_hole = Some(HolePaintFn(Box::new(paint)));
+        self
+    }
+
+    /// Sets a function that is called each frame while dragging to paint over
+    /// the UI, given the geometry of the drag.
+    ///
+    /// This is useful for drawing an arrow from the original place of the
+    /// object to the target. The painter is on a layer above the UI but below
+    /// the payload.
+    #[must_use]
+    pub fn with_drag_painter(
+        mut self,
+        paint: impl Fn(&egui::Painter, &DndDragGeometry) + 'static,
+    ) -> Self {
+        self.paint_drag = Some(DragPaintFn(Box::new(paint)));
+        self
+    }
+
+    /// Puts the objects and drop zones added after this into `section`, until
+    /// the next call to [`Dnd::set_section()`] or [`Dnd::clear_section()`].
+    ///
+    /// Objects dragged from a section may only be dropped on drop zones in the
+    /// same section or not in any section, and objects that are not in any
+    /// section may be dropped anywhere. This is useful for separate lists that
+    /// share one `Dnd`, such as the sections of a settings list. Drop zones in
+    /// other sections are still painted, but never become the target.
+    pub fn set_section(&mut self, section: impl Hash) {
+        self.section = Some(self.id.with(section));
+        self.pin_segment = 0;
+        // Keyboard reordering doesn't cross sections.
+        self.last_reorderable = None;
+        self.keyboard_move_later = None;
+    }
+
+    /// Stops putting objects and drop zones into a section. See
+    /// [`Dnd::set_section()`].
+    pub fn clear_section(&mut self) {
+        self.section = None;
+        self.pin_segment = 0;
+        self.last_reorderable = None;
+        self.keyboard_move_later = None;
+    }
+
+    /// Adds an object that can't be dragged and is never displaced by dragging
+    /// other objects, such as a header that always stays first.
+    ///
+    /// Objects can't be dragged past a pinned object, so as long as the pinned
+    /// object is shown at its own index, [`ReorderDndMove::reorder()`] never
+    /// moves it. Objects dragged from another `Dnd` in the same group may
+    /// still be dropped anywhere.
+    pub fn pinned<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> egui::InnerResponse<R> {
+        self.pin_segment += 1;
+        // Keyboard reordering doesn't cross pinned objects.
+        self.last_reorderable = None;
+        self.keyboard_move_later = None;
+        ui.scope(add_contents)
+    }
+
+    /// Returns whether `payload` may be dropped on `target`.
+    fn accepts(&self, payload: &Payload, target: &Target) -> bool {
+        self.accept.as_ref().is_none_or(|f| (f.0)(payload, target))
+    }
+
+    /// Sets a function that is called once when a payload starts being
+    /// dragged from this `Dnd`.
+    ///
+    /// This is useful for playing sounds, logging, or setting a flag while
+    /// dragging, along with [`Dnd::on_drag_end()`].
+    #[must_use]
+    pub fn on_drag_start(mut self, f: impl Fn(&Payload) + 'static) -> Self {
+        self.hooks.drag_start = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a function that is called once when a payload is dropped on a
+    /// target of this `Dnd`, when [`Dnd::finish()`] returns
+    /// [`DndResponse::DoneDragging`].
+    #[must_use]
+    pub fn on_drop(mut self, f: impl Fn(&DndMove<Payload, Target>) + 'static) -> Self {
+        self.hooks.drop = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a function that is called once when a drag from this `Dnd` is
+    /// cancelled, either using <kbd>Escape</kbd> or the cancel button or by
+    /// releasing the payload where it can't be dropped.
+    #[must_use]
+    pub fn on_cancel(mut self, f: impl Fn(&Payload) + 'static) -> Self {
+        self.hooks.cancel = Some(Box::new(f));
+        self
+    }
+
+    /// Sets a function that is called once when a drag from this `Dnd` ends
+    /// for any reason, after [`Dnd::on_drop()`] or [`Dnd::on_cancel()`].
+    #[must_use]
+    pub fn on_drag_end(mut self, f: impl Fn() + 'static) -> Self {
+        self.hooks.drag_end = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the distance that the pointer must move after pressing a drag
+    /// handle before dragging starts. Default: 0.
+    ///
+    /// This prevents small movements while clicking on widgets inside a
+    /// draggable object from starting a drag.
+    #[must_use]
+    pub fn with_drag_threshold(mut self, distance: f32) -> Self {
+        self.drag_threshold = distance;
+        self
+    }
+
+    /// Sets the time in seconds that a drag handle must be held before
+    /// dragging starts. Default: 0.
+    ///
+    /// This is useful on touchscreens, where dragging might otherwise conflict
+    /// with scrolling.
+    #[must_use]
+    pub fn with_drag_delay(mut self, seconds: f32) -> Self {
+        self.drag_delay = seconds;
+        self
+    }
+
+    /// Sets the axes along which the initial motion of a touch on a drag handle
+    /// starts dragging. Default: both.
+    ///
+    /// If the touch first moves along another axis, then it scrolls the
+    /// enclosing [`egui::ScrollArea`] instead. Holding the handle still for a
+    /// long press starts dragging in any direction. This is useful for lists
+    /// in scroll areas on touchscreens, such as
+    /// `with_touch_drag_axis(egui::Vec2b::new(true, false))` for a vertical
+    /// list that scrolls when swiped vertically.
+    ///
+    /// This has no effect on mouse input.
+    #[must_use]
+    pub fn with_touch_drag_axis(mut self, axis: egui::Vec2b) -> Self {
+        self.touch_drag_axis = axis;
+        self
+    }
+
+    /// Sets the pointer buttons that can start a drag by dragging a drag
+    /// handle. Default: only the primary (left) mouse button.
+    ///
+    /// This is useful when another button is used for something else, such as
+    /// `with_drag_buttons(&[egui::PointerButton::Middle])` when dragging with
+    /// the primary button draws a selection rectangle.
+    #[must_use]
+    pub fn with_drag_buttons(mut self, buttons: &[egui::PointerButton]) -> Self {
+        self.drag_buttons = pointer_buttons(buttons);
+        self
+    }
+
+    /// Sets the pointer button that cancels the drag when pressed while
+    /// dragging, like pressing <kbd>Escape</kbd>. Default: the secondary
+    /// (right) mouse button. If `None`, only <kbd>Escape</kbd> cancels the
+    /// drag.
+    ///
+    /// This has no effect if the button is one of the
+    /// [drag buttons](Dnd::with_drag_buttons()).
+    #[must_use]
+    pub fn with_cancel_button(mut self, button: Option<egui::PointerButton>) -> Self {
+        self.cancel_button = button;
+        self
+    }
+
+    /// Sets whether clicking a drag handle picks up the payload, so that it
+    /// follows the pointer without holding the button until the next click
+    /// drops it. Pressing <kbd>Escape</kbd> cancels the drag. Dragging the
+    /// handle still works as usual. Default: `false`.
+    ///
+    /// This is useful for users who find it difficult to hold the button while
+    /// moving the pointer, such as on a trackpad.
+    #[must_use]
+    pub fn with_sticky_drag(mut self, sticky_drag: bool) -> Self {
+        self.sticky_drag = sticky_drag;
+        self
+    }
+
+    /// Sets the rectangle outside which releasing the payload returns
+    /// [`DndResponse::DraggedOut`], such as the rectangle of the whole window
+    /// to detect dragging out of it. Default: `None`.
+    ///
+    /// This is useful for detaching a tab into a new window.
+    #[must_use]
+    pub fn with_drag_out_rect(mut self, rect: egui::Rect) -> Self {
+        self.drag_out_rect = Some(rect);
+        self
+    }
+
+    /// Sets the rectangle to clip the payload to while dragging, such as the
+    /// [`egui::Ui::clip_rect()`] of the enclosing panel or window. Default:
+    /// `None`, which lets the payload be painted anywhere on the screen.
+    ///
+    /// This is useful for keeping the payload from escaping a small side
+    /// panel.
+    #[must_use]
+    pub fn with_payload_clip_rect(mut self, rect: egui::Rect) -> Self {
+        self.payload_clip_rect = Some(rect);
+        self
+    }
+
+    /// Sets the time in seconds that the payload must hover a spring-loaded
+    /// widget before it springs. Default: 0.7. See [`Dnd::spring_loaded()`].
+    #[must_use]
+    pub fn with_spring_load_delay(mut self, seconds: f32) -> Self {
+        self.spring_load_delay = seconds;
+        self
+    }
+
+    /// Sets the distance that the pointer must move past the midpoint between
+    /// two reorder drop zones before the target changes. Default: 2.0.
+    #[must_use]
+    pub fn with_reorder_hysteresis(mut self, distance: f32) -> Self {
+        self.reorder_hysteresis = distance;
+        self
+    }
+
+    /// Sets whether to announce picking up, moving, and dropping the payload
+    /// to screen readers. Default: `true`.
+    #[must_use]
+    pub fn with_announce_drags(mut self, announce_drags: bool) -> Self {
+        self.announce_drags = announce_drags;
+        self
+    }
+
+    /// Overrides the text displayed or announced to screen readers, such as
+    /// for translating it.
+    #[must_use]
+    pub fn with_text(mut self, text: DndText) -> Self {
+        self.text = text;
+        self
+    }
+
+    /// Sets whether to paint debug information on top of everything while
+    /// dragging. Default: `false`, or the value set using
+    /// [`set_debug_paint()`].
+    ///
+    /// Drop zones are outlined in green if they are the target, blue if they
+    /// accept the payload, and red if they don't. Reorder drop zones are shown
+    /// as thin yellow lines, with the target in green. The position used to
+    /// find the target is shown as a circle.
+    #[must_use]
+    pub fn with_debug_paint(mut self, debug_paint: bool) -> Self {
+        self.debug_paint = debug_paint;
+        self
+    }
+
+    /// Returns whether the pointer has moved far enough and been held long
+    /// enough to start dragging, requesting a repaint if it is only waiting
+    /// for the delay.
+    fn is_past_drag_threshold(&self) -> bool {
+        let (distance, elapsed) = self.ctx.input(|input| {
+            let distance = match (input.pointer.press_origin(), input.pointer.interact_pos()) {
+                (Some(origin), Some(pos)) => origin.distance(pos),
+                _ => 0.0,
+            };
+            let elapsed =
+                (input.pointer.press_start_time()).map_or(0.0, |start| (input.time - start) as f32);
+            (distance, elapsed)
+        });
+        if distance < self.drag_threshold {
+            return false;
+        }
+        if elapsed < self.drag_delay {
+            let remaining = self.drag_delay - elapsed;
+            (self.ctx).request_repaint_after(std::time::Duration::from_secs_f32(remaining));
+            return false;
+        }
+        true
+    }
+
+    /// Returns whether a touch on a drag handle should start dragging or
+    /// scroll instead. See [`Dnd::with_touch_drag_axis()`].
+    fn touch_gesture(&self) -> TouchGesture {
+        if self.touch_drag_axis == egui::Vec2b::TRUE {
+            return TouchGesture::Drag;
+        }
+        let input_options = self.ctx.options(|options| options.input_options);
+        let (is_touch, motion, elapsed) = self.ctx.input(|input| {
+            let motion = match (input.pointer.press_origin(), input.pointer.interact_pos()) {
+                (Some(origin), Some(pos)) => pos - origin,
+                _ => egui::Vec2::ZERO,
+            };
+            let elapsed =
+                (input.pointer.press_start_time()).map_or(0.0, |start| input.time - start);
+            (input.any_touches(), motion, elapsed)
+        });
+        if !is_touch || elapsed >= input_options.max_click_duration {
+            // mouse input or long press
+            TouchGesture::Drag
+        } else if motion.length() < input_options.max_click_dist {
+            let remaining = input_options.max_click_duration - elapsed;
+            (self.ctx).request_repaint_after(std::time::Duration::from_secs_f64(remaining));
+            TouchGesture::Undecided
+        } else if (motion.x.abs() >= motion.y.abs() && self.touch_drag_axis.x)
+            || (motion.y.abs() >= motion.x.abs() && self.touch_drag_axis.y)
+        {
+            TouchGesture::Drag
+        } else {
+            TouchGesture::Scroll(motion)
+        }
+    }
+
+    /// Starts dragging `payload` from code instead of by dragging a handle,
+    /// such as from a "Move" entry in a context menu. `payload_id` is the ID of
+    /// the draggable object, which is passed to `add_contents` by methods such
+    /// as [`Dnd::draggable()`]. Does nothing if something is already being
+    /// dragged.
+    ///
+    /// The payload follows the pointer until the next click, which drops it,
+    /// or until <kbd>Escape</kbd> is pressed. The payload can't be dropped on
+    /// other `Dnd`s in a group.
+    ///
+    /// In a list using [`ReorderDnd::reorderable_hidden_rows()`], starting a
+    /// drag on a row that has not been shown using [`Dnd::reorderable()`] is
+    /// unsupported.
+    pub fn begin_drag(&mut self, payload_id: egui::Id, payload: Payload) {
+        if self.is_dragging() {
+            return;
+        }
+        let pointer_pos = self.ctx.pointer_latest_pos().unwrap_or_default();
+        self.pending_drag = None;
+        self.current_drag = Some(DndDragState {
+            payload_id,
+            drop_pos: pointer_pos,
+            click_to_drop: Some(false),
+            ..Default::default()
+        });
+        self.drag_started_this_frame = true;
+        if let Some(f) = &self.hooks.drag_start {
+            f(&payload);
+        }
+        self.payload = Some(payload);
+    }
+
     /// Returns whether there is an active drag in this context.
     pub fn is_dragging(&self) -> bool {
         self.current_drag.is_some()
@@ -145,6 +1032,21 @@ impl<Payload, Target> Dnd<Payload, Target> {
     pub fn payload_id(&self) -> Option<egui::Id> {
         self.current_drag.as_ref().map(|state| state.payload_id)
     }
+    /// Returns measurements of the active drag, if there is one.
+    ///
+    /// This is still available on the frame when the payload is released, so
+    /// it can be used before calling [`Dnd::finish()`] to detect gestures such
+    /// as flinging the payload away.
+    pub fn drag_metrics(&self) -> Option<DndDragMetrics> {
+        let state = self.current_drag.as_ref()?;
+        self.ctx.input(|input| {
+            Some(DndDragMetrics {
+                distance: state.distance,
+                elapsed: state.start_time.map_or(0.0, |t| (input.time - t) as f32),
+                velocity: input.pointer.velocity(),
+            })
+        })
+    }
 
     /// Allows the `Dnd` to be dropped without calling `finish()`.
     ///
@@ -166,79 +1068,370 @@ impl<Payload, Target> Dnd<Payload, Target> {
         payload: Payload,
         add_contents: impl FnOnce(&mut egui::Ui) -> (egui::Response, R),
     ) -> egui::InnerResponse<R> {
+        self.draggable_impl(ui, id, payload, add_contents, None).0
+    }
+
+    /// Adds a new draggable object with a custom ID, optionally with a
+    /// separate overlay to show while dragging it. Also returns the response
+    /// for the drag handle, if the object is not being dragged.
+    fn draggable_impl<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        payload: Payload,
+        add_contents: impl FnOnce(&mut egui::Ui) -> (egui::Response, R),
+        overlay: Option<&mut dyn FnMut(&mut egui::Ui)>,
+    ) -> (egui::InnerResponse<R>, Option<egui::Response>) {
+        // Objects are shown in a child UI, which can't wrap on its own, so wrap
+        // based on the size from the previous frame.
+        let wrap = ui.layout().main_wrap;
+        if wrap {
+            let size = ui.data(|data| data.get_temp::<WrappedItemSize>(id));
+            let (available, max) = (ui.available_size_before_wrap(), ui.max_rect().size());
+            let axis = usize::from(ui.layout().main_dir().is_vertical());
+            if let Some(WrappedItemSize(size)) = size
+                && available[axis] < size[axis]
+                && size[axis] <= max[axis]
+            {
+                ui.end_row();
+            }
+        }
+
+        let (r, drag_handle_response) =
+            self.draggable_impl_unwrapped(ui, id, payload, add_contents, overlay);
+        if wrap {
+            let size = WrappedItemSize(r.response.rect.size());
+            ui.data_mut(|data| data.insert_temp(id, size));
+        }
+        (r, drag_handle_response)
+    }
+
+    /// Implementation of [`Dnd::draggable_impl()`] after wrapping.
+    fn draggable_impl_unwrapped<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        id: egui::Id,
+        payload: Payload,
+        add_contents: impl FnOnce(&mut egui::Ui) -> (egui::Response, R),
+        overlay: Option<&mut dyn FnMut(&mut egui::Ui)>,
+    ) -> (egui::InnerResponse<R>, Option<egui::Response>) {
         let state = self
             .current_drag
             .as_mut()
             .filter(|state| state.payload_id == id);
 
         if ui.is_sizing_pass() {
-            ui.scope(|ui| add_contents(ui).1)
+            (ui.scope(|ui| add_contents(ui).1), None)
         } else if let Some(state) = state {
             ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
             self.payload = Some(payload);
 
-            // Paint the widget to a different layer so that we can move it
-            // around independently. Highlight the widget so that it looks like
-            // it's still being hovered.
+            // Paint the widget (or its overlay) to a different layer so that we
+            // can move it around independently. Highlight the widget so that it
+            // looks like it's still being hovered.
             let layer_id = egui::LayerId::new(egui::Order::Tooltip, id);
-            let r = ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
-                ui.set_opacity(self.style.payload_opacity);
-                // `push_id()` is a workaround for https://github.com/emilk/egui/issues/2253
-                ui.push_id(id, |ui| add_contents(ui)).inner
-            });
-            let (_, return_value) = r.inner;
+            let tint = (self.style.invalid_target_tint).filter(|_| state.over_invalid_target);
+            let rounding = self.style.payload_hole_rounding;
+            let has_overlay = overlay.is_some();
+            let (r, ghost_rect, (shadow_shape, stack_shape)) = match overlay {
+                None => {
+                    let r = ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
+                        ui.set_opacity(self.style.payload_opacity);
+                        let shadow_shape = ui.painter().add(egui::Shape::Noop);
+                        let stack_shape = ui.painter().add(egui::Shape::Noop);
+                        // `push_id()` is a workaround for https://github.com/emilk/egui/issues/2253
+                        let inner = ui.push_id(id, |ui| add_contents(ui)).inner;
+                        if let Some(tint) = tint {
+                            ui.painter().rect_filled(ui.min_rect(), rounding, tint);
+                        }
+                        ((shadow_shape, stack_shape), inner)
+                    });
+                    let (shapes, (_, return_value)) = r.inner;
+                    let rect = r.response.rect;
+                    (
+                        egui::InnerResponse::new(return_value, r.response),
+                        rect,
+                        shapes,
+                    )
+                }
+                Some(overlay) => {
+                    let r = ui.scope(|ui| {
+                        ui.set_invisible();
+                        ui.push_id(id, |ui| add_contents(ui).1).inner
+                    });
+                    let max_rect =
+                        egui::Rect::from_min_size(r.response.rect.min, egui::Vec2::INFINITY);
+                    let ui_builder = egui::UiBuilder::new()
+                        .layer_id(layer_id)
+                        .max_rect(max_rect)
+                        .layout(egui::Layout::top_down(egui::Align::Min));
+                    let overlay_r = ui.scope_builder(ui_builder, |ui| {
+                        ui.set_opacity(self.style.payload_opacity);
+                        let shadow_shape = ui.painter().add(egui::Shape::Noop);
+                        let stack_shape = ui.painter().add(egui::Shape::Noop);
+                        ui.push_id(id.with("overlay"), overlay);
+                        if let Some(tint) = tint {
+                            ui.painter().rect_filled(ui.min_rect(), rounding, tint);
+                        }
+                        (shadow_shape, stack_shape)
+                    });
+                    (r, overlay_r.response.rect, overlay_r.inner)
+                }
+            };
+
+            if !state.others.is_empty() {
+                paint_payload_stack(ui, layer_id, stack_shape, ghost_rect, state.others.len());
+            }
 
-            ui.painter().rect_filled(
+            let visuals = ui.visuals();
+            let paint_hole = self.paint_hole.as_ref();
+            paint_payload_hole(
+                ui.painter(),
                 r.response.rect,
-                self.style.payload_hole_rounding,
-                (ui.visuals().widgets.hovered.bg_fill)
-                    .gamma_multiply(self.style.payload_hole_opacity),
+                visuals,
+                &self.style,
+                paint_hole,
             );
+            self.payload_rects = Some((r.response.rect, ghost_rect));
 
             if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
-                let delta = pointer_pos + state.cursor_offset - r.response.rect.left_top();
-                ui.ctx().transform_layer_shapes(
-                    layer_id,
-                    egui::emath::TSTransform::from_translation(delta),
-                );
-                state.drop_pos = r.response.rect.center() + delta;
+                let mut cursor_offset = state.cursor_offset;
+                if state.click_to_drop.is_some() {
+                    // The payload isn't being held, so center it on the pointer.
+                    cursor_offset = -ghost_rect.size() / 2.0;
+                } else if has_overlay {
+                    // Keep the pointer over the overlay, which may be smaller
+                    // than the widget.
+                    cursor_offset = cursor_offset.max(-ghost_rect.size());
+                }
+                let delta = pointer_pos + cursor_offset - ghost_rect.left_top();
+                lift_payload(ui, &self.style, layer_id, shadow_shape, ghost_rect, delta);
+                if let Some(clip_rect) = self.payload_clip_rect {
+                    clip_layer_shapes(ui.ctx(), layer_id, clip_rect);
+                }
+                state.drop_pos = ghost_rect.center() + delta;
+                state.payload_size = r.response.rect.size();
+                self.payload_rects = Some((r.response.rect, ghost_rect.translate(delta)));
             }
 
-            egui::InnerResponse::new(return_value, r.response)
+            (r, None)
         } else {
-            // We must use `.scope()` *and* `.push_id()` so that the IDs are all
-            // the same as the other case.
-            let r = ui.scope(|ui| ui.push_id(id, |ui| add_contents(ui)).inner);
+            let snap_back = (self.settle.as_ref())
+                .and_then(|settle| settle.snap_back)
+                .filter(|&(payload_id, _)| payload_id == id);
+            let r = match snap_back {
+                // We must use `.scope()` *and* `.push_id()` so that the IDs are
+                // all the same as the other case.
+                None => ui.scope(|ui| ui.push_id(id, |ui| add_contents(ui)).inner),
+                Some((_, from)) => {
+                    // Paint the widget above everything else on its way back.
+                    let layer_id = egui::LayerId::new(egui::Order::Tooltip, id);
+                    let r = ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
+                        ui.push_id(id, |ui| add_contents(ui)).inner
+                    });
+                    if let Some(settle) = &self.settle {
+                        let delta =
+                            (from.min - r.response.rect.min) * settle.progress.get(ui.ctx());
+                        ui.ctx().transform_layer_shapes(
+                            layer_id,
+                            egui::emath::TSTransform::from_translation(delta),
+                        );
+                    }
+                    r
+                }
+            };
             let (drag_handle_response, return_value) = r.inner;
+            let drag_handle_response =
+                self.handle_drag_start(id, || payload, drag_handle_response, r.response.rect);
+            (
+                egui::InnerResponse::new(return_value, r.response),
+                Some(drag_handle_response),
+            )
+        }
+    }
 
-            // Ensure that the drag handle detects drags
-            let drag_handle_response = drag_handle_response.interact(egui::Sense::drag());
+    /// Starts dragging the object with ID `id` if its drag handle is being
+    /// dragged, where `rect` is the rectangle of the whole object. `payload` is
+    /// only called if the drag starts. Returns the drag handle response,
+    /// modified to sense drags.
+    fn handle_drag_start(
+        &mut self,
+        id: egui::Id,
+        payload: impl FnOnce() -> Payload,
+        drag_handle_response: egui::Response,
+        rect: egui::Rect,
+    ) -> egui::Response {
+        // Ensure that the drag handle detects drags, and clicks in sticky mode
+        let senses_click = drag_handle_response.sense.senses_click();
+        let sense = match self.sticky_drag {
+            true => egui::Sense::click_and_drag(),
+            false => egui::Sense::drag(),
+        };
+        let drag_handle_response = drag_handle_response.interact(sense);
 
-            if !drag_handle_response.sense.senses_click() && drag_handle_response.hovered() {
-                ui.ctx().set_cursor_icon(egui::CursorIcon::Grab);
-            }
+        if !senses_click && drag_handle_response.hovered() && !self.is_dragging() {
+            self.ctx.set_cursor_icon(egui::CursorIcon::Grab);
+        }
 
-            if drag_handle_response.drag_started()
-                && let Some(interact_pos) = drag_handle_response.interact_pointer_pos()
-            {
-                let cursor_offset = r.response.rect.left_top() - interact_pos;
-                self.current_drag = Some(DndDragState {
-                    payload_id: id,
-                    cursor_offset,
-                    drop_pos: r.response.rect.center(),
-                });
-                self.payload = Some(payload);
+        let is_pending = self.pending_drag == Some(id);
+        let is_drag_button = |button| self.drag_buttons[button as usize];
+        let is_starting = (drag_handle_response.drag_started() || is_pending)
+            && POINTER_BUTTONS
+                .iter()
+                .any(|&button| is_drag_button(button) && drag_handle_response.dragged_by(button))
+            && !self.is_dragging();
+        let is_picked_up = self.sticky_drag
+            && POINTER_BUTTONS
+                .iter()
+                .any(|&button| is_drag_button(button) && drag_handle_response.clicked_by(button))
+            && !self.is_dragging();
+        let touch_gesture = match is_starting {
+            true => self.touch_gesture(),
+            false => TouchGesture::Drag,
+        };
+        if is_starting
+            && (!self.is_past_drag_threshold() || touch_gesture == TouchGesture::Undecided)
+        {
+            self.pending_drag = Some(id);
+        } else if let TouchGesture::Scroll(motion) = touch_gesture {
+            self.pending_drag = None;
+            self.touch_scroll = Some(motion);
+        } else if (is_starting || is_picked_up)
+            && let Some(interact_pos) = drag_handle_response.interact_pointer_pos()
+        {
+            self.pending_drag = None;
+            let cursor_offset = rect.left_top() - interact_pos;
+            let (time, press_origin) =
+                (self.ctx).input(|input| (input.time, input.pointer.press_origin()));
+            self.current_drag = Some(DndDragState {
+                payload_id: id,
+                cursor_offset,
+                drop_pos: rect.center(),
+                payload_size: rect.size(),
+                gap: None,
+                gaps: SmallVec::new(),
+                others: SmallVec::new(),
+                reorder_zone: None,
+                section: self.section,
+                pin_segment: Some(self.pin_segment),
+                announced_pick_up: false,
+                announced_gap: None,
+                over_invalid_target: false,
+                shared_with_egui: false,
+                click_to_drop: is_picked_up.then_some(false),
+                preview: None,
+                start_time: Some(time),
+                distance: press_origin.map_or(0.0, |origin| origin.distance(interact_pos)),
+            });
+            self.drag_started_this_frame = true;
+            let payload = payload();
+            if let Some(f) = &self.hooks.drag_start {
+                f(&payload);
             }
-
-            egui::InnerResponse::new(return_value, r.response)
+            self.payload = Some(payload);
         }
+
+        drag_handle_response
     }
 
-    /// Adds a new draggable object, using `payload` for the ID.
-    ///
-    /// `add_contents` takes the [`egui::Ui`] and the ID of the current
-    /// draggable element. If it is equal to [`Dnd::payload_id()`], then the
-    /// current element is being dragged.
+    /// Adds a new draggable object, using `payload` for the ID, that shows
+    /// `overlay` under the pointer instead of `add_contents` while it is being
+    /// dragged. See [`Dnd::draggable()`].
+    ///
+    /// This is useful for large widgets, which can be represented by something
+    /// more compact while dragging.
+    pub fn draggable_with_overlay<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        payload: Payload,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+        overlay: impl FnOnce(&mut egui::Ui),
+    ) -> egui::InnerResponse<R>
+    where
+        Payload: Hash,
+    {
+        let id = self.id.with(&payload);
+        let mut overlay = Some(overlay);
+        let mut overlay = |ui: &mut egui::Ui| {
+            if let Some(f) = overlay.take() {
+                f(ui);
+            }
+        };
+        let add_contents = |ui: &mut egui::Ui| add_contents(ui, id);
+        self.draggable_impl(ui, id, payload, add_contents, Some(&mut overlay))
+            .0
+    }
+
+    /// Adds a drag source that stays in place and creates a new payload when it
+    /// is dragged, using `id_salt` for the ID.
+    ///
+    /// This is useful for palettes, such as a sidebar of node types that can be
+    /// dragged into a document. `make_payload` is only called while the source
+    /// is being dragged, so each drag produces a fresh payload.
+    ///
+    /// `add_contents` is the same as for [`Dnd::draggable()`], except that it
+    /// is called a second time to show a copy of the source under the pointer
+    /// while it is being dragged.
+    pub fn drag_source_factory<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        id_salt: impl Hash,
+        make_payload: impl FnOnce() -> Payload,
+        mut add_contents: impl FnMut(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let id = self.id.with(id_salt);
+        let r = ui.scope(|ui| ui.push_id(id, |ui| add_contents(ui, id)).inner);
+        let (drag_handle_response, return_value) = r.inner;
+        let r = egui::InnerResponse::new(return_value, r.response);
+        if ui.is_sizing_pass() {
+            return r;
+        }
+
+        let state = self
+            .current_drag
+            .as_mut()
+            .filter(|state| state.payload_id == id);
+        if let Some(state) = state {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+            self.payload = Some(make_payload());
+
+            // Paint a copy of the source to a different layer so that we can
+            // move it around independently.
+            let layer_id = egui::LayerId::new(egui::Order::Tooltip, id);
+            let ui_builder = egui::UiBuilder::new()
+                .layer_id(layer_id)
+                .max_rect(r.response.rect);
+            let tint = (self.style.invalid_target_tint).filter(|_| state.over_invalid_target);
+            let r_ghost = ui.scope_builder(ui_builder, |ui| {
+                ui.set_opacity(self.style.payload_opacity);
+                let shadow_shape = ui.painter().add(egui::Shape::Noop);
+                ui.push_id(id.with("ghost"), |ui| add_contents(ui, id));
+                if let Some(tint) = tint {
+                    let rounding = self.style.payload_hole_rounding;
+                    ui.painter().rect_filled(ui.min_rect(), rounding, tint);
+                }
+                shadow_shape
+            });
+            let ghost_rect = r_ghost.response.rect;
+
+            if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
+                let delta = pointer_pos + state.cursor_offset - ghost_rect.left_top();
+                lift_payload(ui, &self.style, layer_id, r_ghost.inner, ghost_rect, delta);
+                state.drop_pos = ghost_rect.center() + delta;
+                state.payload_size = ghost_rect.size();
+            }
+        } else {
+            self.handle_drag_start(id, make_payload, drag_handle_response, r.response.rect);
+        }
+
+        r
+    }
+
+    /// Adds a new draggable object, using `payload` for the ID.
+    ///
+    /// `add_contents` takes the [`egui::Ui`] and the ID of the current
+    /// draggable element. If it is equal to [`Dnd::payload_id()`], then the
+    /// current element is being dragged.
     ///
     /// The first value returned by `add_contents` is used as the response for
     /// the drag handle, which may be any widget or region that does not use
@@ -259,72 +1452,760 @@ impl<Payload, Target> Dnd<Payload, Target> {
     /// Add a drop zone onto an existing widget.
     ///
     /// `target` is a value representing this drop zone.
+    ///
+    /// If drop zones overlap, then the payload is dropped on the smallest one,
+    /// or the one added last if they are the same size. Use
+    /// [`Dnd::drop_zone_with_priority()`] to override this.
     pub fn drop_zone(&mut self, ui: &mut egui::Ui, r: &egui::Response, target: Target) {
-        if ui.is_sizing_pass() {
-            return;
+        self.drop_zone_impl(ui, r.rect, r.interact_rect, Some(target), 0, None);
+    }
+
+    /// Adds a drop zone onto an existing widget that takes precedence over
+    /// overlapping drop zones with lower priority. See [`Dnd::drop_zone()`].
+    ///
+    /// The default priority is 0.
+    pub fn drop_zone_with_priority(
+        &mut self,
+        ui: &mut egui::Ui,
+        r: &egui::Response,
+        target: Target,
+        priority: i32,
+    ) {
+        let target = Some(target);
+        self.drop_zone_impl(ui, r.rect, r.interact_rect, target, priority, None);
+    }
+
+    /// Adds a drop zone onto an existing widget, which is painted using `paint`
+    /// instead of an outline. See [`Dnd::drop_zone()`].
+    ///
+    /// `paint` is called with the rectangle of the drop zone and whether the
+    /// payload is hovering it. Shapes that it paints appear just above the
+    /// widget.
+    pub fn drop_zone_with_painter(
+        &mut self,
+        ui: &mut egui::Ui,
+        r: &egui::Response,
+        target: Target,
+        paint: impl FnOnce(&egui::Painter, egui::Rect, bool) + 'static,
+    ) {
+        self.drop_zone_impl(
+            ui,
+            r.rect,
+            r.interact_rect,
+            Some(target),
+            0,
+            Some(Box::new(paint)),
+        );
+    }
+
+    /// Adds a drop zone within `rect` that uses `contains` to test whether the
+    /// payload is over it, instead of testing whether it is inside `rect`. See
+    /// [`Dnd::drop_zone()`].
+    ///
+    /// `contains` is only called with positions inside `rect`, which is used
+    /// for painting the outline and for choosing between overlapping drop
+    /// zones. This is useful for targets that aren't rectangles, such as the
+    /// cells of a hexagonal grid.
+    pub fn drop_zone_custom(
+        &mut self,
+        ui: &mut egui::Ui,
+        rect: egui::Rect,
+        target: Target,
+        contains: impl Fn(egui::Pos2) -> bool + 'static,
+    ) {
+        if let Some(zone) = self.drop_zone_impl(ui, rect, rect, Some(target), 0, None) {
+            zone.contains = Some(Box::new(contains));
+        }
+    }
+
+    /// Makes an existing widget spring-loaded: if the payload hovers it for
+    /// [`Dnd::spring_load_delay`], then `on_spring` is called once. Returns
+    /// whether it was called.
+    ///
+    /// This is useful for opening a collapsed [`egui::CollapsingHeader`] or
+    /// switching tabs so that the payload can be dropped inside.
+    pub fn spring_loaded(
+        &self,
+        ui: &egui::Ui,
+        r: &egui::Response,
+        on_spring: impl FnOnce(),
+    ) -> bool {
+        let Some((hover, elapsed)) = self.hover_time(ui, self.id, r, self.spring_load_delay) else {
+            return false;
+        };
+        let springs = !hover.has_sprung && elapsed >= self.spring_load_delay;
+        let hover = SpringHover {
+            has_sprung: hover.has_sprung || springs,
+            ..hover
+        };
+        ui.data_mut(|data| data.insert_temp(self.id, hover));
+
+        if springs {
+            on_spring();
+        }
+        springs
+    }
+
+    /// Adds a drop zone onto an existing widget that calls `on_dwell` on each
+    /// frame once the payload has hovered it for `delay` seconds. Returns
+    /// whether `on_dwell` was called. See [`Dnd::drop_zone()`].
+    ///
+    /// This is useful for showing a preview while the payload hovers a target,
+    /// such as a popup with the contents of a folder.
+    ///
+    /// The delay only counts while the drop zone is the target, so `on_dwell`
+    /// is not called for drop zones that don't accept the payload, are covered
+    /// by a nested drop zone, or are in another section. The target is chosen
+    /// in [`Dnd::finish()`], so `on_dwell` is called from the frame after the
+    /// delay elapses.
+    pub fn drop_zone_with_hover_delay(
+        &mut self,
+        ui: &mut egui::Ui,
+        r: &egui::Response,
+        target: Target,
+        delay: f32,
+        on_dwell: impl FnOnce(),
+    ) -> bool {
+        let dwells = self.current_drag.as_ref().is_some_and(|state| {
+            let now = ui.input(|input| input.time);
+            let pass_nr = ui.ctx().cumulative_pass_nr();
+            let hover = ui.data(|data| data.get_temp::<DwellHover>(self.dwell_id(r.id)));
+            !ui.is_sizing_pass()
+                && r.interact_rect.contains(state.drop_pos)
+                && hover.is_some_and(|hover| {
+                    hover.pass_nr + 1 >= pass_nr && now - hover.since >= delay as f64
+                })
+        });
+        let target = Some(target);
+        if let Some(zone) = self.drop_zone_impl(ui, r.rect, r.interact_rect, target, 0, None) {
+            zone.dwell = Some((r.id, delay));
+        }
+        if dwells {
+            on_dwell();
+        }
+        dwells
+    }
+
+    /// Returns the ID used to store the [`DwellHover`] for a drop zone added
+    /// using [`Dnd::drop_zone_with_hover_delay()`] onto `widget_id`.
+    fn dwell_id(&self, widget_id: egui::Id) -> egui::Id {
+        self.id.with(("dwell", widget_id))
+    }
+
+    /// Returns the widget that the payload is hovering, stored in egui memory
+    /// at `data_id`, and how long it has been hovering it in seconds, or `None`
+    /// if the payload is not hovering `r`. Requests a repaint after `delay` if
+    /// it has not elapsed yet.
+    ///
+    /// The caller must store the returned value at `data_id`.
+    fn hover_time(
+        &self,
+        ui: &egui::Ui,
+        data_id: egui::Id,
+        r: &egui::Response,
+        delay: f32,
+    ) -> Option<(SpringHover, f32)> {
+        let state = self.current_drag.as_ref()?;
+        if ui.is_sizing_pass() || !r.interact_rect.contains(state.drop_pos) {
+            return None;
+        }
+
+        let now = ui.input(|input| input.time);
+        let pass_nr = ui.ctx().cumulative_pass_nr();
+        let hover = ui.data(|data| data.get_temp::<SpringHover>(data_id));
+        let hover = match hover {
+            Some(hover) if hover.id == r.id && hover.pass_nr + 1 >= pass_nr => hover,
+            // Start timing when the payload moves onto the widget.
+            _ => SpringHover {
+                id: r.id,
+                since: now,
+                pass_nr,
+                has_sprung: false,
+            },
+        };
+        let elapsed = (now - hover.since) as f32;
+        if elapsed < delay {
+            let remaining = delay - elapsed;
+            ui.ctx()
+                .request_repaint_after(std::time::Duration::from_secs_f32(remaining));
         }
+        Some((SpringHover { pass_nr, ..hover }, elapsed))
+    }
 
+    /// Adds a target for deleting the payload, which is only shown while
+    /// dragging. Dropping the payload on it returns [`DndResponse::Deleted`]
+    /// from [`Dnd::finish()`].
+    ///
+    /// The trash zone fills the available width and is highlighted while the
+    /// payload is over it. It takes priority over any other target, like a
+    /// drop zone added using [`Dnd::drop_zone_with_priority()`] with priority
+    /// [`i32::MAX`]. Returns `None` if nothing is being dragged.
+    pub fn trash_zone(&mut self, ui: &mut egui::Ui) -> Option<egui::Response> {
         if !self.is_dragging() {
-            return;
+            return None;
+        }
+
+        let size = egui::vec2(ui.available_width(), ui.spacing().interact_size.y * 2.0);
+        let (rect, r) = ui.allocate_exact_size(size, egui::Sense::hover());
+        crate::a11y::describe(
+            &r,
+            egui::WidgetType::Other,
+            &self.text.trash_zone_description,
+        );
+
+        let visuals = ui.visuals();
+        let error_color = visuals.error_fg_color;
+        let inactive_color = (self.style.drop_zone_inactive_color)
+            .unwrap_or(visuals.widgets.noninteractive.bg_stroke.color);
+        let text_color = visuals.text_color();
+        let rounding = self.style.drop_zone_rounding;
+        let stroke_width = self.style.drop_zone_stroke_width;
+        let label = self.text.trash_zone_label.clone();
+        let font_id = egui::TextStyle::Button.resolve(ui.style());
+        let paint = move |painter: &egui::Painter, rect: egui::Rect, is_active: bool| {
+            let (fill, color, text_color) = if is_active {
+                (error_color.gamma_multiply(0.2), error_color, error_color)
+            } else {
+                (egui::Color32::TRANSPARENT, inactive_color, text_color)
+            };
+            let stroke = egui::Stroke::new(stroke_width, color);
+            painter.rect(rect, rounding, fill, stroke, egui::StrokeKind::Inside);
+            let align = egui::Align2::CENTER_CENTER;
+            painter.text(rect.center(), align, label, font_id, text_color);
+        };
+        // Don't delete the payload when the trash zone is scrolled out of view.
+        let interact_rect = r.interact_rect.intersect(ui.clip_rect());
+        let paint = Some(Box::new(paint) as DropZonePaintFn);
+        if let Some(zone) = self.drop_zone_impl(ui, rect, interact_rect, None, i32::MAX, paint) {
+            // Payloads from any section can be deleted.
+            zone.section = None;
         }
 
-        let color = ui.visuals().widgets.active.bg_stroke.color;
-        let width = self.style.drop_zone_stroke_width;
-        let active_stroke = egui::Stroke { width, color };
+        Some(r)
+    }
 
-        let color = ui.visuals().widgets.noninteractive.bg_stroke.color;
-        let inactive_stroke = egui::Stroke { width, color };
+    /// Adds a drop zone onto an existing widget for files dragged in from
+    /// outside the application, such as from the OS file manager. Use
+    /// [`Dnd::take_os_file_response()`] to get the files.
+    ///
+    /// `target` is a value representing this drop zone.
+    pub fn os_file_drop_zone(&mut self, ui: &egui::Ui, r: &egui::Response, target: Target) {
+        if ui.is_sizing_pass() {
+            return;
+        }
 
-        let is_active = self
-            .current_drag
-            .as_ref()
-            .is_some_and(|s| r.interact_rect.contains(s.drop_pos));
+        let (is_hovering, is_dropping, pointer_pos) = ui.input(|input| {
+            let raw = &input.raw;
+            let pointer_pos = input.pointer.latest_pos();
+            (
+                !raw.hovered_files.is_empty(),
+                !raw.dropped_files.is_empty(),
+                pointer_pos,
+            )
+        });
+        if !is_hovering && !is_dropping {
+            return;
+        }
+        let is_active = pointer_pos.is_some_and(|pos| r.interact_rect.contains(pos));
 
-        if is_active {
-            self.target = Some(target);
+        if is_dropping {
+            if is_active {
+                let files = ui.input(|input| input.raw.dropped_files.clone());
+                self.os_files = DndResponse::DoneDragging(DndMove::new(files, target));
+            }
+            return;
         }
 
-        let stroke = if is_active {
-            active_stroke
+        let color = if is_active {
+            (self.style.drop_zone_active_color)
+                .unwrap_or(ui.visuals().widgets.active.bg_stroke.color)
         } else {
-            inactive_stroke
+            (self.style.drop_zone_inactive_color)
+                .unwrap_or(ui.visuals().widgets.noninteractive.bg_stroke.color)
         };
-
         ui.painter().rect_stroke(
             r.rect,
             self.style.drop_zone_rounding,
-            stroke,
+            egui::Stroke::new(self.style.drop_zone_stroke_width, color),
             egui::StrokeKind::Outside,
         );
+
+        if is_active || matches!(self.os_files, DndResponse::Inactive) {
+            // Hovered files only have a path and MIME type.
+            let files = ui.input(|input| {
+                (input.raw.hovered_files.iter())
+                    .map(|file| egui::DroppedFile {
+                        path: file.path.clone(),
+                        mime: file.mime.clone(),
+                        ..Default::default()
+                    })
+                    .collect()
+            });
+            let target = is_active.then_some(target);
+            self.os_files = DndResponse::MidDrag(DndMove::new(files, target));
+        }
+    }
+
+    /// Returns the files from outside the application that are hovering or
+    /// were just dropped on a drop zone added using
+    /// [`Dnd::os_file_drop_zone()`].
+    ///
+    /// While hovering, only [`egui::DroppedFile::path`] and
+    /// [`egui::DroppedFile::mime`] are set, and the target is `None` if the
+    /// files are not over any drop zone. Files dropped outside every drop zone
+    /// are ignored.
+    pub fn take_os_file_response(&mut self) -> OsFileDndResponse<Target> {
+        std::mem::take(&mut self.os_files)
+    }
+
+    /// Adds a drop zone that is painted using `paint`, or an outline if `paint`
+    /// is `None`.
+    fn drop_zone_impl(
+        &mut self,
+        ui: &mut egui::Ui,
+        rect: egui::Rect,
+        interact_rect: egui::Rect,
+        target: Option<Target>,
+        priority: i32,
+        paint: Option<DropZonePaintFn>,
+    ) -> Option<&mut DropZone<Target>> {
+        if ui.is_sizing_pass() {
+            return None;
+        }
+
+        if !self.is_dragging() {
+            return None;
+        }
+
+        // The payload may not be known yet, so paint the drop zone later.
+        let shape_idx = ui.painter().add(egui::Shape::Noop);
+        self.drop_zones.push(DropZone {
+            painter: ui.painter().clone(),
+            shape_idx,
+            rect,
+            interact_rect,
+            target,
+            priority,
+            section: self.section,
+            active_color: (self.style.drop_zone_active_color)
+                .unwrap_or(ui.visuals().widgets.active.bg_stroke.color),
+            inactive_color: (self.style.drop_zone_inactive_color)
+                .unwrap_or(ui.visuals().widgets.noninteractive.bg_stroke.color),
+            paint,
+            contains: None,
+            dwell: None,
+        });
+        self.drop_zones.last_mut()
+    }
+
+    /// Paints the drop zones and sets the target to the one containing the drop
+    /// position that accepts `payload` with the highest priority, then the
+    /// smallest area, then the one added last.
+    ///
+    /// Drop zones in a section other than `state.section` are painted but never
+    /// become the target, and neither do drop zones covered by another layer,
+    /// such as a window.
+    fn finish_drop_zones(&mut self, payload: Option<&Payload>, state: &DndDragState) {
+        let zones = std::mem::take(&mut self.drop_zones);
+        let top_layer = self.ctx.layer_id_at(state.drop_pos);
+        let accepted: SmallVec<[bool; 4]> = (zones.iter())
+            .map(|zone| {
+                // Trash zones accept any payload.
+                payload.is_some_and(|p| zone.target.as_ref().is_none_or(|t| self.accepts(p, t)))
+            })
+            .collect();
+        let active_index = (zones.iter().enumerate())
+            .filter(|&(i, zone)| accepted[i] && is_in_section(zone.section, state.section))
+            .filter(|(_, zone)| zone.contains(state.drop_pos))
+            .filter(|(_, zone)| is_on_top(zone.painter.layer_id(), top_layer))
+            .max_by(|(i, a), (j, b)| {
+                (a.priority.cmp(&b.priority))
+                    .then(f32::total_cmp(&b.rect.area(), &a.rect.area()))
+                    .then(i.cmp(j))
+            })
+            .map(|(i, _)| i);
+
+        // Time how long a drop zone added using `drop_zone_with_hover_delay()`
+        // has been the target.
+        if let Some(i) = active_index
+            && let Some((widget_id, delay)) = zones[i].dwell
+        {
+            let data_id = self.dwell_id(widget_id);
+            let now = self.ctx.input(|input| input.time);
+            let pass_nr = self.ctx.cumulative_pass_nr();
+            let since = self.ctx.data(|data| {
+                (data.get_temp::<DwellHover>(data_id))
+                    .filter(|hover| hover.pass_nr + 1 >= pass_nr)
+                    .map_or(now, |hover| hover.since)
+            });
+            let elapsed = (now - since) as f32;
+            if elapsed < delay {
+                let remaining = std::time::Duration::from_secs_f32(delay - elapsed);
+                self.ctx.request_repaint_after(remaining);
+            }
+            let hover = DwellHover { since, pass_nr };
+            self.ctx.data_mut(|data| data.insert_temp(data_id, hover));
+        }
+
+        for (i, zone) in zones.into_iter().enumerate() {
+            let is_accepted = accepted[i];
+            let is_active = active_index == Some(i);
+            if self.debug_paint && payload.is_some() {
+                let color = match (is_active, is_accepted) {
+                    (true, _) => DEBUG_TARGET_COLOR,
+                    (false, true) => egui::Color32::LIGHT_BLUE,
+                    (false, false) => egui::Color32::RED,
+                };
+                let stroke = egui::Stroke::new(1.0, color);
+                (self.ctx.debug_painter()).rect_stroke(
+                    zone.interact_rect,
+                    0.0,
+                    stroke,
+                    egui::StrokeKind::Inside,
+                );
+            }
+            let color = if is_active {
+                zone.active_color
+            } else {
+                zone.inactive_color
+            };
+            if let Some(paint) = zone.paint {
+                if payload.is_none() || is_accepted {
+                    paint_in_place(&zone.painter, zone.shape_idx, |painter| {
+                        paint(painter, zone.rect, is_active);
+                    });
+                }
+            } else if payload.is_none() || is_accepted {
+                let stroke = egui::Stroke::new(self.style.drop_zone_stroke_width, color);
+                let mut animation = DropZoneAnimation::None;
+                if is_active && !crate::anim::reduced_motion(&self.ctx) {
+                    animation = self.style.drop_zone_animation;
+                }
+                if animation != DropZoneAnimation::None {
+                    self.ctx.request_repaint();
+                }
+                let time = self.ctx.input(|input| input.time);
+                let rounding = self.style.drop_zone_rounding;
+                let shape = animation.stroke_shape(zone.rect, rounding, stroke, time);
+                zone.painter.set(zone.shape_idx, shape);
+            }
+            if is_active {
+                match zone.target {
+                    Some(target) => {
+                        self.target = Some(target);
+                        self.hover = Some(DndHover {
+                            rect: zone.rect,
+                            kind: DndHoverKind::DropZone,
+                            pos: state.drop_pos,
+                        });
+                    }
+                    None => self.trash_hovered = true,
+                }
+            }
+        }
     }
 
     /// Ends the drag-and-drop context and returns a response.
-    pub fn finish(mut self, ui: &egui::Ui) -> DndResponse<Payload, Target> {
-        self = self.allow_unfinished();
+    pub fn finish(self, ui: &egui::Ui) -> DndResponse<Payload, Target> {
+        self.finish_with_hover(ui).0
+    }
+
+    /// Ends the drag-and-drop context and returns a response, along with the
+    /// location of the target where the payload is being hovered or was
+    /// dropped.
+    ///
+    /// This is useful for drawing a custom preview of the drop, such as a
+    /// label saying how many objects will be inserted.
+    pub fn finish_with_hover(
+        mut self,
+        ui: &egui::Ui,
+    ) -> (DndResponse<Payload, Target>, Option<DndHover>) {
+        #[cfg(feature = "inspector")]
+        let (drop_zones, reorder_drop_zones) =
+            (self.drop_zones.len(), self.reorder_drop_zones.len());
+
+        let is_dragging = self.is_dragging();
+        let was_dragging = is_dragging && !self.is_receiving();
+        let (response, mut state) = self.compute_response(ui);
+        if let DndResponse::DoneDragging(m) = &response
+            && let Some(f) = &self.hooks.drop
+        {
+            f(m);
+        }
+        if was_dragging
+            && state.is_none()
+            && let Some(f) = &self.hooks.drag_end
+        {
+            f();
+        }
+        if is_dragging && state.is_none() {
+            // Forget the payload remembered by `reorderable_hidden_rows()`.
+            (self.ctx).data_mut(|data| data.remove::<HiddenRowPayload>(self.id));
+        }
+        if let Some(preview) = self.live_preview
+            && let Some(state) = &mut state
+        {
+            state.preview = match &response {
+                DndResponse::MidDrag(DndMove {
+                    payload,
+                    target: Some(target),
+                }) => Some(preview(payload, target)),
+                _ => None,
+            };
+        }
+        if state.is_some() {
+            self.auto_scroll(ui);
+            wheel_scroll(ui);
+        }
+        if let Some(delta) = self.touch_scroll {
+            ui.scroll_with_delta_animation(delta, egui::style::ScrollAnimation::none());
+        }
+        if let Some(group) = &self.group {
+            self.update_group(group, &response, state.as_ref());
+            if group.is_receiving {
+                // The state is recreated from the group each frame.
+                state = None;
+            }
+        }
+        let mut shared_with_egui = false;
+        if let Some(egui_dnd) = &self.egui_dnd {
+            if egui_dnd.is_receiving {
+                if matches!(response, DndResponse::DoneDragging(_)) {
+                    egui::DragAndDrop::clear_payload(&self.ctx);
+                }
+                // The state is recreated from `egui::DragAndDrop` each frame.
+                state = None;
+            } else if let DndResponse::MidDrag(m) = &response
+                && let Some(state) = &mut state
+            {
+                (egui_dnd.share)(&self.ctx, &m.payload);
+                state.shared_with_egui = true;
+                shared_with_egui = true;
+            }
+        }
+        if let DndResponse::MidDrag(_) = &response
+            && let Some(state) = &state
+            && let Some(DragPaintFn(paint)) = &self.paint_drag
+        {
+            let (source_rect, payload_rect) = match self.payload_rects {
+                Some((source, payload)) => (Some(source), payload),
+                None => (
+                    None,
+                    egui::Rect::from_center_size(state.drop_pos, state.payload_size),
+                ),
+            };
+            let geometry = DndDragGeometry {
+                source_rect,
+                payload_rect,
+                hover: self.hover,
+            };
+            let layer_id = egui::LayerId::new(egui::Order::Foreground, self.id.with("drag_paint"));
+            paint(&self.ctx.layer_painter(layer_id), &geometry);
+        }
+        let pass_nr = self.ctx.cumulative_pass_nr();
+
+        #[cfg(feature = "inspector")]
+        crate::inspector::record_dnd(
+            &self.ctx,
+            crate::inspector::DndStats {
+                id: self.id,
+                drag: state.as_ref().map(|s| (s.payload_id, s.drop_pos)),
+                drop_zones,
+                reorder_drop_zones,
+                has_target: matches!(&response, DndResponse::MidDrag(m) if m.target.is_some()),
+            },
+        );
+
+        let settle = (self.settle.take()).filter(|settle| settle.progress.is_animating(&self.ctx));
+
+        // Mark as finished and store state in a single access.
+        self.ctx.data_mut(|data| {
+            data.remove_temp::<()>(self.id);
+            if shared_with_egui {
+                data.insert_temp(egui_source_id(), pass_nr);
+            }
+            if let Some(state) = state {
+                data.insert_temp::<DndDragState>(self.id, state);
+            }
+            if let Some(pending_id) = self.pending_drag {
+                data.insert_temp(self.id, PendingDrag(pending_id));
+            }
+            if self.touch_scroll.is_some() {
+                data.insert_temp(self.id, TouchScroll);
+            }
+            if let Some(settle) = settle {
+                data.insert_temp(self.id, settle);
+            } else {
+                data.remove::<SettleAnimation>(self.id);
+            }
+            if !matches!(response, DndResponse::MidDrag(_)) {
+                data.remove::<SpringHover>(self.id);
+            }
+        });
+
+        let hover = match &response {
+            DndResponse::MidDrag(_) | DndResponse::DoneDragging(_) => self.hover,
+            _ => None,
+        };
+        (response, hover)
+    }
+
+    /// Scrolls the enclosing scroll area if the pointer is near its edge.
+    fn auto_scroll(&self, ui: &egui::Ui) {
+        /// Scroll speed when the pointer is at or past the edge, in points per
+        /// second.
+        const MAX_SPEED: f32 = 800.0;
+
+        let margin = self.auto_scroll_margin;
+        if margin <= 0.0 {
+            return;
+        }
+        let in_scroll_area =
+            (ui.stack().iter()).any(|frame| frame.kind() == Some(egui::UiKind::ScrollArea));
+        if !in_scroll_area {
+            return;
+        }
+        let Some(pointer) = ui.ctx().pointer_interact_pos() else {
+            return;
+        };
+        let rect = ui.clip_rect();
+
+        // Returns how fast to scroll toward the lower end of `range`, from -1
+        // to 1.
+        let speed = |range: egui::Rangef, pos: f32| {
+            let toward_min = (range.min + margin - pos) / margin;
+            let toward_max = (pos - (range.max - margin)) / margin;
+            toward_min.clamp(0.0, 1.0) - toward_max.clamp(0.0, 1.0)
+        };
+        let mut direction = egui::Vec2::ZERO;
+        if rect.x_range().contains(pointer.x) {
+            direction.y = speed(rect.y_range(), pointer.y);
+        }
+        if rect.y_range().contains(pointer.y) {
+            direction.x = speed(rect.x_range(), pointer.x);
+        }
+
+        if direction != egui::Vec2::ZERO {
+            let dt = ui.input(|input| input.stable_dt).min(0.1);
+            ui.scroll_with_delta_animation(
+                direction * MAX_SPEED * dt,
+                egui::style::ScrollAnimation::none(),
+            );
+            ui.ctx().request_repaint();
+        }
+    }
+
+    /// Computes the response for [`Dnd::finish()`], along with the state to
+    /// persist to the next frame.
+    fn compute_response(
+        &mut self,
+        ui: &egui::Ui,
+    ) -> (DndResponse<Payload, Target>, Option<DndDragState>) {
+        // Moving using the keyboard is instant
+        if let Some(keyboard_move) = self.keyboard_move.take() {
+            return (DndResponse::DoneDragging(keyboard_move), None);
+        }
 
         // If nothing is being dragged, do nothing
-        let Some(state) = self.current_drag.take() else {
-            return DndResponse::Inactive;
+        let Some(mut state) = self.current_drag.take() else {
+            return (DndResponse::Inactive, None);
         };
         let Some(payload) = self.payload.take() else {
-            return DndResponse::Inactive;
+            self.finish_drop_zones(None, &state);
+            return (DndResponse::Inactive, None);
         };
 
+        // Escape or the cancel button cancels the drag. egui won't report the
+        // drag as starting again until the pointer is released.
+        let is_receiving = self.is_receiving();
+        // `egui::DragAndDrop` consumes the key itself and clears the payload.
+        let egui_cancelled = state.shared_with_egui
+            && !egui::DragAndDrop::has_any_payload(&self.ctx)
+            && !self.ctx.input(|input| input.pointer.any_released());
+        let cancel_pressed = (self.cancel_button)
+            .filter(|&button| !self.drag_buttons[button as usize])
+            .is_some_and(|button| ui.input(|input| input.pointer.button_pressed(button)));
+        if !is_receiving
+            && (egui_cancelled
+                || cancel_pressed
+                || ui
+                    .input_mut(|input| input.consume_key(egui::Modifiers::NONE, egui::Key::Escape)))
+        {
+            if state.shared_with_egui {
+                egui::DragAndDrop::clear_payload(&self.ctx);
+            }
+            self.start_snap_back(ui, &state);
+            if self.announce_drags {
+                crate::a11y::announce(ui.ctx(), &self.text.cancelled);
+            }
+            if let Some(f) = &self.hooks.cancel {
+                f(&payload);
+            }
+            return (DndResponse::Cancelled(payload), None);
+        }
+
+        self.finish_drop_zones(Some(&payload), &state);
+
+        let remote_pointer = self.group.as_ref().and_then(|group| group.remote_pointer);
+        if remote_pointer.is_some() {
+            // The object isn't shown in this viewport, so show a placeholder.
+            let rect = egui::Rect::from_center_size(state.drop_pos, state.payload_size);
+            let layer_id = egui::LayerId::new(egui::Order::Tooltip, state.payload_id);
+            let color = (ui.visuals().widgets.active.weak_bg_fill)
+                .gamma_multiply(self.style.payload_opacity);
+            let rounding = self.style.payload_hole_rounding;
+            ui.ctx()
+                .layer_painter(layer_id)
+                .rect_filled(rect, rounding, color);
+        }
+
+        if self.debug_paint {
+            self.debug_paint_reorder_zones(&state);
+        }
+
         // Compute reorder drop target and draw line
         let reorder_drop_target = (|| {
-            let cursor_pos = ui.input(|input| input.pointer.interact_pos())?;
-            let drop_pos = state.drop_pos;
-
-            let clip_rect = &ui.clip_rect();
-            if !clip_rect.contains(egui::pos2(drop_pos.x, cursor_pos.y))
-                && !clip_rect.contains(egui::pos2(cursor_pos.x, drop_pos.y))
-            {
-                return None; // cursor position is outside the current UI
+            profile_scope!("hcegui::dnd::Dnd::finish target search");
+            if self.trash_hovered {
+                return None;
             }
+            let cursor_pos = match remote_pointer {
+                Some(pointer) => pointer.pos,
+                None => ui.input(|input| input.pointer.interact_pos())?,
+            };
+            let drop_pos = state.drop_pos;
+            let top_layer = self.ctx.layer_id_at(drop_pos);
 
+            let (previous, section) = (state.reorder_zone, state.section);
             let closest = std::mem::take(&mut self.reorder_drop_zones)
                 .into_iter()
+                .filter(|drop_zone| {
+                    // Ignore drop zones in a UI that the cursor is outside of,
+                    // or that is covered by another layer.
+                    let clip_rect = drop_zone.clip_rect;
+                    (clip_rect.contains(egui::pos2(drop_pos.x, cursor_pos.y))
+                        || clip_rect.contains(egui::pos2(cursor_pos.x, drop_pos.y)))
+                        && is_on_top(drop_zone.layer_id, top_layer)
+                })
+                .filter(|drop_zone| is_in_section(drop_zone.section, section))
+                .filter(|drop_zone| {
+                    (state.pin_segment).is_none_or(|segment| drop_zone.pin_segment == segment)
+                })
+                .filter(|drop_zone| self.accepts(&payload, &drop_zone.target))
                 .filter_map(|drop_zone| {
+                    // Favor the previous target so that the pointer must move
+                    // some distance past the midpoint to change it. Moving
+                    // past the midpoint changes the difference between the
+                    // distances by twice as much.
+                    let bias = if previous.is_some_and(|key| drop_zone.is_same_zone(key)) {
+                        self.reorder_hysteresis * 2.0
+                    } else {
+                        0.0
+                    };
+                    if let Some(area) = drop_zone.area {
+                        let distance = area.distance_to_pos(drop_pos);
+                        return Some((drop_zone, distance - bias));
+                    }
                     let [a, b] = drop_zone.line_endpoints;
                     let distance_to_cursor = if drop_zone.direction.is_horizontal() {
                         (a.y..=b.y)
@@ -335,44 +2216,327 @@ impl<Payload, Target> Dnd<Payload, Target> {
                             .contains(&drop_pos.x)
                             .then(|| (a.y - cursor_pos.y).abs())
                     };
-                    Some((drop_zone, distance_to_cursor?))
+                    Some((drop_zone, distance_to_cursor? - bias))
                 })
                 .min_by(|(_, distance1), (_, distance2)| f32::total_cmp(distance1, distance2));
 
             closest.map(|(drop_zone, _distance)| {
-                let color = ui.visuals().widgets.active.bg_stroke.color;
-                let stroke = egui::Stroke::new(self.style.reorder_stroke_width, color);
-                ui.painter()
-                    .with_clip_rect(drop_zone.clip_rect.expand(self.style.reorder_stroke_width))
-                    .line_segment(drop_zone.line_endpoints, stroke);
-                drop_zone.target
+                let key = drop_zone.key();
+                // Gaps only open in lists, not grids. Don't draw a line next
+                // to the payload, since dropping there does nothing.
+                let has_gap =
+                    self.style.reorder_gap && drop_zone.gap.is_some() && drop_zone.area.is_none();
+                if has_gap || drop_zone.is_noop {
+                    return (
+                        drop_zone.target,
+                        drop_zone.gap,
+                        drop_zone.line_endpoints,
+                        key,
+                    );
+                }
+                let color = (self.style.reorder_line_color)
+                    .unwrap_or(ui.visuals().widgets.active.bg_stroke.color);
+                let width = self.style.reorder_stroke_width;
+                let stroke = egui::Stroke::new(width, color);
+                let painter = egui::Painter::new(
+                    ui.ctx().clone(),
+                    drop_zone.layer_id,
+                    drop_zone.clip_rect.expand(width),
+                );
+                let points = crate::util::snap_line_segment(
+                    drop_zone.line_endpoints,
+                    width,
+                    painter.pixels_per_point(),
+                );
+                painter.line_segment(points, stroke);
+                (
+                    drop_zone.target,
+                    drop_zone.gap,
+                    drop_zone.line_endpoints,
+                    key,
+                )
             })
         })();
-        if self.target.is_none() {
+        if self.trash_hovered {
+            self.target = None;
+            self.hover = None;
+        }
+        state.gap = None;
+        state.reorder_zone = None;
+        if self.target.is_none()
+            && let Some((target, gap, [a, b], key)) = reorder_drop_target
+        {
             // IIFE to mimic try_block
-            self.target = reorder_drop_target;
+            self.target = Some(target);
+            self.hover = Some(DndHover {
+                rect: egui::Rect::from_two_pos(a, b),
+                kind: DndHoverKind::Reorder,
+                pos: state.drop_pos,
+            });
+            state.gap = gap;
+            state.reorder_zone = Some(key);
+            if self.debug_paint {
+                let stroke = egui::Stroke::new(2.0, DEBUG_TARGET_COLOR);
+                self.ctx.debug_painter().line_segment([a, b], stroke);
+            }
+        }
+        if self.announce_drags && !is_receiving {
+            self.announce_progress(&mut state);
         }
 
         // Compute response and store state
-        if self.ctx.input(|input| input.pointer.any_released()) {
-            if let Some(target) = self.target.take() {
-                // done dragging
-                DndResponse::DoneDragging(DndMove { payload, target })
-            } else {
-                // done dragging but not hovering any endpoint
-                DndResponse::Inactive
+        let remote_released = remote_pointer.is_some_and(|pointer| pointer.released);
+        let released = self.ctx.input(|input| match &mut state.click_to_drop {
+            None => input.pointer.any_released(),
+            Some(pressed) => {
+                *pressed |= input.pointer.any_pressed();
+                *pressed && input.pointer.any_released()
             }
-        } else {
+        });
+        if remote_released || released {
+            // When dragging in a group, use the `Dnd` that was hovered on the
+            // previous pass so that all `Dnd`s agree regardless of the order
+            // they are shown in.
+            // A payload released in another viewport is dropped on the target
+            // here, since that viewport didn't see it.
+            let group = self.group.as_ref().filter(|_| !remote_released);
+            let group_target = group.map(|group| self.group_hovered(group));
+            let response = match (self.target.take(), group_target) {
+                // dropped on the trash zone
+                _ if self.trash_hovered => (DndResponse::Deleted(payload), None),
+                // done dragging
+                (Some(target), None) => {
+                    self.start_settle(ui, &state);
+                    self.scroll_to_drop(ui, &state);
+                    (DndResponse::DoneDragging(DndMove { payload, target }), None)
+                }
+                (Some(target), Some(Some(hovered))) if hovered == self.id => {
+                    self.start_settle(ui, &state);
+                    self.scroll_to_drop(ui, &state);
+                    (DndResponse::DoneDragging(DndMove { payload, target }), None)
+                }
+                // dropped on another `Dnd` in the group
+                (_, Some(Some(hovered))) if hovered != self.id && !is_receiving => (
+                    DndResponse::DroppedElsewhere(DndMove::new(payload, hovered)),
+                    None,
+                ),
+                // dropped on a drop zone using `egui::DragAndDrop`
+                _ if !is_receiving
+                    && state.shared_with_egui
+                    && !egui::DragAndDrop::has_any_payload(&self.ctx) =>
+                {
+                    let m = DndMove::new(payload, egui::Id::NULL);
+                    (DndResponse::DroppedElsewhere(m), None)
+                }
+                // dropped outside the window or designated rectangle
+                _ if !is_receiving && self.is_dragged_out() => {
+                    (DndResponse::DraggedOut(payload), None)
+                }
+                // done dragging but not hovering any endpoint
+                _ => {
+                    if !is_receiving {
+                        self.start_snap_back(ui, &state);
+                        if let Some(f) = &self.hooks.cancel {
+                            f(&payload);
+                        }
+                    }
+                    (DndResponse::Inactive, None)
+                }
+            };
+            if self.announce_drags && !is_receiving {
+                let text = match &response.0 {
+                    DndResponse::Deleted(_) => &self.text.deleted,
+                    DndResponse::DraggedOut(_) => &self.text.dropped_outside,
+                    DndResponse::Inactive => &self.text.cancelled,
+                    _ => &self.text.dropped,
+                };
+                crate::a11y::announce(ui.ctx(), text);
+            }
+            response
+        } else {
             // still dragging
-            self.ctx
-                .data_mut(|data| data.insert_temp::<DndDragState>(self.id, state));
             let target = self.target.take();
-            DndResponse::MidDrag(DndMove { payload, target })
+            if !is_receiving {
+                state.over_invalid_target = target.is_none()
+                    && !self.trash_hovered
+                    && !self.is_dragged_out()
+                    && self
+                        .group
+                        .as_ref()
+                        .is_none_or(|g| self.group_hovered(g).is_none());
+                if state.over_invalid_target {
+                    self.ctx.set_cursor_icon(egui::CursorIcon::NotAllowed);
+                }
+            }
+            (
+                DndResponse::MidDrag(DndMove { payload, target }),
+                Some(state),
+            )
+        }
+    }
+
+    /// Announces picking up the payload and changes to the reorder gap to
+    /// screen readers, such as "Picked up item 3 of 10" or "Moved before item
+    /// 5". See [`Dnd::announce_drags`] and [`DndText`].
+    fn announce_progress(&self, state: &mut DndDragState) {
+        // Wait until every item has been added while dragging.
+        if self.drag_started_this_frame {
+            return;
+        }
+        let position = |id| self.reorder_items.iter().position(|&(i, _)| i == id);
+        if !state.announced_pick_up {
+            state.announced_pick_up = true;
+            let count = self.reorder_items.len();
+            let text = match position(state.payload_id) {
+                Some(i) => DndText::format_position(&self.text.picked_up_item, i, count),
+                None => self.text.picked_up.to_string(),
+            };
+            crate::a11y::announce(&self.ctx, text);
+        }
+        if state.gap != state.announced_gap {
+            state.announced_gap = state.gap;
+            if let Some((item_id, side)) = state.gap
+                && let Some(i) = position(item_id)
+            {
+                let template = match side {
+                    BeforeOrAfter::Before => &self.text.moved_before_item,
+                    BeforeOrAfter::After => &self.text.moved_after_item,
+                };
+                let count = self.reorder_items.len();
+                let text = DndText::format_position(template, i, count);
+                crate::a11y::announce(&self.ctx, text);
+            }
+        }
+    }
+
+    /// Paints reorder drop zones and the position of the payload, for
+    /// [`Dnd::debug_paint`].
+    fn debug_paint_reorder_zones(&self, state: &DndDragState) {
+        let painter = self.ctx.debug_painter();
+        let stroke = egui::Stroke::new(1.0, egui::Color32::YELLOW);
+        for zone in &self.reorder_drop_zones {
+            painter.line_segment(zone.line_endpoints, stroke);
+            if let Some(area) = zone.area {
+                let stroke = egui::Stroke::new(1.0, stroke.color.gamma_multiply(0.25));
+                painter.rect_stroke(area, 0.0, stroke, egui::StrokeKind::Inside);
+            }
         }
+        painter.circle_stroke(state.drop_pos, 4.0, (1.0, egui::Color32::WHITE));
+        let payload_rect = egui::Rect::from_center_size(state.drop_pos, state.payload_size);
+        painter.rect_stroke(
+            payload_rect,
+            0.0,
+            (1.0, egui::Color32::GRAY),
+            egui::StrokeKind::Inside,
+        );
+    }
+
+    /// Returns whether the pointer is outside [`Dnd::drag_out_rect`].
+    fn is_dragged_out(&self) -> bool {
+        let pointer_pos = self.ctx.input(|input| input.pointer.latest_pos());
+        self.drag_out_rect
+            .zip(pointer_pos)
+            .is_some_and(|(rect, pos)| !rect.contains(pos))
+    }
+
+    /// Starts [`DndStyle::settle_animation`] after the payload is dropped
+    /// between items added using [`Dnd::reorderable()`].
+    fn start_settle(&mut self, ui: &egui::Ui, state: &DndDragState) {
+        let Some((item_id, side)) = state.gap else {
+            return;
+        };
+        if !self.style.settle_animation {
+            return;
+        }
+
+        // Compute where each item was shown, in the new order.
+        let mut items = std::mem::take(&mut self.reorder_items);
+        let Some(payload_index) = items.iter().position(|&(id, _)| id == state.payload_id) else {
+            return;
+        };
+        items.remove(payload_index);
+        let target_index = match items.iter().position(|&(id, _)| id == item_id) {
+            Some(i) if side == BeforeOrAfter::After => i + 1,
+            Some(i) => i,
+            None => payload_index, // dropped next to itself
+        };
+        let ghost_rect = egui::Rect::from_center_size(state.drop_pos, state.payload_size);
+        items.insert(target_index, (state.payload_id, ghost_rect));
+
+        self.settle = Some(SettleAnimation {
+            from: items.into_iter().map(|(_, rect)| rect).collect(),
+            snap_back: None,
+            progress: settle_progress(ui),
+        });
+    }
+
+    /// Scrolls the enclosing scroll area to show the target where the payload
+    /// was dropped, if [`Dnd::scroll_to_drop`] is enabled.
+    fn scroll_to_drop(&self, ui: &egui::Ui, state: &DndDragState) {
+        if !self.scroll_to_drop {
+            return;
+        }
+        let Some(hover) = &self.hover else {
+            return;
+        };
+        let mut rect = hover.rect;
+        if hover.kind == DndHoverKind::Reorder {
+            // The payload ends up on one side of the reorder line, so show
+            // enough space on both sides.
+            let size = state.payload_size;
+            rect = if rect.width() > rect.height() {
+                rect.expand2(egui::vec2(0.0, size.y))
+            } else {
+                rect.expand2(egui::vec2(size.x, 0.0))
+            };
+        }
+        ui.scroll_to_rect(rect, None);
+    }
+
+    /// Starts [`DndStyle::snap_back_animation`] after the payload is released
+    /// outside any target.
+    fn start_snap_back(&mut self, ui: &egui::Ui, state: &DndDragState) {
+        if !self.style.snap_back_animation {
+            return;
+        }
+        let ghost_rect = egui::Rect::from_center_size(state.drop_pos, state.payload_size);
+        self.settle = Some(SettleAnimation {
+            from: vec![],
+            snap_back: Some((state.payload_id, ghost_rect)),
+            progress: settle_progress(ui),
+        });
+    }
+
+    /// Offsets the shapes painted since `first_shape` by `offset` scaled by the
+    /// remaining progress of [`DndStyle::settle_animation`].
+    fn settle_shapes(
+        &self,
+        ui: &egui::Ui,
+        first_shape: egui::layers::ShapeIdx,
+        offset: egui::Vec2,
+    ) {
+        let Some(settle) = &self.settle else {
+            return;
+        };
+        let offset = offset * settle.progress.get(ui.ctx());
+        if offset == egui::Vec2::ZERO {
+            return;
+        }
+        let transform = egui::emath::TSTransform::from_translation(offset);
+        ui.ctx().graphics_mut(|g| {
+            let list = g.entry(ui.layer_id());
+            for i in first_shape.0..list.next_idx().0 {
+                list.mutate_shape(egui::layers::ShapeIdx(i), |s| s.shape.transform(transform));
+            }
+        });
     }
 
     /// Adds a new reorder drop zone at `ui.cursor()`.
     pub fn reorder_drop_zone(&mut self, ui: &mut egui::Ui, target: Target) {
+        if !self.is_dragging() {
+            return;
+        }
+
         let dir = ui.layout().main_dir;
         let rect = ui.cursor();
         self.reorder_drop_zones.push(ReorderTarget {
@@ -382,121 +2546,1272 @@ impl<Payload, Target> Dnd<Payload, Target> {
                 egui::Direction::TopDown => [rect.left_top(), rect.right_top()],
                 egui::Direction::BottomUp => [rect.left_bottom(), rect.right_bottom()],
             },
+            layer_id: ui.layer_id(),
             clip_rect: ui.clip_rect(),
             direction: dir,
             target,
+            gap: None,
+            area: None,
+            section: self.section,
+            pin_segment: self.pin_segment,
+            is_noop: false,
         });
     }
 }
 
 impl<Payload, Target: Clone, BA: From<BeforeOrAfter>> Dnd<Payload, (Target, BA)> {
     /// Creates a new reorder drop zone before and after `r`.
+    ///
+    /// In a wrapping layout, such as [`egui::Ui::horizontal_wrapped()`], this
+    /// is the same as [`Dnd::grid_reorder_drop_zone_before_after()`].
     pub fn reorder_drop_zone_before_after(
         &mut self,
         ui: &mut egui::Ui,
         r: &egui::Response,
         target: Target,
+    ) {
+        self.reorder_drop_zone_before_after_item(ui, r.rect, target, None, false);
+    }
+
+    /// Creates a new reorder drop zone before and after `r`, for an object in
+    /// a grid or wrapped layout. See [`Dnd::grid_reorderable()`].
+    pub fn grid_reorder_drop_zone_before_after(
+        &mut self,
+        ui: &mut egui::Ui,
+        r: &egui::Response,
+        target: Target,
+    ) {
+        self.reorder_drop_zone_before_after_item(ui, r.rect, target, None, true);
+    }
+
+    /// Creates a new reorder drop zone before and after `rect`, which is the
+    /// item with ID `item_id` if it was added using [`Dnd::reorderable()`].
+    ///
+    /// If `grid` is `true` or the layout wraps, then the payload is dropped
+    /// next to whichever item it is closest to on both axes instead of only
+    /// along the layout direction.
+    fn reorder_drop_zone_before_after_item(
+        &mut self,
+        ui: &egui::Ui,
+        rect: egui::Rect,
+        target: Target,
+        item_id: Option<egui::Id>,
+        grid: bool,
+    ) {
+        let expansion = ui.spacing().item_spacing / 2.0;
+        let dir = ui.layout().main_dir;
+        let wrap = ui.layout().main_wrap;
+        if wrap && self.is_dragging() {
+            self.extend_zone_to_row_end(ui.max_rect().expand2(expansion), rect, dir);
+        }
+        let grid = grid || wrap;
+        self.reorder_drop_zone_impl(rect, ui.painter(), expansion, dir, target, item_id, grid);
+    }
+
+    /// If `rect` starts a new row in a wrapping layout, extends the area of the
+    /// drop zone after the last object on the previous row to the end of the
+    /// row, so that dropping past the end of a short row inserts the payload
+    /// at the line break instead of on the next row.
+    fn extend_zone_to_row_end(
+        &mut self,
+        max_rect: egui::Rect,
+        rect: egui::Rect,
+        dir: egui::Direction,
+    ) {
+        let Some(zone) = self.reorder_drop_zones.last_mut() else {
+            return;
+        };
+        let Some(area) = &mut zone.area else {
+            return;
+        };
+        if zone.direction != dir {
+            return;
+        }
+        let cross = usize::from(dir.is_horizontal());
+        if rect.center()[cross] <= area.max[cross] {
+            return; // same row
+        }
+        match dir {
+            egui::Direction::LeftToRight => area.max.x = area.max.x.max(max_rect.right()),
+            egui::Direction::RightToLeft => area.min.x = area.min.x.min(max_rect.left()),
+            egui::Direction::TopDown => area.max.y = area.max.y.max(max_rect.bottom()),
+            egui::Direction::BottomUp => area.min.y = area.min.y.min(max_rect.top()),
+        }
+    }
+
+    /// Adds reorder drop zones before and after an object. See
+    /// [`Dnd::reorder_drop_zone_before_after_item()`].
+    fn reorder_drop_zone_impl(
+        &mut self,
+        rect: egui::Rect,
+        painter: &egui::Painter,
+        expansion: egui::Vec2,
+        dir: egui::Direction,
+        target: Target,
+        item_id: Option<egui::Id>,
+        grid: bool,
     ) {
         if !self.is_dragging() {
             return;
         }
 
-        let expansion = ui.spacing().item_spacing / 2.0;
-        let rect = r.rect.expand2(expansion);
-        let clip_rect = ui.clip_rect().expand2(expansion);
+        let rect = rect.expand2(expansion);
+        let clip_rect = painter.clip_rect().expand2(expansion);
+        let layer_id = painter.layer_id();
 
-        let dir = ui.layout().main_dir;
-        let tl = rect.left_top();
-        let tr = rect.right_top();
-        let dl = rect.left_bottom();
-        let dr = rect.right_bottom();
+        let left = [rect.left_top(), rect.left_bottom()];
+        let right = [rect.right_top(), rect.right_bottom()];
+        let top = [rect.left_top(), rect.right_top()];
+        let bottom = [rect.left_bottom(), rect.right_bottom()];
+        // "Before" is the side where the layout starts.
+        let (before, after) = match dir {
+            egui::Direction::LeftToRight => (left, right),
+            egui::Direction::RightToLeft => (right, left),
+            egui::Direction::TopDown => (top, bottom),
+            egui::Direction::BottomUp => (bottom, top),
+        };
+        // Split the item in half, with each half nearest its line.
+        let half = |[a, b]: [egui::Pos2; 2]| {
+            grid.then(|| egui::Rect::from_two_pos(a, b).union(egui::Rect::from_pos(rect.center())))
+        };
         self.reorder_drop_zones.push(ReorderTarget {
-            line_endpoints: [tl, if dir.is_horizontal() { dl } else { tr }],
+            line_endpoints: before,
+            layer_id,
             clip_rect,
             direction: dir,
             target: (target.clone(), BeforeOrAfter::Before.into()),
+            gap: item_id.map(|id| (id, BeforeOrAfter::Before)),
+            area: half(before),
+            section: self.section,
+            pin_segment: self.pin_segment,
+            is_noop: false,
         });
         self.reorder_drop_zones.push(ReorderTarget {
-            line_endpoints: [if dir.is_horizontal() { tr } else { dl }, dr],
+            line_endpoints: after,
+            layer_id,
             clip_rect,
             direction: dir,
             target: (target, BeforeOrAfter::After.into()),
+            gap: item_id.map(|id| (id, BeforeOrAfter::After)),
+            area: half(after),
+            section: self.section,
+            pin_segment: self.pin_segment,
+            is_noop: false,
+        });
+    }
+}
+
+impl<I: Clone + PartialEq + Hash> Dnd<I, (I, BeforeOrAfter)> {
+    /// Adds a new draggable object, using `index` for the ID. See
+    /// [`Dnd::draggable()`].
+    ///
+    /// When the drag handle has keyboard focus, <kbd>Alt</kbd> plus an arrow
+    /// key along the layout direction moves the object one place earlier or
+    /// later. This is reported by [`Dnd::finish()`] as
+    /// [`DndResponse::DoneDragging`], and keyboard focus moves to the drag
+    /// handle at the new position. This assumes that `index` is the position
+    /// of the object in the sequence. The drag handle must be focusable, such
+    /// as a [`ReorderHandle`] or a widget that senses clicks or drags on its
+    /// own.
+    pub fn reorderable<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let item_id = self.id.with(&index);
+        self.reorderable_impl(ui, index, item_id, false, add_contents)
+    }
+
+    /// Adds a new draggable object, using `id_source` for the ID instead of
+    /// `index`. See [`Dnd::reorderable()`].
+    ///
+    /// Use this if the list may change while dragging, such as when data
+    /// arrives asynchronously. The drag follows the object with the same
+    /// `id_source` even if its index changes, and the response uses the index
+    /// it has when the drag ends.
+    pub fn reorderable_with_id_source<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        id_source: impl Hash,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let item_id = self.id.with(id_source);
+        self.reorderable_impl(ui, index, item_id, false, add_contents)
+    }
+
+    /// Adds a new draggable object in a grid or wrapped layout, such as
+    /// [`egui::Grid`] or [`egui::Ui::horizontal_wrapped()`], using `index` for
+    /// the ID. See [`Dnd::reorderable()`].
+    ///
+    /// The payload is dropped before or after whichever object it is closest
+    /// to on both axes, so objects may be different sizes and rows may have
+    /// different lengths. `index` is the position of the object in the flat
+    /// sequence, so [`ReorderDndMove::reorder()`] works as usual.
+    /// [`DndStyle::reorder_gap`] is ignored.
+    ///
+    /// In a wrapping layout, [`Dnd::reorderable()`] is the same as this.
+    pub fn grid_reorderable<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let item_id = self.id.with(&index);
+        self.reorderable_impl(ui, index, item_id, true, add_contents)
+    }
+
+    /// Implementation of [`Dnd::reorderable()`] and
+    /// [`Dnd::grid_reorderable()`], where `item_id` is the ID of the object.
+    fn reorderable_impl<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        item_id: egui::Id,
+        grid: bool,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let grid = grid || ui.layout().main_wrap;
+        let prev = self.last_reorderable.take();
+        let prev_item_id = prev.as_ref().map(|prev| prev.id);
+
+        // The gap after the previous item is in the same place as the gap
+        // before this one, so only use the latter.
+        if let Some(prev_id) = prev_item_id
+            && let Some(zone) = self.reorder_drop_zones.last_mut()
+            && zone.gap == Some((prev_id, BeforeOrAfter::After))
+        {
+            zone.gap = Some((item_id, BeforeOrAfter::Before));
+        }
+
+        // Don't open a gap next to the hole left by the payload, since dropping
+        // there does nothing.
+        let payload_id = self.payload_id();
+        let is_payload = payload_id == Some(item_id);
+        let prev_is_payload = prev_item_id.is_some() && prev_item_id == payload_id;
+
+        let gap = self.gap_size(
+            ui,
+            (item_id, BeforeOrAfter::Before),
+            grid || is_payload || prev_is_payload,
+        );
+        if gap > 0.0 {
+            ui.add_space(gap);
+        }
+        let settle_from = (self.settle.as_ref())
+            .and_then(|settle| settle.from.get(self.reorder_item_count).copied());
+        self.reorder_item_count += 1;
+        let first_shape = ui.ctx().graphics(|g| {
+            g.get(ui.layer_id())
+                .map_or(egui::layers::ShapeIdx(0), |list| list.next_idx())
         });
+        let (r, handle) = self.draggable_impl(
+            ui,
+            item_id,
+            index.clone(),
+            |ui| add_contents(ui, item_id),
+            None,
+        );
+        if let Some(from) = settle_from {
+            self.settle_shapes(ui, first_shape, from.min - r.response.rect.min);
+        }
+        if self.is_dragging() {
+            self.reorder_items.push((item_id, r.response.rect));
+        }
+        if is_payload
+            && prev.is_some()
+            && let Some(zone) = self.reorder_drop_zones.last_mut()
+        {
+            zone.is_noop = true; // after the previous item
+        }
+        let zone_count = self.reorder_drop_zones.len();
+        self.reorder_drop_zone_before_after_item(
+            ui,
+            r.response.rect,
+            index.clone(),
+            Some(item_id),
+            grid,
+        );
+        if is_payload && self.live_preview.is_some() {
+            // The payload is shown where it would be dropped, so its own drop
+            // zones would move it back to where it started.
+            self.reorder_drop_zones.truncate(zone_count);
+        }
+        for (i, zone) in self.reorder_drop_zones[zone_count..].iter_mut().enumerate() {
+            // Both sides of the payload, and before the item after it
+            zone.is_noop |= is_payload || (prev_is_payload && i == 0);
+        }
+        let gap = self.gap_size(ui, (item_id, BeforeOrAfter::After), grid || is_payload);
+        if gap > 0.0 {
+            ui.add_space(gap);
+        }
+
+        // Move using the keyboard
+        if let Some(payload) = self.keyboard_move_later.take() {
+            if let Some(handle) = &handle {
+                handle.request_focus();
+            }
+            let target = (index.clone(), BeforeOrAfter::After);
+            self.keyboard_move = Some(DndMove::new(payload, target));
+        } else if let Some(handle) = &handle
+            && handle.has_focus()
+            && self.keyboard_move.is_none()
+        {
+            let (earlier_key, later_key) = keyboard_reorder_keys(ui.layout().main_dir);
+            let (earlier, later) = ui.input_mut(|input| {
+                (
+                    input.consume_key(egui::Modifiers::ALT, earlier_key),
+                    input.consume_key(egui::Modifiers::ALT, later_key),
+                )
+            });
+            if earlier && let Some(prev) = &prev {
+                if let Some(prev_handle_id) = prev.handle_id {
+                    ui.memory_mut(|mem| mem.request_focus(prev_handle_id));
+                }
+                let target = (prev.index.clone(), BeforeOrAfter::Before);
+                self.keyboard_move = Some(DndMove::new(index.clone(), target));
+            } else if later {
+                self.keyboard_move_later = Some(index.clone());
+            }
+        }
+
+        self.last_reorderable = Some(LastReorderable {
+            id: item_id,
+            index,
+            handle_id: handle.map(|handle| handle.id),
+        });
+        r
+    }
+
+    /// Adds reorder drop zones for an object that is not shown, such as a row
+    /// outside the visible range of [`egui::ScrollArea::show_rows()`], using
+    /// `rect` as an estimate of where it would be.
+    ///
+    /// If the object is being dragged, then the drag continues even though
+    /// nothing is shown under the pointer.
+    pub fn reorderable_offscreen(&mut self, ui: &egui::Ui, index: I, rect: egui::Rect) {
+        let item_id = self.id.with(&index);
+        if let Some(state) = &mut self.current_drag
+            && state.payload_id == item_id
+        {
+            ui.ctx().set_cursor_icon(egui::CursorIcon::Grabbing);
+            if let Some(pointer_pos) = ui.ctx().pointer_interact_pos() {
+                state.drop_pos = pointer_pos + state.cursor_offset + state.payload_size / 2.0;
+            }
+            self.payload = Some(index.clone());
+        }
+        self.reorder_drop_zone_before_after_item(ui, rect, index, None, false);
+    }
+
+    /// Adds a new object with a draggable handle, using `index` for the ID. See
+    /// [`Dnd::draggable()`].
+    pub fn reorderable_with_handle<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> R,
+    ) -> egui::InnerResponse<R> {
+        let handle = ReorderHandle::new().description(self.text.reorder_handle_description.clone());
+        self.reorderable(ui, index, |ui, id| {
+            let main_dir = ui.layout().main_dir();
+            ui.horizontal(|ui| {
+                if main_dir.is_vertical() {
+                    ui.set_width(ui.available_width());
+                }
+                (ui.add(handle), add_contents(ui, id))
+            })
+            .inner
+        })
+    }
+}
+
+impl ReorderDnd {
+    /// Adds reorder drop zones for the rows outside `visible_rows` in a list
+    /// shown using [`egui::ScrollArea::show_rows()`], where each row is
+    /// `row_height` tall. See [`Dnd::reorderable_offscreen()`].
+    ///
+    /// Call this inside `show_rows()` after adding the visible rows using
+    /// [`Dnd::reorderable()`], so that dragging continues when the payload is
+    /// scrolled out of view and dropping beyond the visible rows inserts the
+    /// payload in the right place.
+    pub fn reorderable_hidden_rows(
+        &mut self,
+        ui: &egui::Ui,
+        row_height: f32,
+        total_rows: usize,
+        visible_rows: std::ops::Range<usize>,
+    ) {
+        if !self.is_dragging() || total_rows == 0 {
+            return;
+        }
+
+        // Remember the payload so that the drag continues once it is hidden.
+        let payload = match self.payload {
+            Some(payload) => {
+                ui.data_mut(|data| data.insert_temp(self.id, HiddenRowPayload(payload)));
+                None
+            }
+            None => ui.data(|data| data.get_temp::<HiddenRowPayload>(self.id)),
+        };
+
+        let stride = row_height + ui.spacing().item_spacing.y;
+        let top = ui.max_rect().top() - visible_rows.start as f32 * stride;
+        let row_rect = |i: usize| {
+            let y = top + i as f32 * stride;
+            egui::Rect::from_x_y_ranges(ui.max_rect().x_range(), y..=y + row_height)
+        };
+
+        // Only the rows between the visible rows and the pointer can be
+        // closest to it.
+        let pointer_row = match ui.ctx().pointer_interact_pos() {
+            Some(pos) => (((pos.y - top) / stride).max(0.0) as usize).min(total_rows - 1),
+            None => visible_rows.start,
+        };
+        let above = pointer_row.min(visible_rows.start)..visible_rows.start;
+        let below = visible_rows.end..(pointer_row + 1).max(visible_rows.end).min(total_rows);
+        for i in above.chain(below) {
+            self.reorderable_offscreen(ui, i, row_rect(i));
+        }
+        if let Some(HiddenRowPayload(i)) = payload
+            && self.payload.is_none()
+        {
+            self.reorderable_offscreen(ui, i, row_rect(i));
+        }
+    }
+}
+
+impl Dnd<usize, (usize, BeforeOrAfter)> {
+    /// Sets whether to show the list in the order it would have if the
+    /// payload were dropped on the current target, so that objects move out of
+    /// the way while dragging. The order only changes when the payload is
+    /// dropped.
+    ///
+    /// To show the preview, add the objects in the order returned by
+    /// [`Dnd::preview_order()`], using their indices from before the move.
+    #[must_use]
+    pub fn with_live_preview(mut self, live_preview: bool) -> Self {
+        self.live_preview = live_preview
+            .then_some(|&payload, &target| DndMove { payload, target }.list_reorder_indices());
+        self
+    }
+
+    /// Returns the indices of a sequence of length `len` in the order to show
+    /// them. See [`Dnd::with_live_preview()`].
+    ///
+    /// This is `0..len` unless a payload is being dragged with live preview
+    /// enabled.
+    pub fn preview_order(&self, len: usize) -> Vec<usize> {
+        let mut order: Vec<usize> = (0..len).collect();
+        if let Some((i, j)) = self.current_drag.as_ref().and_then(|state| state.preview)
+            && i < len
+            && j < len
+        {
+            order.move_element(i, j);
+        }
+        order
+    }
+}
+
+impl<Payload, Target> Dnd<Payload, Target> {
+    /// Returns the current size of the animated gap at `key` for
+    /// [`DndStyle::reorder_gap`].
+    fn gap_size(&mut self, ui: &egui::Ui, key: (egui::Id, BeforeOrAfter), suppress: bool) -> f32 {
+        if !self.style.reorder_gap || ui.is_sizing_pass() {
+            return 0.0;
+        }
+        let Some(state) = &mut self.current_drag else {
+            return 0.0;
+        };
+
+        let target = if !suppress && state.gap == Some(key) {
+            let size = state.payload_size + ui.spacing().item_spacing;
+            if ui.layout().main_dir().is_horizontal() {
+                size.x
+            } else {
+                size.y
+            }
+        } else {
+            0.0
+        };
+
+        let i = match state.gaps.iter().position(|(k, _)| *k == key) {
+            Some(i) => i,
+            None if target == 0.0 => return 0.0,
+            None => {
+                let duration = ui.style().animation_time;
+                let value = crate::anim::AnimatedValue::new(0.0).with_duration(duration);
+                state.gaps.push((key, value));
+                state.gaps.len() - 1
+            }
+        };
+        let value = &mut state.gaps[i].1;
+        value.set_target(ui.ctx(), target);
+        let size = value.get(ui.ctx());
+        if size == 0.0 {
+            state.gaps.swap_remove(i);
+        }
+        size
+    }
+}
+
+/// Shows `items` as a list that can be reordered using drag handles, and
+/// reorders it when an item is dropped. Returns whether the order changed this
+/// frame.
+///
+/// `row_fn` shows the contents of each row, after its drag handle. This is
+/// equivalent to adding each item using [`Dnd::reorderable_with_handle()`] and
+/// then calling [`ReorderDndMove::reorder()`] with the result of
+/// [`Dnd::finish()`].
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let mut items = vec!["apple", "banana", "cherry"];
+/// hcegui::dnd::reorderable_list(ui, "fruits", &mut items, |ui, item| {
+///     ui.label(*item);
+/// });
+/// # });
+/// ```
+pub fn reorderable_list<T>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    items: &mut [T],
+    mut row_fn: impl FnMut(&mut egui::Ui, &mut T),
+) -> bool {
+    let mut dnd = ReorderDnd::new(ui.ctx(), id);
+    for (i, item) in items.iter_mut().enumerate() {
+        dnd.reorderable_with_handle(ui, i, |ui, _| row_fn(ui, item));
+    }
+    match dnd.finish(ui).if_done_dragging() {
+        Some(r) => {
+            r.reorder(items);
+            true
+        }
+        None => false,
+    }
+}
+
+/// Shows `items` as a list that can be reordered using drag handles, and
+/// remembers the order in egui memory. Returns whether the order changed this
+/// frame.
+///
+/// Each item is identified by `key`, which should be stable across runs of the
+/// program. The first time the list is shown, `items` is sorted to match the
+/// saved order, with any unknown items moved to the end. To save the order
+/// between runs, enable egui's `persistence` feature, such as using this
+/// crate's `persistence` feature.
+///
+/// `row_fn` shows the contents of each row, after its drag handle.
+pub fn persisted_order<T, K: Hash>(
+    ui: &mut egui::Ui,
+    id: impl Into<egui::Id>,
+    items: &mut [T],
+    key: impl Fn(&T) -> K,
+    mut row_fn: impl FnMut(&mut egui::Ui, &T),
+) -> bool {
+    let id = id.into();
+    let item_id = |item: &T| egui::Id::new(key(item));
+
+    let (is_first, saved_order) = ui.data_mut(|data| {
+        let is_first = data.get_temp::<OrderApplied>(id).is_none();
+        data.insert_temp(id, OrderApplied);
+        (is_first, data.get_persisted::<Vec<egui::Id>>(id))
+    });
+    if is_first && let Some(saved_order) = saved_order {
+        let ranks: std::collections::HashMap<egui::Id, usize> = saved_order
+            .into_iter()
+            .enumerate()
+            .map(|(i, id)| (id, i))
+            .collect();
+        items.sort_by_key(|item| ranks.get(&item_id(item)).copied().unwrap_or(usize::MAX));
+    }
+
+    let changed = reorderable_list(ui, id.with("dnd"), items, |ui, item| row_fn(ui, item));
+
+    if changed || is_first {
+        let order: Vec<egui::Id> = items.iter().map(item_id).collect();
+        ui.data_mut(|data| data.insert_persisted(id, order));
+    }
+    changed
+}
+
+/// Cancels the drag in the [`Dnd`] with the given ID, and clears the marker
+/// that `finish()` has not been called.
+#[cfg(feature = "inspector")]
+pub(crate) fn clear_state(ctx: &egui::Context, id: egui::Id) {
+    ctx.data_mut(|data| {
+        data.remove_temp::<DndDragState>(id);
+        data.remove_temp::<()>(id);
+    });
+}
+
+/// Scrolls the enclosing scroll area using the mouse wheel or trackpad while
+/// dragging, which [`egui::ScrollArea`] ignores while anything is dragged.
+fn wheel_scroll(ui: &egui::Ui) {
+    let in_scroll_area =
+        (ui.stack().iter()).any(|frame| frame.kind() == Some(egui::UiKind::ScrollArea));
+    // The payload is in a layer above the scroll area, so check the position
+    // directly instead of using `ui.rect_contains_pointer()`.
+    let pointer = ui.ctx().pointer_interact_pos();
+    if !in_scroll_area || !pointer.is_some_and(|pos| ui.clip_rect().contains(pos)) {
+        return;
+    }
+    let delta = ui.input_mut(|input| std::mem::take(&mut input.smooth_scroll_delta));
+    if delta != egui::Vec2::ZERO {
+        ui.scroll_with_delta_animation(delta, egui::style::ScrollAnimation::none());
+    }
+}
+
+/// State persisted between frames for each [`Dnd`].
+#[derive(Debug, Clone)]
+struct DndDragState {
+    payload_id: egui::Id,
+    cursor_offset: egui::Vec2,
+    drop_pos: egui::Pos2,
+    payload_size: egui::Vec2,
+    /// Reorderable item and side where the payload would be inserted.
+    gap: Option<(egui::Id, BeforeOrAfter)>,
+    /// Animated gaps for [`DndStyle::reorder_gap`].
+    gaps: SmallVec<[((egui::Id, BeforeOrAfter), crate::anim::AnimatedValue<f32>); 2]>,
+    /// IDs of other objects dragged along with the payload, for
+    /// [`Dnd::draggable_multi()`].
+    others: SmallVec<[egui::Id; 4]>,
+    /// Reorder drop zone that was the target on the previous frame, for
+    /// [`Dnd::reorder_hysteresis`].
+    reorder_zone: Option<ReorderZoneKey>,
+    /// Section that the payload was in when the drag started. See
+    /// [`Dnd::set_section()`].
+    section: Option<egui::Id>,
+    /// Number of objects added using [`Dnd::pinned()`] before the payload in
+    /// its section, or `None` if the payload is from another `Dnd`.
+    pin_segment: Option<usize>,
+    /// Whether picking up the payload has been announced, for
+    /// [`Dnd::announce_drags`].
+    announced_pick_up: bool,
+    /// Reorder gap that was most recently announced, for
+    /// [`Dnd::announce_drags`].
+    announced_gap: Option<(egui::Id, BeforeOrAfter)>,
+    /// Whether releasing the payload on the previous frame would not have
+    /// dropped it anywhere.
+    over_invalid_target: bool,
+    /// Whether the payload has been shared with [`egui::DragAndDrop`]. See
+    /// [`Dnd::with_egui_drag_and_drop()`].
+    shared_with_egui: bool,
+    /// For drags started using [`Dnd::begin_drag()`] or by clicking in
+    /// [sticky mode](Dnd::with_sticky_drag()), whether the pointer has been
+    /// pressed since the drag started. The payload is dropped when it is
+    /// released.
+    click_to_drop: Option<bool>,
+    /// Index `i` of the payload and index `j` where it is shown while
+    /// dragging. See [`Dnd::with_live_preview()`].
+    preview: Option<(usize, usize)>,
+    /// Time when the drag started, from [`egui::InputState::time`].
+    start_time: Option<f64>,
+    /// Total distance that the pointer has moved since the drag started.
+    distance: f32,
+}
+impl Default for DndDragState {
+    /// This value is never actually used, but the trait impl is necessary for
+    /// [`egui::Data::remove_temp()`].
+    fn default() -> Self {
+        Self {
+            payload_id: egui::Id::NULL,
+            cursor_offset: Default::default(),
+            drop_pos: Default::default(),
+            payload_size: Default::default(),
+            gap: None,
+            gaps: SmallVec::new(),
+            others: SmallVec::new(),
+            reorder_zone: None,
+            section: None,
+            pin_segment: None,
+            announced_pick_up: false,
+            announced_gap: None,
+            over_invalid_target: false,
+            shared_with_egui: false,
+            click_to_drop: None,
+            preview: None,
+            start_time: None,
+            distance: 0.0,
+        }
+    }
+}
+
+impl<P: Clone + PartialEq + Hash, Target> Dnd<Vec<P>, Target> {
+    /// Adds a new draggable object, using `item` for the ID. If `item` is in
+    /// `selection`, then dragging it drags the whole selection and the payload
+    /// is `selection`. Otherwise the payload is just `item`. See
+    /// [`Dnd::draggable()`].
+    ///
+    /// While dragging, the other selected objects are hidden and the dragged
+    /// object is drawn as a stack with the number of objects.
+    pub fn draggable_multi<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        item: P,
+        selection: &[P],
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let id = self.id.with(&item);
+
+        let is_other = !ui.is_sizing_pass()
+            && (self.current_drag.as_ref())
+                .is_some_and(|state| state.payload_id != id && state.others.contains(&id));
+        if is_other {
+            // We must use `.scope()` *and* `.push_id()` so that the IDs are
+            // all the same as when the object is not hidden.
+            let r = ui.scope(|ui| {
+                ui.set_invisible();
+                ui.push_id(id, |ui| add_contents(ui, id).1).inner
+            });
+            let visuals = ui.visuals();
+            let paint_hole = self.paint_hole.as_ref();
+            paint_payload_hole(
+                ui.painter(),
+                r.response.rect,
+                visuals,
+                &self.style,
+                paint_hole,
+            );
+            return r;
+        }
+
+        let is_selected = selection.contains(&item);
+        let payload = if is_selected {
+            selection.to_vec()
+        } else {
+            vec![item]
+        };
+        let was_dragging = self.is_dragging();
+        let (r, _) = self.draggable_impl(ui, id, payload, |ui| add_contents(ui, id), None);
+
+        // Record the other selected objects when the drag starts.
+        if !was_dragging
+            && is_selected
+            && let Some(state) = &mut self.current_drag
+            && state.payload_id == id
+        {
+            state.others = (selection.iter())
+                .map(|p| self.id.with(p))
+                .filter(|&other_id| other_id != id)
+                .collect();
+        }
+
+        r
+    }
+}
+
+impl<I: Clone + PartialEq + Hash> Dnd<Vec<I>, (I, BeforeOrAfter)> {
+    /// Adds a new draggable object that drags the whole selection if it is
+    /// selected, using `index` for the ID. See [`Dnd::draggable_multi()`].
+    pub fn reorderable_multi<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        selection: &[I],
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let r = self.draggable_multi(ui, index.clone(), selection, add_contents);
+        self.reorder_drop_zone_before_after(ui, &r.response, index);
+        r
+    }
+}
+
+/// Paints the shadow of the payload in `shadow_shape` and moves the layer
+/// `layer_id` containing the payload by `delta`, applying
+/// [`DndStyle::payload_scale`] and [`DndStyle::payload_rotation`] around the
+/// center of `ghost_rect`.
+fn lift_payload(
+    ui: &egui::Ui,
+    style: &DndStyle,
+    layer_id: egui::LayerId,
+    shadow_shape: egui::layers::ShapeIdx,
+    ghost_rect: egui::Rect,
+    delta: egui::Vec2,
+) {
+    let ctx = ui.ctx();
+    if let Some(shadow) = style.payload_shadow {
+        let shape = shadow.as_shape(ghost_rect, style.payload_hole_rounding);
+        ctx.layer_painter(layer_id).set(shadow_shape, shape);
+    }
+
+    let center = ghost_rect.center();
+    if style.payload_rotation != 0.0 {
+        rotate_layer_shapes(ctx, layer_id, style.payload_rotation, center);
+    }
+    let scale = style.payload_scale;
+    let translation = center.to_vec2() * (1.0 - scale) + delta;
+    ctx.transform_layer_shapes(layer_id, egui::emath::TSTransform::new(translation, scale));
+}
+
+/// Clips everything painted so far on a layer to `clip_rect`.
+fn clip_layer_shapes(ctx: &egui::Context, layer_id: egui::LayerId, clip_rect: egui::Rect) {
+    ctx.graphics_mut(|graphics| {
+        let list = graphics.entry(layer_id);
+        for i in 0..list.next_idx().0 {
+            list.mutate_shape(egui::layers::ShapeIdx(i), |s| {
+                s.clip_rect = s.clip_rect.intersect(clip_rect);
+            });
+        }
+    });
+}
+
+/// Rotates everything painted so far on a layer by `angle` radians around
+/// `origin`, by tessellating it.
+fn rotate_layer_shapes(
+    ctx: &egui::Context,
+    layer_id: egui::LayerId,
+    angle: f32,
+    origin: egui::Pos2,
+) {
+    let shapes = ctx.graphics_mut(|graphics| {
+        let paint_list = std::mem::take(graphics.entry(layer_id));
+        paint_list.all_entries().cloned().collect()
+    });
+    let primitives = ctx.tessellate(shapes, ctx.pixels_per_point());
+    let rot = egui::emath::Rot2::from_angle(angle);
+    ctx.graphics_mut(|graphics| {
+        let paint_list = graphics.entry(layer_id);
+        for egui::ClippedPrimitive {
+            clip_rect,
+            primitive,
+        } in primitives
+        {
+            // Paint callbacks can't be rotated.
+            if let egui::epaint::Primitive::Mesh(mut mesh) = primitive {
+                mesh.rotate(rot, origin);
+                paint_list.add(clip_rect, egui::Shape::mesh(mesh));
+            }
+        }
+    });
+}
+
+/// Paints the objects below the payload and the number of objects, for
+/// [`Dnd::draggable_multi()`].
+fn paint_payload_stack(
+    ui: &egui::Ui,
+    layer_id: egui::LayerId,
+    stack_shape: egui::layers::ShapeIdx,
+    rect: egui::Rect,
+    others: usize,
+) {
+    /// Offset between objects in the stack.
+    const OFFSET: f32 = 4.0;
+    /// Maximum number of objects to draw below the payload.
+    const MAX_STACK: usize = 2;
+
+    let visuals = &ui.visuals().widgets.inactive;
+    let painter = ui.ctx().layer_painter(layer_id);
+    let stack = (1..=others.min(MAX_STACK))
+        .rev()
+        .map(|i| {
+            let offset = egui::Vec2::splat(OFFSET * i as f32);
+            egui::Shape::rect_filled(
+                rect.translate(offset),
+                visuals.corner_radius,
+                visuals.bg_fill,
+            )
+        })
+        .chain([egui::Shape::rect_filled(
+            rect,
+            visuals.corner_radius,
+            ui.visuals().panel_fill,
+        )])
+        .collect();
+    painter.set(stack_shape, egui::Shape::Vec(stack));
+
+    let text = (others + 1).to_string();
+    let font_id = egui::TextStyle::Small.resolve(ui.style());
+    let galley = painter.layout_no_wrap(text, font_id, ui.visuals().selection.stroke.color);
+    let radius = galley.size().max_elem() / 2.0 + 2.0;
+    let center = rect.right_top();
+    painter.circle_filled(center, radius, ui.visuals().selection.bg_fill);
+    painter.galley(
+        center - galley.size() / 2.0,
+        galley,
+        egui::Color32::PLACEHOLDER,
+    );
+}
+
+/// ID of a draggable object that has not yet passed the drag threshold or
+/// delay, stored in egui memory.
+#[derive(Debug, Clone, Copy)]
+struct PendingDrag(egui::Id);
+
+/// All pointer buttons, in the order of their indices.
+const POINTER_BUTTONS: [egui::PointerButton; egui::NUM_POINTER_BUTTONS] = [
+    egui::PointerButton::Primary,
+    egui::PointerButton::Secondary,
+    egui::PointerButton::Middle,
+    egui::PointerButton::Extra1,
+    egui::PointerButton::Extra2,
+];
+
+/// Returns which pointer buttons are in `buttons`, indexed by
+/// [`egui::PointerButton`]. See [`Dnd::with_drag_buttons()`].
+fn pointer_buttons(buttons: &[egui::PointerButton]) -> [bool; egui::NUM_POINTER_BUTTONS] {
+    let mut ret = [false; egui::NUM_POINTER_BUTTONS];
+    for &button in buttons {
+        ret[button as usize] = true;
+    }
+    ret
+}
+
+/// Marker that a touch on a drag handle is scrolling instead of dragging,
+/// stored in egui memory. See [`Dnd::with_touch_drag_axis()`].
+#[derive(Debug, Default, Clone, Copy)]
+struct TouchScroll;
+
+/// Whether a touch on a drag handle should start dragging. See
+/// [`Dnd::touch_gesture()`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum TouchGesture {
+    /// The touch hasn't moved far enough or been held long enough to tell.
+    Undecided,
+    /// The touch should start dragging.
+    Drag,
+    /// The touch should scroll by the given distance instead.
+    Scroll(egui::Vec2),
+}
+
+/// Returns a value that animates from `1.0` to `0.0`, for
+/// [`SettleAnimation::progress`].
+fn settle_progress(ui: &egui::Ui) -> crate::anim::AnimatedValue<f32> {
+    let duration = ui.style().animation_time;
+    let mut progress = crate::anim::AnimatedValue::new(1.0).with_duration(duration);
+    progress.set_target(ui.ctx(), 0.0);
+    progress
+}
+
+/// Animation for [`DndStyle::settle_animation`] and
+/// [`DndStyle::snap_back_animation`], stored in egui memory.
+#[derive(Debug, Clone)]
+struct SettleAnimation {
+    /// Rect where each item was shown when the payload was dropped, in the new
+    /// order.
+    from: Vec<egui::Rect>,
+    /// ID of the payload that is returning to where it was picked up from, and
+    /// the rect where it was released.
+    snap_back: Option<(egui::Id, egui::Rect)>,
+    /// Fraction of the distance that items have left to move, which animates
+    /// from `1.0` to `0.0`.
+    progress: crate::anim::AnimatedValue<f32>,
+}
+
+/// Widget that the payload is hovering, for [`Dnd::spring_loaded()`], stored in
+/// egui memory.
+#[derive(Debug, Copy, Clone)]
+struct SpringHover {
+    id: egui::Id,
+    /// Time when the payload started hovering the widget.
+    since: f64,
+    /// Pass when the payload was last seen hovering the widget.
+    pass_nr: u64,
+    has_sprung: bool,
+}
+
+/// Time that a drop zone added using [`Dnd::drop_zone_with_hover_delay()`] has
+/// been the target, stored in egui memory for each widget.
+#[derive(Debug, Copy, Clone)]
+struct DwellHover {
+    /// Time when the drop zone became the target.
+    since: f64,
+    /// Pass when the drop zone was last the target.
+    pass_nr: u64,
+}
+
+/// Marker that the saved order has been applied by [`persisted_order()`],
+/// stored in egui memory.
+#[derive(Debug, Copy, Clone)]
+struct OrderApplied;
+
+/// Index of the payload for [`Dnd::reorderable_hidden_rows()`], stored in egui
+/// memory.
+#[derive(Debug, Copy, Clone)]
+struct HiddenRowPayload(usize);
+
+/// Size of a draggable object in a wrapping layout on the previous frame.
+#[derive(Debug, Copy, Clone)]
+struct WrappedItemSize(egui::Vec2);
+
+/// Function that returns whether a payload may be dropped on a target. See
+/// [`Dnd::with_accept()`].
+struct AcceptFn<Payload, Target>(Box<dyn Fn(&Payload, &Target) -> bool>);
+impl<Payload, Target> std::fmt::Debug for AcceptFn<Payload, Target> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AcceptFn").finish_non_exhaustive()
+    }
+}
+
+/// Functions called when a drag starts or ends. See [`Dnd::on_drag_start()`],
+/// [`Dnd::on_drop()`], [`Dnd::on_cancel()`], and [`Dnd::on_drag_end()`].
+struct DndHooks<Payload, Target> {
+    drag_start: Option<Box<dyn Fn(&Payload)>>,
+    drop: Option<Box<dyn Fn(&DndMove<Payload, Target>)>>,
+    cancel: Option<Box<dyn Fn(&Payload)>>,
+    drag_end: Option<Box<dyn Fn()>>,
+}
+impl<Payload, Target> Default for DndHooks<Payload, Target> {
+    fn default() -> Self {
+        Self {
+            drag_start: None,
+            drop: None,
+            cancel: None,
+            drag_end: None,
+        }
+    }
+}
+impl<Payload, Target> std::fmt::Debug for DndHooks<Payload, Target> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DndHooks").finish_non_exhaustive()
     }
 }
 
-impl<I: Clone + PartialEq + Hash> Dnd<I, (I, BeforeOrAfter)> {
-    /// Adds a new draggable object, using `index` for the ID. See
-    /// [`Dnd::draggable()`].
-    pub fn reorderable<R>(
-        &mut self,
-        ui: &mut egui::Ui,
-        index: I,
-        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
-    ) -> egui::InnerResponse<R> {
-        let r = self.draggable(ui, index.clone(), add_contents);
-        self.reorder_drop_zone_before_after(ui, &r.response, index);
-        r
+/// Function that paints the hole left behind by the payload. See
+/// [`Dnd::with_hole_painter()`].
+struct HolePaintFn(Box<dyn Fn(&egui::Painter, egui::Rect)>);
+impl std::fmt::Debug for HolePaintFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("HolePaintFn").finish_non_exhaustive()
     }
+}
 
-    /// Adds a new object with a draggable handle, using `index` for the ID. See
-    /// [`Dnd::draggable()`].
-    pub fn reorderable_with_handle<R>(
-        &mut self,
-        ui: &mut egui::Ui,
-        index: I,
-        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> R,
-    ) -> egui::InnerResponse<R> {
-        self.reorderable(ui, index, |ui, id| {
-            let main_dir = ui.layout().main_dir();
-            ui.horizontal(|ui| {
-                if main_dir.is_vertical() {
-                    ui.set_width(ui.available_width());
-                }
-                (ui.add(ReorderHandle), add_contents(ui, id))
-            })
-            .inner
-        })
+/// Function that paints over the UI while dragging. See
+/// [`Dnd::with_drag_painter()`].
+struct DragPaintFn(Box<dyn Fn(&egui::Painter, &DndDragGeometry)>);
+impl std::fmt::Debug for DragPaintFn {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DragPaintFn").finish_non_exhaustive()
     }
 }
 
-/// State persisted between frames for each [`Dnd`].
-#[derive(Debug, Clone)]
-struct DndDragState {
-    payload_id: egui::Id,
-    cursor_offset: egui::Vec2,
-    drop_pos: egui::Pos2,
+/// Paints the hole left behind by the payload at `rect`, using `paint_hole` if
+/// there is one.
+fn paint_payload_hole(
+    painter: &egui::Painter,
+    rect: egui::Rect,
+    visuals: &egui::Visuals,
+    style: &DndStyle,
+    paint_hole: Option<&HolePaintFn>,
+) {
+    match paint_hole {
+        Some(HolePaintFn(paint)) => paint(painter, rect),
+        None => {
+            let fill = style.payload_hole_fill(visuals);
+            painter.rect_filled(rect, style.payload_hole_rounding, fill);
+        }
+    }
 }
-impl Default for DndDragState {
-    /// This value is never actually used, but the trait impl is necessary for
-    /// [`egui::Data::remove_temp()`].
-    fn default() -> Self {
-        Self {
-            payload_id: egui::Id::NULL,
-            cursor_offset: Default::default(),
-            drop_pos: Default::default(),
+
+/// Returns whether a drop zone on `layer_id` is visible at the drop position,
+/// where `top_layer` is the topmost [`egui::Area`] there, if any.
+///
+/// A layer that is not an area, such as one created using
+/// [`egui::UiBuilder::layer_id()`], is only covered by areas in the same or a
+/// higher [`egui::Order`].
+fn is_on_top(layer_id: egui::LayerId, top_layer: Option<egui::LayerId>) -> bool {
+    top_layer.is_none_or(|top| top == layer_id || layer_id.order > top.order)
+}
+
+/// Returns whether a payload from `payload_section` may be dropped on a drop
+/// zone in `zone_section`. See [`Dnd::set_section()`].
+fn is_in_section(zone_section: Option<egui::Id>, payload_section: Option<egui::Id>) -> bool {
+    match (zone_section, payload_section) {
+        (Some(a), Some(b)) => a == b,
+        _ => true,
+    }
+}
+
+/// Function that paints a drop zone. See [`Dnd::drop_zone_with_painter()`].
+type DropZonePaintFn = Box<dyn FnOnce(&egui::Painter, egui::Rect, bool)>;
+
+/// Function that returns whether a position is over a drop zone. See
+/// [`Dnd::drop_zone_custom()`].
+type DropZoneHitTestFn = Box<dyn Fn(egui::Pos2) -> bool>;
+
+/// Calls `paint` and moves the shapes that it paints to `shape_idx`, which must
+/// be in the same layer as `painter`.
+fn paint_in_place(
+    painter: &egui::Painter,
+    shape_idx: egui::layers::ShapeIdx,
+    paint: impl FnOnce(&egui::Painter),
+) {
+    let layer_id = painter.layer_id();
+    let next_idx =
+        |ctx: &egui::Context| ctx.graphics(|g| g.get(layer_id).map_or(0, |list| list.next_idx().0));
+    let start = next_idx(painter.ctx());
+    paint(painter);
+    let end = next_idx(painter.ctx());
+
+    painter.ctx().graphics_mut(|g| {
+        let list = g.entry(layer_id);
+        let mut shapes = vec![];
+        for i in start..end {
+            list.mutate_shape(egui::layers::ShapeIdx(i), |clipped| {
+                shapes.push(std::mem::replace(&mut clipped.shape, egui::Shape::Noop));
+            });
         }
+        list.set(shape_idx, painter.clip_rect(), egui::Shape::Vec(shapes));
+    });
+}
+
+/// Drop zone added using [`Dnd::drop_zone()`].
+struct DropZone<Target> {
+    /// Painter for the layer and clip rectangle of the drop zone.
+    painter: egui::Painter,
+    /// Placeholder shape to replace with the drop zone outline.
+    shape_idx: egui::layers::ShapeIdx,
+    rect: egui::Rect,
+    interact_rect: egui::Rect,
+    /// Target, or `None` for a trash zone added using [`Dnd::trash_zone()`].
+    target: Option<Target>,
+    /// Priority from [`Dnd::drop_zone_with_priority()`].
+    priority: i32,
+    /// Section from [`Dnd::set_section()`].
+    section: Option<egui::Id>,
+    active_color: egui::Color32,
+    inactive_color: egui::Color32,
+    /// Custom painting from [`Dnd::drop_zone_with_painter()`].
+    paint: Option<DropZonePaintFn>,
+    /// Custom hit testing from [`Dnd::drop_zone_custom()`].
+    contains: Option<DropZoneHitTestFn>,
+    /// ID of the widget and delay in seconds, for drop zones added using
+    /// [`Dnd::drop_zone_with_hover_delay()`].
+    dwell: Option<(egui::Id, f32)>,
+}
+impl<Target> DropZone<Target> {
+    /// Returns whether `pos` is over the drop zone.
+    fn contains(&self, pos: egui::Pos2) -> bool {
+        self.interact_rect.contains(pos) && self.contains.as_ref().is_none_or(|f| f(pos))
+    }
+}
+impl<Target: std::fmt::Debug> std::fmt::Debug for DropZone<Target> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DropZone")
+            .field("rect", &self.rect)
+            .field("target", &self.target)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Sharing payloads with [`egui::DragAndDrop`]. See
+/// [`Dnd::with_egui_drag_and_drop()`].
+#[derive(Debug)]
+struct EguiDnd<Payload> {
+    /// Sets the payload as the `egui::DragAndDrop` payload.
+    share: fn(&egui::Context, &Payload),
+    /// Whether the payload came from `egui::DragAndDrop`.
+    is_receiving: bool,
+}
+
+/// ID of the pass number when a [`Dnd`] last shared its payload with
+/// [`egui::DragAndDrop`], stored in egui memory.
+fn egui_source_id() -> egui::Id {
+    egui::Id::new("hcegui::dnd::egui_source")
+}
+
+/// Color of the target painted by [`Dnd::debug_paint`].
+const DEBUG_TARGET_COLOR: egui::Color32 = egui::Color32::GREEN;
+
+/// Item added using [`Dnd::reorderable()`].
+#[derive(Debug)]
+struct LastReorderable<Payload> {
+    id: egui::Id,
+    index: Payload,
+    /// ID of the drag handle, if the item is not being dragged.
+    handle_id: Option<egui::Id>,
+}
+
+/// Returns the keys that move an item earlier and later in a layout with main
+/// direction `dir`.
+fn keyboard_reorder_keys(dir: egui::Direction) -> (egui::Key, egui::Key) {
+    use egui::Key;
+    match dir {
+        egui::Direction::LeftToRight => (Key::ArrowLeft, Key::ArrowRight),
+        egui::Direction::RightToLeft => (Key::ArrowRight, Key::ArrowLeft),
+        egui::Direction::TopDown => (Key::ArrowUp, Key::ArrowDown),
+        egui::Direction::BottomUp => (Key::ArrowDown, Key::ArrowUp),
     }
 }
 
 #[derive(Debug)]
 struct ReorderTarget<Target> {
     line_endpoints: [egui::Pos2; 2],
+    /// Layer of the UI containing the drop zone, where the line is painted.
+    layer_id: egui::LayerId,
     clip_rect: egui::Rect,
     direction: egui::Direction,
     target: Target,
+    /// Reorderable item and side, used for [`DndStyle::reorder_gap`].
+    gap: Option<(egui::Id, BeforeOrAfter)>,
+    /// Area that the payload must be closest to, for zones in a grid. If this
+    /// is `None`, then only the distance along `direction` is considered.
+    area: Option<egui::Rect>,
+    /// Section from [`Dnd::set_section()`].
+    section: Option<egui::Id>,
+    /// Number of objects added using [`Dnd::pinned()`] before this in the
+    /// same section.
+    pin_segment: usize,
+    /// Whether the drop zone is next to the payload, so dropping there does
+    /// nothing and no line is drawn.
+    is_noop: bool,
+}
+impl<Target> ReorderTarget<Target> {
+    fn key(&self) -> ReorderZoneKey {
+        match self.gap {
+            Some(gap) => ReorderZoneKey::Item(gap),
+            None => ReorderZoneKey::Line(self.line_endpoints),
+        }
+    }
+
+    /// Returns whether this is the same drop zone as `key` from a previous
+    /// frame.
+    fn is_same_zone(&self, key: ReorderZoneKey) -> bool {
+        match (self.key(), key) {
+            (ReorderZoneKey::Item(a), ReorderZoneKey::Item(b)) => a == b,
+            // Allow for floating-point error in the layout.
+            (ReorderZoneKey::Line([a1, b1]), ReorderZoneKey::Line([a2, b2])) => {
+                a1.distance(a2) < 0.5 && b1.distance(b2) < 0.5
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Identity of a [`ReorderTarget`] that persists between frames.
+#[derive(Debug, Copy, Clone, PartialEq)]
+enum ReorderZoneKey {
+    /// Side of a reorderable item, which stays the same even when the item
+    /// moves.
+    Item((egui::Id, BeforeOrAfter)),
+    /// Location of the reorder line.
+    Line([egui::Pos2; 2]),
 }
 
 /// Response from a drag-and-drop.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum DndResponse<Payload, Target> {
     /// Not dragging.
     #[default]
     Inactive,
-    /// In the middle of a drag-and-drop.
+    /// In the middle of a drag-and-drop. The target is where the payload would
+    /// be dropped, if anywhere.
     MidDrag(DndMove<Payload, Option<Target>>),
     /// Just completed a drag-and-drop.
     DoneDragging(DndMove<Payload, Target>),
+    /// Just completed a drag-and-drop onto another `Dnd` in the same group,
+    /// whose ID is the target. See [`Dnd::with_group()`].
+    DroppedElsewhere(DndMove<Payload, egui::Id>),
+    /// Just cancelled a drag-and-drop by pressing <kbd>Escape</kbd> or the
+    /// [cancel button](Dnd::with_cancel_button()). The payload stays where it
+    /// was.
+    Cancelled(Payload),
+    /// Just released the payload outside [`Dnd::drag_out_rect`], such as
+    /// outside the window. See [`Dnd::with_drag_out_rect()`].
+    DraggedOut(Payload),
+    /// Just dropped the payload on a trash zone. See [`Dnd::trash_zone()`].
+    Deleted(Payload),
 }
 impl<Payload, Target> DndResponse<Payload, Target> {
     /// Returns the drag-and-drop response only on the frame the payload was
@@ -507,6 +3822,144 @@ impl<Payload, Target> DndResponse<Payload, Target> {
             _ => None,
         }
     }
+
+    /// Converts the payload using `f`.
+    pub fn map_payload<P>(self, f: impl FnOnce(Payload) -> P) -> DndResponse<P, Target> {
+        match self {
+            DndResponse::Inactive => DndResponse::Inactive,
+            DndResponse::MidDrag(m) => DndResponse::MidDrag(m.map_payload(f)),
+            DndResponse::DoneDragging(m) => DndResponse::DoneDragging(m.map_payload(f)),
+            DndResponse::DroppedElsewhere(m) => DndResponse::DroppedElsewhere(m.map_payload(f)),
+            DndResponse::Cancelled(payload) => DndResponse::Cancelled(f(payload)),
+            DndResponse::DraggedOut(payload) => DndResponse::DraggedOut(f(payload)),
+            DndResponse::Deleted(payload) => DndResponse::Deleted(f(payload)),
+        }
+    }
+
+    /// Converts the target using `f`.
+    pub fn map_target<T>(self, f: impl FnOnce(Target) -> T) -> DndResponse<Payload, T> {
+        self.and_then_target(|target| Some(f(target)))
+    }
+
+    /// Converts the target using `f`, which returns `None` if the target
+    /// should be ignored.
+    ///
+    /// This is useful for picking out one kind of target from a `Dnd` with
+    /// several. If `f` returns `None` for the target of
+    /// [`DndResponse::MidDrag`], then the target becomes `None`. If `f` returns
+    /// `None` for the target of [`DndResponse::DoneDragging`], then the
+    /// response becomes [`DndResponse::Inactive`].
+    pub fn and_then_target<T>(
+        self,
+        f: impl FnOnce(Target) -> Option<T>,
+    ) -> DndResponse<Payload, T> {
+        match self {
+            DndResponse::Inactive => DndResponse::Inactive,
+            DndResponse::MidDrag(m) => DndResponse::MidDrag(m.map_target(|t| t.and_then(f))),
+            DndResponse::DoneDragging(DndMove { payload, target }) => match f(target) {
+                Some(target) => DndResponse::DoneDragging(DndMove { payload, target }),
+                None => DndResponse::Inactive,
+            },
+            DndResponse::DroppedElsewhere(m) => DndResponse::DroppedElsewhere(m),
+            DndResponse::Cancelled(payload) => DndResponse::Cancelled(payload),
+            DndResponse::DraggedOut(payload) => DndResponse::DraggedOut(payload),
+            DndResponse::Deleted(payload) => DndResponse::Deleted(payload),
+        }
+    }
+}
+
+/// Response from drop zones for files from outside the application. See
+/// [`Dnd::os_file_drop_zone()`].
+pub type OsFileDndResponse<Target> = DndResponse<Vec<egui::DroppedFile>, Target>;
+
+/// Measurements of a drag in progress. See [`Dnd::drag_metrics()`].
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct DndDragMetrics {
+    /// Total distance that the pointer has moved since the drag started.
+    pub distance: f32,
+    /// Time in seconds since the drag started.
+    pub elapsed: f32,
+    /// Velocity of the pointer in points per second.
+    pub velocity: egui::Vec2,
+}
+
+/// Geometry of a drag in progress. See [`Dnd::with_drag_painter()`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct DndDragGeometry {
+    /// Rectangle of the dragged object in its original place, or `None` if it
+    /// was not added to this `Dnd` this frame, such as when it is dragged
+    /// from another `Dnd` in the same group.
+    pub source_rect: Option<egui::Rect>,
+    /// Rectangle of the payload following the pointer, before
+    /// [`DndStyle::payload_scale`] and [`DndStyle::payload_rotation`].
+    pub payload_rect: egui::Rect,
+    /// Location of the target where the payload is being hovered.
+    pub hover: Option<DndHover>,
+}
+
+/// Location of the target where the payload is being hovered. See
+/// [`Dnd::finish_with_hover()`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DndHover {
+    /// Rectangle of the drop zone, or the line segment where the payload will
+    /// be inserted.
+    pub rect: egui::Rect,
+    /// Kind of target.
+    pub kind: DndHoverKind,
+    /// Position of the payload, which is used to find the target.
+    pub pos: egui::Pos2,
+}
+impl DndHover {
+    /// Returns the position of the payload within `rect`, from 0.0 at the
+    /// left or top edge to 1.0 at the right or bottom edge.
+    pub fn fraction(&self) -> egui::Vec2 {
+        let f = |x: f32, range: egui::Rangef| {
+            if range.span() > 0.0 {
+                ((x - range.min) / range.span()).clamp(0.0, 1.0)
+            } else {
+                0.5
+            }
+        };
+        egui::vec2(
+            f(self.pos.x, self.rect.x_range()),
+            f(self.pos.y, self.rect.y_range()),
+        )
+    }
+
+    /// Returns which edge of `rect` the payload is near along `direction`, or
+    /// `None` if it is in the middle. `edge` is the size of each edge as a
+    /// fraction of `rect`, such as 0.25 for the first and last quarter.
+    ///
+    /// This is useful for a drop zone that inserts the payload before or after
+    /// an object when dropped near its edges, and inside it when dropped in
+    /// the middle.
+    pub fn edge(&self, direction: egui::Direction, edge: f32) -> Option<BeforeOrAfter> {
+        let fraction = self.fraction();
+        let t = match direction {
+            egui::Direction::LeftToRight => fraction.x,
+            egui::Direction::RightToLeft => 1.0 - fraction.x,
+            egui::Direction::TopDown => fraction.y,
+            egui::Direction::BottomUp => 1.0 - fraction.y,
+        };
+        if t < edge {
+            Some(BeforeOrAfter::Before)
+        } else if t > 1.0 - edge {
+            Some(BeforeOrAfter::After)
+        } else {
+            None
+        }
+    }
+}
+
+/// Kind of target where the payload is being hovered.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DndHoverKind {
+    /// Drop zone added using [`Dnd::drop_zone()`].
+    DropZone,
+    /// Reorder drop zone, such as one added using [`Dnd::reorderable()`].
+    Reorder,
 }
 
 /// Drag-and-drop for reordering a sequence.
@@ -514,6 +3967,7 @@ pub type ReorderDnd<I = usize> = Dnd<I, (I, BeforeOrAfter)>;
 
 /// Drag-and-drop move.
 #[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct DndMove<Payload, Target> {
     /// Thing being moved.
     pub payload: Payload,
@@ -525,13 +3979,29 @@ impl<Payload, Target> DndMove<Payload, Target> {
     pub fn new(payload: Payload, target: Target) -> Self {
         Self { payload, target }
     }
+
+    /// Converts the payload using `f`.
+    pub fn map_payload<P>(self, f: impl FnOnce(Payload) -> P) -> DndMove<P, Target> {
+        DndMove::new(f(self.payload), self.target)
+    }
+
+    /// Converts the target using `f`.
+    pub fn map_target<T>(self, f: impl FnOnce(Target) -> T) -> DndMove<Payload, T> {
+        DndMove::new(self.payload, f(self.target))
+    }
 }
 
 /// Drag-and-drop move for reordering a sequence.
+///
+/// The payload is the index of the element being moved, and the target is the
+/// index of the element it is placed before or after. Both indices are from
+/// before the move. For example, in `[a, b, c, d]`, moving `a` (index 0) after
+/// `c` (index 2) results in `[b, c, a, d]`, where `a` is at index 1.
 pub type ReorderDndMove<I = usize> = DndMove<I, (I, BeforeOrAfter)>;
 impl ReorderDndMove {
     /// Returns the `i` and `j` such that the element at index `i` should shift
-    /// to index `j`.
+    /// to index `j`. `i` is the index before the move and `j` is the index
+    /// after the move, and the elements in between shift by one to make room.
     pub fn list_reorder_indices(self) -> (usize, usize) {
         let i = self.payload;
         let (j, before_or_after) = self.target;
@@ -544,42 +4014,429 @@ impl ReorderDndMove {
         }
     }
 
-    /// Reorders a slice.
-    pub fn reorder<T>(self, v: &mut [T]) {
+    /// Returns the index where the payload should be inserted after it is
+    /// removed from a sequence of length `len`.
+    ///
+    /// This is useful for backends that move an element by removing it and
+    /// inserting it at a position, such as databases. The result is at most
+    /// `len - 1`.
+    pub fn insertion_index(self, len: usize) -> usize {
+        let (_, j) = self.list_reorder_indices();
+        j.min(len.saturating_sub(1))
+    }
+
+    /// Returns whether the move leaves the sequence unchanged, such as when
+    /// the payload is dropped right before or after itself.
+    ///
+    /// This is useful for skipping undo entries and dirty flags for drops
+    /// that do nothing.
+    pub fn is_noop(self) -> bool {
+        let (i, j) = self.list_reorder_indices();
+        i == j
+    }
+
+    /// Reorders a sequence, such as a slice, [`Vec`], or
+    /// [`std::collections::VecDeque`].
+    pub fn reorder<S: ReorderSequence + ?Sized>(self, v: &mut S) {
+        let (i, j) = self.list_reorder_indices();
+        v.move_element(i, j);
+    }
+
+    /// Returns the move that undoes this one.
+    ///
+    /// This is useful for recording moves in an undo history.
+    #[must_use]
+    pub fn inverse(self) -> Self {
         let (i, j) = self.list_reorder_indices();
-        if i < j {
-            v[i..=j].rotate_left(1);
+        if i > j {
+            Self::new(j, (i, BeforeOrAfter::After))
+        } else {
+            Self::new(j, (i, BeforeOrAfter::Before))
+        }
+    }
+}
+
+impl<K: PartialEq> ReorderDndMove<K> {
+    /// Reorders a sequence, finding the payload and target elements using
+    /// `key` instead of their indices. Returns `false`, without changing `v`,
+    /// if either one is not found.
+    ///
+    /// This is useful when only some elements are shown, such as when the list
+    /// is filtered by a search query, so the indices shown don't match the
+    /// indices in `v`.
+    pub fn reorder_by_key<S: ReorderSequence + ?Sized>(
+        self,
+        v: &mut S,
+        mut key: impl FnMut(&S::Item) -> K,
+    ) -> bool {
+        let (target, before_or_after) = self.target;
+        let Some(i) = v.position(|elem| key(elem) == self.payload) else {
+            return false;
+        };
+        let Some(j) = v.position(|elem| key(elem) == target) else {
+            return false;
+        };
+        ReorderDndMove::new(i, (j, before_or_after)).reorder(v);
+        true
+    }
+}
+
+/// Sequence that can be reordered using [`ReorderDndMove::reorder()`].
+pub trait ReorderSequence {
+    /// Type used to find elements in [`ReorderDndMove::reorder_by_key()`].
+    /// For maps, this is the key.
+    type Item;
+
+    /// Moves the element at index `from` to index `to`, shifting the elements
+    /// in between.
+    fn move_element(&mut self, from: usize, to: usize);
+
+    /// Returns the index of the first element for which `predicate` returns
+    /// `true`.
+    fn position(&self, predicate: impl FnMut(&Self::Item) -> bool) -> Option<usize>;
+}
+impl<T> ReorderSequence for [T] {
+    type Item = T;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        if from < to {
+            self[from..=to].rotate_left(1);
         } else {
-            v[j..=i].rotate_right(1);
+            self[to..=from].rotate_right(1);
+        }
+    }
+
+    fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+}
+impl<T, const N: usize> ReorderSequence for [T; N] {
+    type Item = T;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.as_mut_slice().move_element(from, to);
+    }
+
+    fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+}
+impl<T> ReorderSequence for Vec<T> {
+    type Item = T;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.as_mut_slice().move_element(from, to);
+    }
+
+    fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+}
+impl<T> ReorderSequence for std::collections::VecDeque<T> {
+    type Item = T;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.make_contiguous().move_element(from, to);
+    }
+
+    fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+}
+impl<A: smallvec::Array> ReorderSequence for SmallVec<A> {
+    type Item = A::Item;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.as_mut_slice().move_element(from, to);
+    }
+
+    fn position(&self, predicate: impl FnMut(&A::Item) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+}
+/// **Requires `indexmap` feature.**
+#[cfg(feature = "indexmap")]
+impl<K, V, S> ReorderSequence for indexmap::IndexMap<K, V, S> {
+    type Item = K;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.move_index(from, to);
+    }
+
+    fn position(&self, predicate: impl FnMut(&K) -> bool) -> Option<usize> {
+        self.keys().position(predicate)
+    }
+}
+/// **Requires `indexmap` feature.**
+#[cfg(feature = "indexmap")]
+impl<T, S> ReorderSequence for indexmap::IndexSet<T, S> {
+    type Item = T;
+
+    fn move_element(&mut self, from: usize, to: usize) {
+        self.move_index(from, to);
+    }
+
+    fn position(&self, predicate: impl FnMut(&T) -> bool) -> Option<usize> {
+        self.iter().position(predicate)
+    }
+}
+
+/// Drag-and-drop for reordering a sequence, with multiple objects dragged at
+/// once using [`Dnd::draggable_multi()`].
+pub type MultiReorderDnd<I = usize> = Dnd<Vec<I>, (I, BeforeOrAfter)>;
+
+/// Drag-and-drop move for reordering a sequence, with multiple objects.
+pub type MultiReorderDndMove<I = usize> = DndMove<Vec<I>, (I, BeforeOrAfter)>;
+impl MultiReorderDndMove {
+    /// Moves all the payload elements to the target, keeping them in the same
+    /// order, and returns the range of indices they now occupy.
+    ///
+    /// Out-of-range and duplicate indices are ignored.
+    pub fn reorder<T>(self, v: &mut Vec<T>) -> std::ops::Range<usize> {
+        let (j, before_or_after) = self.target;
+        let mut indices = self.payload;
+        indices.retain(|&i| i < v.len());
+        indices.sort_unstable();
+        indices.dedup();
+
+        let insert_index = match before_or_after {
+            BeforeOrAfter::Before => j,
+            BeforeOrAfter::After => j + 1,
+        };
+        let insert_index = insert_index.min(v.len());
+        let shift = indices.iter().filter(|&&i| i < insert_index).count();
+
+        let mut moved: Vec<T> = indices.iter().rev().map(|&i| v.remove(i)).collect();
+        moved.reverse();
+        let start = insert_index - shift;
+        let end = start + moved.len();
+        v.splice(start..start, moved);
+        start..end
+    }
+}
+
+/// Drag-and-drop for moving elements between several lists. The payload is
+/// the index of the list and the index of the element in it, and the target is
+/// the index of the list and the index of the element to drop before or after,
+/// or `None` to drop at the end of the list, such as when the list is empty.
+pub type CrossListDnd = Dnd<(usize, usize), ((usize, Option<usize>), BeforeOrAfter)>;
+
+/// Drag-and-drop move for moving elements between several lists. See
+/// [`CrossListDnd`].
+pub type CrossListDndMove = DndMove<(usize, usize), ((usize, Option<usize>), BeforeOrAfter)>;
+impl CrossListDndMove {
+    /// Moves the payload element to the target, and returns the index of the
+    /// list and the index of the element in it where it ends up.
+    ///
+    /// `lists` may contain the lists themselves or mutable references to them,
+    /// such as `[&mut a, &mut b]`.
+    pub fn move_between<T, L: AsMut<Vec<T>>>(self, lists: &mut [L]) -> (usize, usize) {
+        let (i1, j1) = self.payload;
+        let ((i2, j2), before_or_after) = self.target;
+        if i1 == i2
+            && let Some(j2) = j2
+        {
+            let m = ReorderDndMove::new(j1, (j2, before_or_after));
+            m.reorder(lists[i1].as_mut());
+            return (i1, m.list_reorder_indices().1);
         }
+
+        let elem = lists[i1].as_mut().remove(j1);
+        let dest = lists[i2].as_mut();
+        let j = match (j2, before_or_after) {
+            (Some(j2), BeforeOrAfter::Before) => j2.min(dest.len()),
+            (Some(j2), BeforeOrAfter::After) => (j2 + 1).min(dest.len()),
+            (None, _) => dest.len(),
+        };
+        dest.insert(j, elem);
+        (i2, j)
     }
 }
 
 /// Visual handle for dragging widgets.
-pub struct ReorderHandle;
+///
+/// By default, this is a 12×20 grip of six dots.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReorderHandle {
+    size: egui::Vec2,
+    grip: HandleGrip,
+    horizontal: bool,
+    color: Option<egui::Color32>,
+    hover_color: Option<egui::Color32>,
+    active_color: Option<egui::Color32>,
+    description: Cow<'static, str>,
+}
+impl Default for ReorderHandle {
+    fn default() -> Self {
+        Self {
+            size: egui::vec2(12.0, 20.0),
+            grip: HandleGrip::Dots {
+                columns: 2,
+                rows: 3,
+            },
+            horizontal: false,
+            color: None,
+            hover_color: None,
+            active_color: None,
+            description: DndText::default().drag_handle_description,
+        }
+    }
+}
+impl ReorderHandle {
+    /// Constructs a six-dot drag handle.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the size of the handle, before applying
+    /// [`ReorderHandle::horizontal()`]. Default: 12×20.
+    #[must_use]
+    pub fn size(mut self, size: egui::Vec2) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the grip to a grid of dots. Default: 2 columns and 3 rows.
+    #[must_use]
+    pub fn dots(mut self, columns: usize, rows: usize) -> Self {
+        self.grip = HandleGrip::Dots { columns, rows };
+        self
+    }
+
+    /// Sets the grip to horizontal bars stacked vertically, like `≡`.
+    #[must_use]
+    pub fn bars(mut self, count: usize) -> Self {
+        self.grip = HandleGrip::Bars { count };
+        self
+    }
+
+    /// Sets the grip to a text glyph, such as `"⠿"` or `"↕"`.
+    #[must_use]
+    pub fn glyph(mut self, glyph: impl Into<String>) -> Self {
+        self.grip = HandleGrip::Glyph(glyph.into());
+        self
+    }
+
+    /// Sets whether the handle is rotated a quarter turn, such as for items in
+    /// a horizontal toolbar. This swaps the width and height, the columns and
+    /// rows of dots, and the direction of bars. Glyphs are not rotated.
+    #[must_use]
+    pub fn horizontal(mut self, horizontal: bool) -> Self {
+        self.horizontal = horizontal;
+        self
+    }
+
+    /// Sets the color of the grip when it is not hovered. Default:
+    /// [`egui::Visuals::weak_text_color()`].
+    #[must_use]
+    pub fn color(mut self, color: egui::Color32) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Sets the color of the grip when it is hovered. Default:
+    /// [`egui::Visuals::text_color()`].
+    #[must_use]
+    pub fn hover_color(mut self, color: egui::Color32) -> Self {
+        self.hover_color = Some(color);
+        self
+    }
+
+    /// Sets the color of the grip when it is focused or being dragged.
+    /// Default: [`egui::Visuals::strong_text_color()`].
+    #[must_use]
+    pub fn active_color(mut self, color: egui::Color32) -> Self {
+        self.active_color = Some(color);
+        self
+    }
+
+    /// Sets the description of the handle for screen readers. Default:
+    /// [`DndText::drag_handle_description`].
+    #[must_use]
+    pub fn description(mut self, description: impl Into<Cow<'static, str>>) -> Self {
+        self.description = description.into();
+        self
+    }
+}
 impl egui::Widget for ReorderHandle {
     fn ui(self, ui: &mut egui::Ui) -> egui::Response {
-        let (rect, r) = ui.allocate_exact_size(egui::vec2(12.0, 20.0), egui::Sense::drag());
+        let size = if self.horizontal {
+            egui::vec2(self.size.y, self.size.x)
+        } else {
+            self.size
+        };
+        let (rect, r) = ui.allocate_exact_size(size, egui::Sense::drag());
+        crate::a11y::describe(&r, egui::WidgetType::Other, &self.description);
         if ui.is_rect_visible(rect) {
+            crate::a11y::paint_focus_outline(ui, &r);
+
             // Change color based on hover/focus.
             let color = if r.has_focus() || r.dragged() {
-                ui.visuals().strong_text_color()
+                (self.active_color).unwrap_or_else(|| ui.visuals().strong_text_color())
             } else if r.hovered() {
-                ui.visuals().text_color()
+                (self.hover_color).unwrap_or_else(|| ui.visuals().text_color())
             } else {
-                ui.visuals().weak_text_color()
+                (self.color).unwrap_or_else(|| ui.visuals().weak_text_color())
             };
 
-            // Draw 6 dots.
-            let r = ui.spacing().button_padding.x / 2.0;
-            for dy in [-2.0, 0.0, 2.0] {
-                for dx in [-1.0, 1.0] {
-                    const RADIUS: f32 = 1.0;
-                    let pos = rect.center() + egui::vec2(dx, dy) * r;
-                    ui.painter().circle_filled(pos, RADIUS, color);
+            // Offsets are computed for a vertical handle, then swapped if it
+            // is horizontal.
+            let spacing = ui.spacing().button_padding.x;
+            let ppp = ui.pixels_per_point();
+            let offset = |x: f32, y: f32| {
+                if self.horizontal {
+                    egui::vec2(y, x)
+                } else {
+                    egui::vec2(x, y)
+                }
+            };
+            let centered = |i: usize, n: usize| (i as f32 - (n as f32 - 1.0) / 2.0) * spacing;
+            match self.grip {
+                HandleGrip::Dots { columns, rows } => {
+                    for y in 0..rows {
+                        for x in 0..columns {
+                            const RADIUS: f32 = 1.0;
+                            let pos =
+                                rect.center() + offset(centered(x, columns), centered(y, rows));
+                            let pos = crate::util::snap_stroke_pos(pos, RADIUS * 2.0, ppp);
+                            ui.painter().circle_filled(pos, RADIUS, color);
+                        }
+                    }
+                }
+                HandleGrip::Bars { count } => {
+                    const WIDTH: f32 = 1.0;
+                    let half_length = (self.size.x / 2.0 - spacing / 2.0).max(0.0);
+                    for y in 0..count {
+                        let y = centered(y, count);
+                        let points = [
+                            rect.center() + offset(-half_length, y),
+                            rect.center() + offset(half_length, y),
+                        ];
+                        let points = crate::util::snap_line_segment(points, WIDTH, ppp);
+                        ui.painter()
+                            .line_segment(points, egui::Stroke::new(WIDTH, color));
+                    }
+                }
+                HandleGrip::Glyph(glyph) => {
+                    let font_id = egui::TextStyle::Body.resolve(ui.style());
+                    ui.painter().text(
+                        rect.center(),
+                        egui::Align2::CENTER_CENTER,
+                        glyph,
+                        font_id,
+                        color,
+                    );
                 }
             }
         }
         r
     }
 }
+
+/// Appearance of a [`ReorderHandle`].
+#[derive(Debug, Clone, PartialEq)]
+enum HandleGrip {
+    Dots { columns: usize, rows: usize },
+    Bars { count: usize },
+    Glyph(String),
+}
@@ -0,0 +1,301 @@
+//! Dragging payloads between [`Dnd`]s in a group, possibly in different
+//! viewports. See [`Dnd::with_group()`].
+
+use std::any::Any;
+use std::sync::Arc;
+
+use super::{Dnd, DndDragState, DndResponse};
+
+impl<Payload, Target> Dnd<Payload, Target> {
+    /// Adds this `Dnd` to a group, so that payloads can be dragged between it
+    /// and other `Dnd`s in the same group, even if they have different types.
+    ///
+    /// `convert` is called on payloads from other `Dnd`s in the group that
+    /// have type `Other`, and returns `None` if the payload cannot be dropped
+    /// here. If the payload types are the same, use `Some`.
+    ///
+    /// When a payload from another `Dnd` is dropped here, this `Dnd` returns
+    /// [`DndResponse::DoneDragging`] and the `Dnd` where the drag started
+    /// returns [`DndResponse::DroppedElsewhere`].
+    ///
+    /// `Dnd`s in a group may be in different viewports, as long as the
+    /// position of each viewport on the screen is known. A payload dragged
+    /// into another viewport is shown there as a rectangle the size of the
+    /// dragged object. The `Dnd` where the drag started may return
+    /// [`DndResponse::DroppedElsewhere`] before the other viewport returns
+    /// [`DndResponse::DoneDragging`], so `convert` should not depend on the
+    /// payload still being in its original list.
+    ///
+    /// ```
+    /// # egui::__run_test_ui(|ui| {
+    /// use hcegui::dnd::Dnd;
+    ///
+    /// // Numbers can be dragged onto the strings, but not the other way around.
+    /// let numbers_dnd = Dnd::<u32, usize>::new(ui.ctx(), "numbers")
+    ///     .with_group("lists", |_: String| None);
+    /// let strings_dnd = Dnd::<String, usize>::new(ui.ctx(), "strings")
+    ///     .with_group("lists", |n: u32| Some(n.to_string()));
+    /// # let _ = (numbers_dnd.finish(ui), strings_dnd.finish(ui));
+    /// # });
+    /// ```
+    #[must_use]
+    pub fn with_group<Other: Clone + Send + Sync + 'static>(
+        mut self,
+        group: impl Into<egui::Id>,
+        convert: impl FnOnce(Other) -> Option<Payload>,
+    ) -> Self
+    where
+        Payload: Clone + Send + Sync + 'static,
+    {
+        let group_id = group.into();
+        let pass_nr = group_pass_nr(&self.ctx);
+        let viewport = self.ctx.viewport_id();
+        let pointer_active =
+            (self.ctx).input(|input| input.pointer.any_down() || input.pointer.any_released());
+        let record = self.ctx.data_mut(|data| {
+            let record = data.get_temp::<GroupDrag>(group_id)?;
+            let is_active = record.is_active(pass_nr);
+            // A drag from another viewport continues until the pointer is
+            // released there.
+            if is_active && (pointer_active || record.viewport != viewport) {
+                Some(record)
+            } else if is_active && record.released {
+                None // other viewports may not have seen the release yet
+            } else {
+                data.remove::<GroupDrag>(group_id);
+                None
+            }
+        });
+
+        // Convert positions from the viewport where the drag started.
+        let mut remote_pointer = None;
+        let record = record.and_then(|mut record| {
+            if record.viewport != viewport {
+                let rect = viewport_screen_rect(&self.ctx)?;
+                let offset = record.screen_offset? - rect.min.to_vec2();
+                let pos = record.pointer_pos? + offset;
+                if !rect.contains(pos + rect.min.to_vec2()) {
+                    return None;
+                }
+                record.drop_pos += offset;
+                remote_pointer = Some(RemotePointer {
+                    pos,
+                    released: record.released,
+                });
+            }
+            Some(record)
+        });
+
+        let mut is_receiving = false;
+        if self.current_drag.is_none()
+            && let Some(record) = record
+            && record.source != self.id
+            && let Some(payload) = record.payload.downcast_ref::<Other>()
+            && let Some(payload) = convert(payload.clone())
+        {
+            self.current_drag = Some(DndDragState {
+                payload_id: record.payload_id,
+                drop_pos: record.drop_pos,
+                payload_size: record.payload_size,
+                ..Default::default()
+            });
+            self.payload = Some(payload);
+            is_receiving = true;
+        }
+
+        self.group = Some(DndGroup {
+            id: group_id,
+            share: |payload| Arc::new(payload.clone()),
+            is_receiving,
+            remote_pointer: remote_pointer.filter(|_| is_receiving),
+        });
+        self
+    }
+
+    /// Returns the ID of the `Dnd` in `group` that had a target on the previous
+    /// pass.
+    pub(super) fn group_hovered(&self, group: &DndGroup<Payload>) -> Option<egui::Id> {
+        let pass_nr = group_pass_nr(&self.ctx);
+        let record = self.ctx.data(|data| data.get_temp::<GroupDrag>(group.id));
+        record.and_then(|r| r.previously_hovered(pass_nr))
+    }
+
+    /// Updates the shared state of the group after computing the response.
+    pub(super) fn update_group(
+        &self,
+        group: &DndGroup<Payload>,
+        response: &DndResponse<Payload, Target>,
+        state: Option<&DndDragState>,
+    ) {
+        let pass_nr = group_pass_nr(&self.ctx);
+        let m = match response {
+            DndResponse::MidDrag(m) => m,
+            DndResponse::Cancelled(_) | DndResponse::DraggedOut(_) if !group.is_receiving => {
+                self.ctx.data_mut(|data| data.remove::<GroupDrag>(group.id));
+                return;
+            }
+            DndResponse::Deleted(_) => {
+                self.ctx.data_mut(|data| data.remove::<GroupDrag>(group.id));
+                return;
+            }
+            DndResponse::DoneDragging(_) if group.remote_pointer.is_some() => {
+                self.ctx.data_mut(|data| data.remove::<GroupDrag>(group.id));
+                return;
+            }
+            DndResponse::DoneDragging(_)
+            | DndResponse::DroppedElsewhere(_)
+            | DndResponse::Inactive
+                if !group.is_receiving && self.ctx.input(|input| input.pointer.any_released()) =>
+            {
+                // Tell `Dnd`s in other viewports that the payload was released.
+                self.ctx.data_mut(|data| {
+                    if let Some(mut record) = data.get_temp::<GroupDrag>(group.id)
+                        && record.source == self.id
+                        && !record.released
+                    {
+                        record.released = true;
+                        record.pass_nr = pass_nr;
+                        data.insert_temp(group.id, record);
+                    }
+                });
+                return;
+            }
+            _ => return,
+        };
+        let viewport = self.ctx.viewport_id();
+        let screen_offset = viewport_screen_rect(&self.ctx).map(|rect| rect.min.to_vec2());
+        let pointer_pos = self.ctx.pointer_interact_pos();
+        self.ctx.data_mut(|data| {
+            let mut record = match (state, group.is_receiving) {
+                (Some(state), false) => {
+                    let old = data.get_temp::<GroupDrag>(group.id);
+                    GroupDrag {
+                        source: self.id,
+                        payload: (group.share)(&m.payload),
+                        payload_id: state.payload_id,
+                        drop_pos: state.drop_pos,
+                        payload_size: state.payload_size,
+                        pass_nr,
+                        hovered: old.map(|old| old.hovered).unwrap_or_default(),
+                        viewport,
+                        screen_offset,
+                        pointer_pos,
+                        released: false,
+                    }
+                }
+                _ => match data.get_temp::<GroupDrag>(group.id) {
+                    Some(record) => record,
+                    None => return,
+                },
+            };
+            if m.target.is_some() {
+                record.hovered.set(pass_nr, self.id);
+            }
+            data.insert_temp(group.id, record);
+        });
+    }
+}
+
+/// Group that a [`Dnd`] belongs to. See [`Dnd::with_group()`].
+#[derive(Debug)]
+pub(super) struct DndGroup<Payload> {
+    pub(super) id: egui::Id,
+    /// Converts the payload to a type-erased value to share with the group.
+    pub(super) share: fn(&Payload) -> Arc<dyn Any + Send + Sync>,
+    /// Whether the payload came from another `Dnd` in the group.
+    pub(super) is_receiving: bool,
+    /// Pointer in another viewport, if the payload came from a `Dnd` there.
+    pub(super) remote_pointer: Option<RemotePointer>,
+}
+
+/// Pointer in the viewport where a drag started, for a [`Dnd`] in another
+/// viewport. See [`Dnd::with_group()`].
+#[derive(Debug, Copy, Clone)]
+pub(super) struct RemotePointer {
+    /// Position of the pointer, in the coordinates of this viewport.
+    pub(super) pos: egui::Pos2,
+    /// Whether the pointer was released.
+    pub(super) released: bool,
+}
+
+/// Drag shared between the [`Dnd`]s in a group, stored in egui memory.
+#[derive(Clone)]
+struct GroupDrag {
+    /// ID of the `Dnd` where the drag started.
+    source: egui::Id,
+    payload: Arc<dyn Any + Send + Sync>,
+    payload_id: egui::Id,
+    drop_pos: egui::Pos2,
+    payload_size: egui::Vec2,
+    /// Pass when the source `Dnd` last updated this. See [`group_pass_nr()`].
+    pass_nr: u64,
+    hovered: GroupHover,
+    /// Viewport where the drag started.
+    viewport: egui::ViewportId,
+    /// Offset from coordinates in `viewport` to screen coordinates, if known.
+    screen_offset: Option<egui::Vec2>,
+    /// Position of the pointer in `viewport`.
+    pointer_pos: Option<egui::Pos2>,
+    /// Whether the pointer was released in `viewport`.
+    released: bool,
+}
+impl GroupDrag {
+    /// Returns whether the source `Dnd` updated this on this pass or the
+    /// previous one.
+    fn is_active(&self, pass_nr: u64) -> bool {
+        self.pass_nr + 1 >= pass_nr
+    }
+
+    /// Returns the ID of the `Dnd` that had a target on the previous pass.
+    fn previously_hovered(&self, pass_nr: u64) -> Option<egui::Id> {
+        let h = &self.hovered;
+        if h.pass_nr == pass_nr {
+            h.previous
+        } else if h.pass_nr + 1 == pass_nr {
+            h.current
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the pass number used for [`GroupDrag`], which is the same in every
+/// viewport.
+///
+/// Other viewports run between passes of the root viewport, so they use the
+/// number of the root pass that just finished.
+fn group_pass_nr(ctx: &egui::Context) -> u64 {
+    let pass_nr = ctx.cumulative_pass_nr_for(egui::ViewportId::ROOT);
+    if ctx.viewport_id() == egui::ViewportId::ROOT {
+        pass_nr
+    } else {
+        pass_nr.saturating_sub(1)
+    }
+}
+
+/// Returns the rectangle of the current viewport in screen coordinates, if it
+/// is known.
+fn viewport_screen_rect(ctx: &egui::Context) -> Option<egui::Rect> {
+    ctx.input(|input| input.viewport().inner_rect)
+}
+
+/// `Dnd` in a group that had a target on the current and previous pass.
+#[derive(Debug, Default, Copy, Clone)]
+struct GroupHover {
+    pass_nr: u64,
+    current: Option<egui::Id>,
+    previous: Option<egui::Id>,
+}
+impl GroupHover {
+    fn set(&mut self, pass_nr: u64, id: egui::Id) {
+        if self.pass_nr != pass_nr {
+            self.previous = if self.pass_nr + 1 == pass_nr {
+                self.current
+            } else {
+                None
+            };
+            self.pass_nr = pass_nr;
+        }
+        self.current = Some(id);
+    }
+}
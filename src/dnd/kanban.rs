@@ -0,0 +1,185 @@
+//! Kanban board with columns of cards that can be dragged within and between
+//! columns. **Requires `dnd` feature.**
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::dnd::kanban::Kanban;
+//!
+//! struct Column {
+//!     title: &'static str,
+//!     cards: Vec<&'static str>,
+//! }
+//! impl AsRef<[&'static str]> for Column {
+//!     fn as_ref(&self) -> &[&'static str] {
+//!         &self.cards
+//!     }
+//! }
+//! impl AsMut<Vec<&'static str>> for Column {
+//!     fn as_mut(&mut self) -> &mut Vec<&'static str> {
+//!         &mut self.cards
+//!     }
+//! }
+//!
+//! let mut columns = vec![
+//!     Column { title: "To do", cards: vec!["Write docs", "Fix bug"] },
+//!     Column { title: "Doing", cards: vec!["Review PR"] },
+//!     Column { title: "Done", cards: vec![] },
+//! ];
+//! let r = Kanban::new("board").show(
+//!     ui,
+//!     &columns,
+//!     |ui, _, column| {
+//!         ui.strong(column.title);
+//!     },
+//!     |ui, card| {
+//!         ui.label(*card);
+//!     },
+//! );
+//! r.apply(&mut columns);
+//! # });
+//! ```
+
+use super::{
+    BeforeOrAfter, CrossListDnd, CrossListDndMove, ReorderDnd, ReorderDndMove, ReorderHandle,
+};
+
+/// Horizontally scrollable row of columns, each with a header and a list of
+/// cards.
+///
+/// Cards can be dragged within a column or into another column, including an
+/// empty one. Columns can be reordered by dragging the handle in their header.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Kanban {
+    id: egui::Id,
+    column_width: f32,
+    reorder_columns: bool,
+}
+impl Kanban {
+    /// Constructs a kanban board with the given ID.
+    pub fn new(id: impl Into<egui::Id>) -> Self {
+        Self {
+            id: id.into(),
+            column_width: 200.0,
+            reorder_columns: true,
+        }
+    }
+
+    /// Sets the width of each column. Default: 200.0.
+    #[must_use]
+    pub fn column_width(mut self, width: f32) -> Self {
+        self.column_width = width;
+        self
+    }
+
+    /// Sets whether columns can be reordered by dragging their header. Default:
+    /// `true`.
+    #[must_use]
+    pub fn reorder_columns(mut self, reorder_columns: bool) -> Self {
+        self.reorder_columns = reorder_columns;
+        self
+    }
+
+    /// Shows the board. `header` shows the header of the column at the given
+    /// index, and `card` shows the contents of a card.
+    ///
+    /// Each column must be convertible to a slice of cards, such as a `Vec`.
+    /// The columns are not changed. Use [`KanbanResponse::apply()`] to move
+    /// cards and columns according to the response.
+    pub fn show<C: AsRef<[T]>, T>(
+        self,
+        ui: &mut egui::Ui,
+        columns: &[C],
+        mut header: impl FnMut(&mut egui::Ui, usize, &C),
+        mut card: impl FnMut(&mut egui::Ui, &T),
+    ) -> KanbanResponse {
+        let mut column_dnd = ReorderDnd::new(ui.ctx(), self.id.with("columns"));
+        let mut card_dnd = CrossListDnd::new(ui.ctx(), self.id.with("cards"));
+
+        let handle_description = column_dnd.text.reorder_handle_description.clone();
+        // Returns the drag handle for the column.
+        let mut show_column = |ui: &mut egui::Ui, i: usize, column: &C| {
+            egui::Frame::group(ui.style())
+                .show(ui, |ui| {
+                    ui.set_width(self.column_width);
+                    let handle = ui
+                        .horizontal(|ui| {
+                            let handle = (self.reorder_columns).then(|| {
+                                let description = handle_description.clone();
+                                ui.add(ReorderHandle::new().description(description))
+                            });
+                            header(ui, i, column);
+                            handle
+                        })
+                        .inner;
+                    ui.separator();
+
+                    let cards = column.as_ref();
+                    for (j, c) in cards.iter().enumerate() {
+                        let r = card_dnd.draggable(ui, (i, j), |ui, _| {
+                            let r = egui::Frame::group(ui.style())
+                                .fill(ui.visuals().faint_bg_color)
+                                .show(ui, |ui| {
+                                    ui.set_width(ui.available_width());
+                                    card(ui, c);
+                                });
+                            (r.response, ())
+                        });
+                        card_dnd.reorder_drop_zone_before_after(ui, &r.response, (i, Some(j)));
+                    }
+                    if cards.is_empty() {
+                        let size =
+                            egui::vec2(ui.available_width(), ui.spacing().interact_size.y * 2.0);
+                        let (_, r) = ui.allocate_exact_size(size, egui::Sense::hover());
+                        card_dnd.drop_zone(ui, &r, ((i, None), BeforeOrAfter::Before));
+                    }
+                    handle
+                })
+                .inner
+        };
+
+        egui::ScrollArea::horizontal()
+            .id_salt(self.id.with("scroll"))
+            .show(ui, |ui| {
+                ui.horizontal_top(|ui| {
+                    ui.style_mut().interaction.selectable_labels = false;
+                    for (i, column) in columns.iter().enumerate() {
+                        if self.reorder_columns {
+                            column_dnd.reorderable(ui, i, |ui, _| {
+                                let r = ui.vertical(|ui| show_column(ui, i, column));
+                                (r.inner.unwrap_or(r.response), ())
+                            });
+                        } else {
+                            ui.vertical(|ui| show_column(ui, i, column));
+                        }
+                    }
+                });
+            });
+
+        KanbanResponse {
+            card_moved: card_dnd.finish(ui).if_done_dragging(),
+            column_moved: column_dnd.finish(ui).if_done_dragging(),
+        }
+    }
+}
+
+/// Response from [`Kanban::show()`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct KanbanResponse {
+    /// Card that was just dragged to a new position.
+    pub card_moved: Option<CrossListDndMove>,
+    /// Column that was just dragged to a new position.
+    pub column_moved: Option<ReorderDndMove>,
+}
+impl KanbanResponse {
+    /// Moves cards and columns according to the response.
+    pub fn apply<C: AsMut<Vec<T>>, T>(&self, columns: &mut [C]) {
+        if let Some(m) = self.card_moved {
+            m.move_between(columns);
+        }
+        if let Some(m) = self.column_moved {
+            m.reorder(columns);
+        }
+    }
+}
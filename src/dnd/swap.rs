@@ -0,0 +1,54 @@
+//! Swapping objects in fixed-size slots. See [`Dnd::swappable()`].
+
+use std::hash::Hash;
+
+use super::{Dnd, DndMove};
+
+impl<I: Clone + Hash> Dnd<I, I> {
+    /// Adds a new draggable object that swaps places with the object it is
+    /// dropped onto, using `index` for the ID and target.
+    ///
+    /// Instead of drawing a line between objects, the object under the payload
+    /// is outlined. This is useful for fixed-size slots, such as an inventory.
+    pub fn swappable<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        index: I,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let is_payload = self.payload_id() == Some(self.id.with(&index));
+        let r = self.draggable(ui, index.clone(), add_contents);
+        if !is_payload {
+            let color = (self.style.drop_zone_active_color)
+                .unwrap_or(ui.visuals().widgets.active.bg_stroke.color);
+            let stroke = egui::Stroke::new(self.style.drop_zone_stroke_width, color);
+            let rounding = self.style.drop_zone_rounding;
+            self.drop_zone_with_painter(ui, &r.response, index, move |painter, rect, active| {
+                if active {
+                    painter.rect_stroke(rect, rounding, stroke, egui::StrokeKind::Outside);
+                }
+            });
+        }
+        r
+    }
+}
+
+/// Drag-and-drop for swapping elements of a sequence. See
+/// [`Dnd::swappable()`].
+pub type SwapDnd<I = usize> = Dnd<I, I>;
+
+/// Drag-and-drop move for swapping elements of a sequence.
+pub type SwapDndMove<I = usize> = DndMove<I, I>;
+impl SwapDndMove {
+    /// Swaps the payload and target elements of a slice.
+    pub fn swap<T>(self, v: &mut [T]) {
+        v.swap(self.payload, self.target);
+    }
+
+    /// Returns the move that undoes this one, which is the same move with the
+    /// payload and target exchanged.
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Self::new(self.target, self.payload)
+    }
+}
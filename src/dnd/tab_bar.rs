@@ -0,0 +1,135 @@
+//! Row of tabs that can be selected, reordered by dragging, and closed.
+//! **Requires `dnd` feature.**
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::dnd::tab_bar::TabBar;
+//!
+//! let mut tabs = vec!["main.rs", "lib.rs"];
+//! let mut active = 0;
+//! let r = TabBar::new("tabs")
+//!     .closable(true)
+//!     .show(ui, &tabs, active, |tab| (*tab).into());
+//! r.apply(&mut tabs, &mut active);
+//! # });
+//! ```
+
+use super::{ReorderDnd, ReorderDndMove};
+
+/// Horizontally scrollable row of tabs that can be reordered by dragging.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TabBar {
+    id: egui::Id,
+    closable: bool,
+}
+impl TabBar {
+    /// Constructs a tab bar with the given ID.
+    pub fn new(id: impl Into<egui::Id>) -> Self {
+        Self {
+            id: id.into(),
+            closable: false,
+        }
+    }
+
+    /// Sets whether each tab has a close button. Default: `false`.
+    #[must_use]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// Shows the tab bar, with the tab at index `active` selected. `label`
+    /// returns the text of each tab.
+    ///
+    /// The tabs are not changed. Use [`TabBarResponse::apply()`] to select,
+    /// reorder, or close tabs according to the response.
+    pub fn show<T>(
+        self,
+        ui: &mut egui::Ui,
+        tabs: &[T],
+        active: usize,
+        mut label: impl FnMut(&T) -> egui::WidgetText,
+    ) -> TabBarResponse {
+        let mut selected = None;
+        let mut closed = None;
+
+        let mut dnd = ReorderDnd::new(ui.ctx(), self.id);
+        let r = egui::ScrollArea::horizontal()
+            .id_salt(self.id.with("scroll"))
+            .show(ui, |ui| {
+                ui.horizontal(|ui| {
+                    for (i, tab) in tabs.iter().enumerate() {
+                        dnd.reorderable(ui, i, |ui, _| {
+                            let r = ui.horizontal(|ui| {
+                                ui.spacing_mut().item_spacing.x = 0.0;
+                                let r = ui.selectable_label(i == active, label(tab));
+                                if self.closable {
+                                    let close = egui::Button::new("×").small().frame(false);
+                                    if ui.add(close).on_hover_text("Close").clicked() {
+                                        closed = Some(i);
+                                    }
+                                }
+                                r
+                            });
+                            let r = r.inner;
+                            if r.clicked() {
+                                selected = Some(i);
+                                r.scroll_to_me(None);
+                            }
+                            (r, ())
+                        });
+                    }
+                });
+            });
+
+        TabBarResponse {
+            rect: r.inner_rect,
+            selected,
+            moved: dnd.finish(ui).if_done_dragging(),
+            closed,
+        }
+    }
+}
+
+/// Response from [`TabBar::show()`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TabBarResponse {
+    /// Rectangle occupied by the tab bar.
+    pub rect: egui::Rect,
+    /// Index of the tab that was just clicked.
+    pub selected: Option<usize>,
+    /// Tab that was just dragged to a new position.
+    pub moved: Option<ReorderDndMove>,
+    /// Index of the tab whose close button was just clicked.
+    pub closed: Option<usize>,
+}
+impl TabBarResponse {
+    /// Selects, reorders, or closes tabs according to the response, and updates
+    /// `active` so that the same tab stays selected, if it still exists.
+    pub fn apply<T>(&self, tabs: &mut Vec<T>, active: &mut usize) {
+        if let Some(i) = self.selected {
+            *active = i;
+        }
+        if let Some(m) = self.moved {
+            let (i, j) = m.list_reorder_indices();
+            if *active == i {
+                *active = j;
+            } else if i < *active && *active <= j {
+                *active -= 1;
+            } else if j <= *active && *active < i {
+                *active += 1;
+            }
+            m.reorder(tabs);
+        }
+        if let Some(i) = self.closed
+            && i < tabs.len()
+        {
+            tabs.remove(i);
+            if *active > i || *active >= tabs.len() {
+                *active = active.saturating_sub(1);
+            }
+        }
+    }
+}
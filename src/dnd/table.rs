@@ -0,0 +1,101 @@
+//! Reorderable rows in an [`egui_extras::TableBuilder`] table. **Requires
+//! `extras` feature.**
+
+use std::hash::Hash;
+
+use super::{BeforeOrAfter, Dnd, ReorderHandle, clip_layer_shapes, paint_payload_hole};
+
+impl<I: Clone + PartialEq + Hash> Dnd<I, (I, BeforeOrAfter)> {
+    /// Adds a row to an [`egui_extras::TableBuilder`] table, with a
+    /// [`ReorderHandle`] in the first column, using `index` for the ID.
+    /// `add_cells` adds the rest of the cells in the row. **Requires `extras`
+    /// feature.**
+    ///
+    /// While the row is being dragged, it is shown on a solid background so
+    /// that it doesn't take the stripes of the rows it passes over.
+    pub fn reorderable_table_row<'a, 'b>(
+        &mut self,
+        row: &mut egui_extras::TableRow<'a, 'b>,
+        index: I,
+        add_cells: impl FnOnce(&mut egui_extras::TableRow<'a, 'b>),
+    ) {
+        let id = self.id.with(&index);
+
+        let mut handle_cell = None;
+        row.col(|ui| {
+            let layer_id = ui.layer_id();
+            let first_shape = ui.ctx().graphics(|g| {
+                g.get(layer_id)
+                    .map_or(egui::layers::ShapeIdx(0), |list| list.next_idx())
+            });
+            let description = self.text.drag_handle_description.clone();
+            let handle = ui.add(ReorderHandle::new().description(description));
+            if !ui.is_sizing_pass() {
+                handle_cell = Some((
+                    handle,
+                    first_shape,
+                    ui.max_rect().y_range(),
+                    ui.painter().clone(),
+                    ui.style().clone(),
+                ));
+            }
+        });
+        add_cells(row);
+        let Some((handle, first_shape, y_range, painter, style)) = handle_cell else {
+            return;
+        };
+        // Cell responses may extend past the bottom of the row, so only use
+        // them for the width.
+        let rect = egui::Rect::from_x_y_ranges(row.response().rect.x_range(), y_range);
+
+        if let Some(state) = (self.current_drag.as_mut()).filter(|state| state.payload_id == id) {
+            self.ctx.set_cursor_icon(egui::CursorIcon::Grabbing);
+            self.payload = Some(index.clone());
+
+            // Move the row to a different layer so that we can move it around
+            // independently.
+            let layer_id = egui::LayerId::new(egui::Order::Tooltip, id);
+            (self.ctx.layer_painter(layer_id)).rect_filled(rect, 0.0, style.visuals.panel_fill);
+            self.ctx.graphics_mut(|g| {
+                let list = g.entry(painter.layer_id());
+                let mut shapes = vec![];
+                for i in first_shape.0..list.next_idx().0 {
+                    list.mutate_shape(egui::layers::ShapeIdx(i), |s| {
+                        shapes.push((
+                            s.clip_rect,
+                            std::mem::replace(&mut s.shape, egui::Shape::Noop),
+                        ));
+                    });
+                }
+                let ghost = g.entry(layer_id);
+                for (clip_rect, shape) in shapes {
+                    ghost.add(clip_rect, shape);
+                }
+            });
+
+            let paint_hole = self.paint_hole.as_ref();
+            paint_payload_hole(&painter, rect, &style.visuals, &self.style, paint_hole);
+            self.payload_rects = Some((rect, rect));
+
+            if let Some(pointer_pos) = self.ctx.pointer_interact_pos() {
+                let delta = pointer_pos + state.cursor_offset - rect.left_top();
+                self.ctx.transform_layer_shapes(
+                    layer_id,
+                    egui::emath::TSTransform::from_translation(delta),
+                );
+                if let Some(clip_rect) = self.payload_clip_rect {
+                    clip_layer_shapes(&self.ctx, layer_id, clip_rect);
+                }
+                state.drop_pos = rect.center() + delta;
+                state.payload_size = rect.size();
+                self.payload_rects = Some((rect, rect.translate(delta)));
+            }
+        } else {
+            self.handle_drag_start(id, || index.clone(), handle, rect);
+        }
+
+        let expansion = style.spacing.item_spacing / 2.0;
+        let dir = egui::Direction::TopDown;
+        self.reorder_drop_zone_impl(rect, &painter, expansion, dir, index, None, false);
+    }
+}
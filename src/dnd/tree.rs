@@ -0,0 +1,182 @@
+//! Reordering and nesting nodes in a tree. See [`Dnd::tree_node()`].
+
+use std::hash::Hash;
+
+use super::{Dnd, DndMove, ReorderTarget};
+
+impl<N: Clone + PartialEq + Hash> Dnd<TreeNode<N>, TreeTarget<N>> {
+    /// Adds a new draggable node in a tree, using `node.node` for the ID. See
+    /// [`Dnd::draggable()`].
+    ///
+    /// Nodes must be added in depth-first order, with each node before its
+    /// children, and each node's children should be indented using
+    /// [`egui::Ui::indent()`]. Dropping the payload on the upper half of a node
+    /// inserts it before that node. Dropping it on the lower half inserts it
+    /// after that node if the payload is level with the node, or makes it the
+    /// first child of that node if the payload is moved to the right by at
+    /// least half of [`egui::Spacing::indent`]. The payload can't be dropped
+    /// into itself or its descendants.
+    pub fn tree_node<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        node: TreeNode<N>,
+        add_contents: impl FnOnce(&mut egui::Ui, egui::Id) -> (egui::Response, R),
+    ) -> egui::InnerResponse<R> {
+        let item_id = self.id.with(&node.node);
+
+        let parent_id = node.parent.as_ref().map(|parent| self.id.with(parent));
+        let is_in_payload = self.payload_id() == Some(item_id)
+            || parent_id.is_some_and(|id| self.tree_payload_nodes.contains(&id));
+        if is_in_payload {
+            self.tree_payload_nodes.push(item_id);
+        }
+
+        let (r, _handle) = self.draggable_impl(
+            ui,
+            item_id,
+            node.clone(),
+            |ui| add_contents(ui, item_id),
+            None,
+        );
+        if !is_in_payload {
+            self.tree_drop_zones(ui, &r.response, node);
+        }
+        r
+    }
+
+    /// Adds drop zones before, after, and inside a node added using
+    /// [`Dnd::tree_node()`].
+    fn tree_drop_zones(&mut self, ui: &egui::Ui, r: &egui::Response, node: TreeNode<N>) {
+        let Some(state) = &self.current_drag else {
+            return;
+        };
+
+        let expansion = ui.spacing().item_spacing / 2.0;
+        let rect = r.rect.expand2(expansion);
+        let clip_rect = ui.clip_rect().expand2(expansion);
+        let indent = ui.spacing().indent;
+
+        // The payload is nested if its left edge is closer to where a child
+        // would be than to where a sibling would be.
+        let split_x = rect.left() + indent / 2.0 + state.payload_size.x / 2.0;
+        let (upper, lower) = rect.split_top_bottom_at_y(rect.center().y);
+        let (lower_sibling, lower_child) = lower.split_left_right_at_x(split_x.min(lower.right()));
+
+        let TreeNode {
+            node,
+            parent,
+            index,
+        } = node;
+        let zones = [
+            (upper, rect.left(), rect.top(), parent.clone(), index),
+            (lower_sibling, rect.left(), rect.bottom(), parent, index + 1),
+            (
+                lower_child,
+                rect.left() + indent,
+                rect.bottom(),
+                Some(node),
+                0,
+            ),
+        ];
+        for (area, left, y, parent, index) in zones {
+            self.reorder_drop_zones.push(ReorderTarget {
+                line_endpoints: [egui::pos2(left, y), egui::pos2(rect.right(), y)],
+                layer_id: ui.layer_id(),
+                clip_rect,
+                direction: egui::Direction::TopDown,
+                target: TreeTarget { parent, index },
+                gap: None,
+                area: Some(area),
+                section: self.section,
+                pin_segment: self.pin_segment,
+                is_noop: false,
+            });
+        }
+    }
+}
+
+/// Node in a tree, used as the payload for [`TreeDnd`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeNode<N> {
+    /// Node ID.
+    pub node: N,
+    /// Parent of the node, or `None` if it is at the root of the tree.
+    pub parent: Option<N>,
+    /// Index of the node among the children of its parent.
+    pub index: usize,
+}
+
+/// Location in a tree, used as the target for [`TreeDnd`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeTarget<N> {
+    /// Parent node, or `None` for the root of the tree.
+    pub parent: Option<N>,
+    /// Index among the children of the parent, before the payload is removed.
+    pub index: usize,
+}
+
+/// Drag-and-drop for reordering and nesting nodes in a tree. See
+/// [`Dnd::tree_node()`].
+pub type TreeDnd<N = usize> = Dnd<TreeNode<N>, TreeTarget<N>>;
+
+/// Drag-and-drop move for reordering and nesting nodes in a tree.
+pub type TreeDndMove<N = usize> = DndMove<TreeNode<N>, TreeTarget<N>>;
+impl<N: PartialEq> TreeDndMove<N> {
+    /// Returns the move as a node to remove from its old parent and insert into
+    /// its new parent.
+    pub fn tree_move(self) -> TreeMove<N> {
+        let TreeNode {
+            node,
+            parent: old_parent,
+            index: old_index,
+        } = self.payload;
+        let TreeTarget {
+            parent: new_parent,
+            mut index,
+        } = self.target;
+        if new_parent == old_parent && index > old_index {
+            index -= 1;
+        }
+        TreeMove {
+            node,
+            old_parent,
+            old_index,
+            new_parent,
+            index,
+        }
+    }
+}
+
+/// Move of a node in a tree. See [`TreeDndMove::tree_move()`].
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TreeMove<N> {
+    /// Node being moved.
+    pub node: N,
+    /// Previous parent of the node, or `None` if it was at the root.
+    pub old_parent: Option<N>,
+    /// Previous index of the node among the children of `old_parent`.
+    pub old_index: usize,
+    /// New parent of the node, or `None` if it is now at the root.
+    pub new_parent: Option<N>,
+    /// Index of the node among the children of `new_parent` after it is
+    /// removed from `old_parent`.
+    pub index: usize,
+}
+impl<N> TreeMove<N> {
+    /// Returns the move that undoes this one.
+    ///
+    /// This is useful for recording moves in an undo history.
+    #[must_use]
+    pub fn inverse(self) -> Self {
+        Self {
+            node: self.node,
+            old_parent: self.new_parent,
+            old_index: self.index,
+            new_parent: self.old_parent,
+            index: self.old_index,
+        }
+    }
+}
@@ -0,0 +1,536 @@
+//! Lightweight docking built on [`crate::dnd`]. **Requires `dock` feature.**
+//!
+//! Panels are arranged in a tree of splits ([`DockLayout`]). Drag a panel by
+//! its title onto one of the docking hints shown over another panel to split
+//! that panel, or onto the center hint to swap the two panels. Drag the gap
+//! between two panels to resize them.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::dock::{DockArea, DockLayout, DockNode};
+//!
+//! // Store this in your app state.
+//! let mut layout = DockLayout::new(DockNode::left_right(
+//!     DockNode::Panel("Files"),
+//!     DockNode::top_bottom(DockNode::Panel("Editor"), DockNode::Panel("Terminal")),
+//! ));
+//!
+//! DockArea::new("dock").show(
+//!     ui,
+//!     &mut layout,
+//!     |panel| panel.to_string(),
+//!     |ui, panel| {
+//!         ui.label(format!("Contents of {panel}"));
+//!     },
+//! );
+//! # });
+//! ```
+
+use std::hash::Hash;
+
+use crate::dnd::{Dnd, DndResponse, DndStyle};
+
+/// Size of each docking hint square.
+const HINT_SIZE: f32 = 28.0;
+/// Gap between docking hint squares.
+const HINT_GAP: f32 = 4.0;
+/// Minimum fraction of a split given to either side when resizing.
+const MIN_FRACTION: f32 = 0.1;
+
+/// Where to dock a panel relative to another panel.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[allow(missing_docs)]
+pub enum DockPosition {
+    Left,
+    Right,
+    Top,
+    Bottom,
+    /// Swap the two panels.
+    Center,
+}
+impl DockPosition {
+    /// All docking positions.
+    pub const ALL: [Self; 5] = [
+        Self::Left,
+        Self::Right,
+        Self::Top,
+        Self::Bottom,
+        Self::Center,
+    ];
+
+    /// Returns the part of `rect` that a panel docked at this position would
+    /// occupy.
+    pub fn preview_rect(self, rect: egui::Rect) -> egui::Rect {
+        let c = rect.center();
+        match self {
+            Self::Left => rect.with_max_x(c.x),
+            Self::Right => rect.with_min_x(c.x),
+            Self::Top => rect.with_max_y(c.y),
+            Self::Bottom => rect.with_min_y(c.y),
+            Self::Center => rect,
+        }
+    }
+
+    /// Returns the offset of this position's hint from the center of the
+    /// compass of docking hints.
+    fn hint_offset(self) -> egui::Vec2 {
+        let d = HINT_SIZE + HINT_GAP;
+        match self {
+            Self::Left => egui::vec2(-d, 0.0),
+            Self::Right => egui::vec2(d, 0.0),
+            Self::Top => egui::vec2(0.0, -d),
+            Self::Bottom => egui::vec2(0.0, d),
+            Self::Center => egui::Vec2::ZERO,
+        }
+    }
+}
+
+/// Direction in which a [`DockNode::Split`] arranges its children.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SplitDirection {
+    /// Children are side by side.
+    Horizontal,
+    /// Children are stacked on top of each other.
+    Vertical,
+}
+
+/// Node in a [`DockLayout`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DockNode<T> {
+    /// Single panel.
+    Panel(T),
+    /// Two nodes sharing space.
+    Split {
+        /// Direction in which the children are arranged.
+        direction: SplitDirection,
+        /// Fraction of the space given to the first child, from 0 to 1.
+        fraction: f32,
+        /// Left and right children, or top and bottom children.
+        children: Box<[DockNode<T>; 2]>,
+    },
+}
+impl<T> DockNode<T> {
+    /// Constructs an even horizontal split.
+    pub fn left_right(left: Self, right: Self) -> Self {
+        Self::Split {
+            direction: SplitDirection::Horizontal,
+            fraction: 0.5,
+            children: Box::new([left, right]),
+        }
+    }
+
+    /// Constructs an even vertical split.
+    pub fn top_bottom(top: Self, bottom: Self) -> Self {
+        Self::Split {
+            direction: SplitDirection::Vertical,
+            fraction: 0.5,
+            children: Box::new([top, bottom]),
+        }
+    }
+
+    /// Sets the fraction of the space given to the first child of a split. Does
+    /// nothing for a single panel.
+    #[must_use]
+    pub fn with_fraction(mut self, new_fraction: f32) -> Self {
+        if let Self::Split { fraction, .. } = &mut self {
+            *fraction = new_fraction.clamp(0.0, 1.0);
+        }
+        self
+    }
+
+    fn collect_panels<'a>(&'a self, out: &mut Vec<&'a T>) {
+        match self {
+            Self::Panel(panel) => out.push(panel),
+            Self::Split { children, .. } => {
+                children.iter().for_each(|child| child.collect_panels(out));
+            }
+        }
+    }
+
+    fn collect_panels_mut<'a>(&'a mut self, out: &mut Vec<&'a mut T>) {
+        match self {
+            Self::Panel(panel) => out.push(panel),
+            Self::Split { children, .. } => {
+                children
+                    .iter_mut()
+                    .for_each(|child| child.collect_panels_mut(out));
+            }
+        }
+    }
+}
+impl<T: PartialEq> DockNode<T> {
+    /// Returns the node with `panel` removed, collapsing its parent split.
+    fn without(self, panel: &T, removed: &mut Option<T>) -> Option<Self> {
+        match self {
+            Self::Panel(p) if removed.is_none() && p == *panel => {
+                *removed = Some(p);
+                None
+            }
+            Self::Panel(p) => Some(Self::Panel(p)),
+            Self::Split {
+                direction,
+                fraction,
+                children,
+            } => {
+                let [a, b] = *children;
+                match (a.without(panel, removed), b.without(panel, removed)) {
+                    (Some(a), Some(b)) => Some(Self::Split {
+                        direction,
+                        fraction,
+                        children: Box::new([a, b]),
+                    }),
+                    (Some(node), None) | (None, Some(node)) => Some(node),
+                    (None, None) => None,
+                }
+            }
+        }
+    }
+
+    /// Returns the node with `new_panel` split off from `target`.
+    fn with_docked(self, new_panel: &mut Option<T>, target: &T, position: DockPosition) -> Self {
+        match self {
+            Self::Panel(p) if p == *target => {
+                let Some(new) = new_panel.take() else {
+                    return Self::Panel(p);
+                };
+                let (old, new) = (Self::Panel(p), Self::Panel(new));
+                match position {
+                    DockPosition::Left => Self::left_right(new, old),
+                    DockPosition::Right => Self::left_right(old, new),
+                    DockPosition::Top => Self::top_bottom(new, old),
+                    DockPosition::Bottom | DockPosition::Center => Self::top_bottom(old, new),
+                }
+            }
+            Self::Panel(p) => Self::Panel(p),
+            Self::Split {
+                direction,
+                fraction,
+                children,
+            } => {
+                let [a, b] = *children;
+                let a = a.with_docked(new_panel, target, position);
+                let b = b.with_docked(new_panel, target, position);
+                Self::Split {
+                    direction,
+                    fraction,
+                    children: Box::new([a, b]),
+                }
+            }
+        }
+    }
+}
+
+/// Tree of panels for [`DockArea`].
+///
+/// `T` identifies each panel and should be unique within the layout.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DockLayout<T> {
+    /// Root node, or `None` if there are no panels.
+    pub root: Option<DockNode<T>>,
+}
+impl<T> Default for DockLayout<T> {
+    fn default() -> Self {
+        Self { root: None }
+    }
+}
+impl<T> DockLayout<T> {
+    /// Constructs a layout from a root node.
+    pub fn new(root: DockNode<T>) -> Self {
+        Self { root: Some(root) }
+    }
+
+    /// Returns all panels in the layout, from left to right and top to bottom.
+    pub fn panels(&self) -> Vec<&T> {
+        let mut out = vec![];
+        if let Some(root) = &self.root {
+            root.collect_panels(&mut out);
+        }
+        out
+    }
+}
+impl<T: PartialEq> DockLayout<T> {
+    /// Returns whether `panel` is in the layout.
+    pub fn contains(&self, panel: &T) -> bool {
+        self.panels().contains(&panel)
+    }
+
+    /// Removes `panel` from the layout and returns it, giving its space to its
+    /// sibling.
+    pub fn remove_panel(&mut self, panel: &T) -> Option<T> {
+        let mut removed = None;
+        self.root = self.root.take()?.without(panel, &mut removed);
+        removed
+    }
+
+    /// Moves `panel` to `position` relative to `target`. Returns whether the
+    /// layout changed.
+    ///
+    /// [`DockPosition::Center`] swaps the two panels.
+    pub fn move_panel(&mut self, panel: &T, target: &T, position: DockPosition) -> bool {
+        if panel == target || !self.contains(panel) || !self.contains(target) {
+            return false;
+        }
+
+        if position == DockPosition::Center {
+            let mut panels = vec![];
+            if let Some(root) = &mut self.root {
+                root.collect_panels_mut(&mut panels);
+            }
+            let i = panels.iter().position(|p| **p == *panel);
+            let j = panels.iter().position(|p| **p == *target);
+            if let Some((i, j)) = i.zip(j)
+                && let Ok([a, b]) = panels.get_disjoint_mut([i, j])
+            {
+                std::mem::swap(*a, *b);
+            }
+            return true;
+        }
+
+        let mut removed = self.remove_panel(panel);
+        if let Some(root) = self.root.take() {
+            self.root = Some(root.with_docked(&mut removed, target, position));
+        }
+        true
+    }
+}
+
+/// Area containing docked panels.
+///
+/// Each panel has a title bar that can be dragged to rearrange the
+/// [`DockLayout`].
+#[derive(Debug, Clone)]
+pub struct DockArea {
+    id_salt: egui::Id,
+    style: Option<DndStyle>,
+}
+impl DockArea {
+    /// Constructs a dock area.
+    pub fn new(id_salt: impl Hash) -> Self {
+        Self {
+            id_salt: egui::Id::new(id_salt),
+            style: None,
+        }
+    }
+
+    /// Overrides the drag-and-drop style. By default,
+    /// [`crate::dnd::default_style()`] is used.
+    #[must_use]
+    pub fn with_style(mut self, style: DndStyle) -> Self {
+        self.style = Some(style);
+        self
+    }
+
+    /// Displays the dock area, filling the available space. Returns whether the
+    /// layout changed.
+    ///
+    /// `title` returns the title of a panel and `add_contents` displays its
+    /// contents.
+    pub fn show<T: Clone + PartialEq + Hash>(
+        self,
+        ui: &mut egui::Ui,
+        layout: &mut DockLayout<T>,
+        title: impl Fn(&T) -> String,
+        mut add_contents: impl FnMut(&mut egui::Ui, &T),
+    ) -> bool {
+        profile_scope!("hcegui::dock::DockArea::show");
+
+        let id = ui.make_persistent_id(self.id_salt);
+        let rect = ui.available_rect_before_wrap();
+        ui.allocate_rect(rect, egui::Sense::hover());
+
+        let mut dnd = Dnd::new(ui.ctx(), id);
+        if let Some(style) = self.style {
+            dnd.style = style;
+        }
+        let mut panel_rects = vec![];
+        if let Some(root) = &mut layout.root {
+            let mut ctx = NodeContext {
+                id,
+                dnd: &mut dnd,
+                title: &title,
+                add_contents: &mut add_contents,
+                panel_rects: &mut panel_rects,
+            };
+            show_node(ui, &mut ctx, root, rect, id);
+        }
+
+        if dnd.is_dragging() && !ui.is_sizing_pass() {
+            let layer_id = egui::LayerId::new(egui::Order::Foreground, id);
+            ui.scope_builder(egui::UiBuilder::new().layer_id(layer_id), |ui| {
+                for (panel, panel_id, panel_rect) in &panel_rects {
+                    if dnd.payload_id() != Some(*panel_id) {
+                        show_hints(ui, &mut dnd, panel, *panel_id, *panel_rect);
+                    }
+                }
+            });
+        }
+
+        match dnd.finish(ui) {
+            DndResponse::Inactive
+            | DndResponse::DroppedElsewhere(_)
+            | DndResponse::Cancelled(_)
+            | DndResponse::DraggedOut(_)
+            | DndResponse::Deleted(_) => false,
+            DndResponse::MidDrag(m) => {
+                if let Some((target, position)) = m.target
+                    && let Some((_, _, target_rect)) =
+                        panel_rects.iter().find(|(p, ..)| *p == target)
+                {
+                    let layer_id = egui::LayerId::new(egui::Order::Foreground, id.with("preview"));
+                    let color = ui.visuals().selection.bg_fill.gamma_multiply(0.3);
+                    ui.ctx().layer_painter(layer_id).rect_filled(
+                        position.preview_rect(*target_rect),
+                        ui.visuals().window_corner_radius,
+                        color,
+                    );
+                }
+                false
+            }
+            DndResponse::DoneDragging(m) => {
+                let (target, position) = m.target;
+                layout.move_panel(&m.payload, &target, position)
+            }
+        }
+    }
+}
+
+/// State shared while showing each node of a [`DockArea`].
+struct NodeContext<'a, T> {
+    id: egui::Id,
+    dnd: &'a mut Dnd<T, (T, DockPosition)>,
+    title: &'a dyn Fn(&T) -> String,
+    add_contents: &'a mut dyn FnMut(&mut egui::Ui, &T),
+    /// Each panel, along with its drag ID and rectangle.
+    panel_rects: &'a mut Vec<(T, egui::Id, egui::Rect)>,
+}
+
+fn show_node<T: Clone + Hash>(
+    ui: &mut egui::Ui,
+    ctx: &mut NodeContext<'_, T>,
+    node: &mut DockNode<T>,
+    rect: egui::Rect,
+    node_id: egui::Id,
+) {
+    match node {
+        DockNode::Panel(panel) => show_panel(ui, ctx, panel, rect),
+        DockNode::Split {
+            direction,
+            fraction,
+            children,
+        } => {
+            let gap = ui.spacing().item_spacing;
+            let [a, b] = &mut **children;
+            let (rect_a, separator, rect_b) = match direction {
+                SplitDirection::Horizontal => {
+                    let x = egui::lerp(rect.x_range(), *fraction);
+                    (
+                        rect.with_max_x(x - gap.x / 2.0),
+                        egui::Rect::from_x_y_ranges(
+                            x - gap.x / 2.0..=x + gap.x / 2.0,
+                            rect.y_range(),
+                        ),
+                        rect.with_min_x(x + gap.x / 2.0),
+                    )
+                }
+                SplitDirection::Vertical => {
+                    let y = egui::lerp(rect.y_range(), *fraction);
+                    (
+                        rect.with_max_y(y - gap.y / 2.0),
+                        egui::Rect::from_x_y_ranges(
+                            rect.x_range(),
+                            y - gap.y / 2.0..=y + gap.y / 2.0,
+                        ),
+                        rect.with_min_y(y + gap.y / 2.0),
+                    )
+                }
+            };
+
+            show_node(ui, ctx, a, rect_a, node_id.with(0));
+            show_node(ui, ctx, b, rect_b, node_id.with(1));
+
+            // Resize by dragging the gap between children.
+            let r = ui.interact(separator, node_id.with("separator"), egui::Sense::drag());
+            let cursor = match direction {
+                SplitDirection::Horizontal => egui::CursorIcon::ResizeHorizontal,
+                SplitDirection::Vertical => egui::CursorIcon::ResizeVertical,
+            };
+            if r.hovered() || r.dragged() {
+                ui.ctx().set_cursor_icon(cursor);
+            }
+            if r.dragged()
+                && let Some(pos) = r.interact_pointer_pos()
+            {
+                let t = match direction {
+                    SplitDirection::Horizontal => egui::remap(pos.x, rect.x_range(), 0.0..=1.0),
+                    SplitDirection::Vertical => egui::remap(pos.y, rect.y_range(), 0.0..=1.0),
+                };
+                *fraction = t.clamp(MIN_FRACTION, 1.0 - MIN_FRACTION);
+            }
+        }
+    }
+}
+
+fn show_panel<T: Clone + Hash>(
+    ui: &mut egui::Ui,
+    ctx: &mut NodeContext<'_, T>,
+    panel: &T,
+    rect: egui::Rect,
+) {
+    let drag_id = ctx.id.with(panel);
+    ctx.panel_rects.push((panel.clone(), drag_id, rect));
+
+    let mut child = ui.new_child(
+        egui::UiBuilder::new()
+            .id_salt(drag_id)
+            .max_rect(rect)
+            .layout(egui::Layout::top_down(egui::Align::Min)),
+    );
+    child.set_clip_rect(rect.intersect(ui.clip_rect()));
+    egui::Frame::group(child.style()).show(&mut child, |ui| {
+        ui.set_min_size(ui.available_size());
+        ui.horizontal(|ui| {
+            ctx.dnd.draggable_with_id(ui, drag_id, panel.clone(), |ui| {
+                let text = egui::RichText::new((ctx.title)(panel)).strong();
+                let r = ui.add(
+                    egui::Label::new(text)
+                        .selectable(false)
+                        .sense(egui::Sense::drag()),
+                );
+                (r, ())
+            });
+        });
+        ui.separator();
+        (ctx.add_contents)(ui, panel);
+    });
+}
+
+/// Shows the compass of docking hints over a panel.
+fn show_hints<T: Clone>(
+    ui: &mut egui::Ui,
+    dnd: &mut Dnd<T, (T, DockPosition)>,
+    panel: &T,
+    panel_id: egui::Id,
+    rect: egui::Rect,
+) {
+    let visuals = ui.visuals();
+    let bg = visuals.window_fill.gamma_multiply(0.8);
+    let fill = visuals.selection.bg_fill.gamma_multiply(0.6);
+    let rounding = dnd.style.drop_zone_rounding;
+
+    for position in DockPosition::ALL {
+        let center = rect.center() + position.hint_offset();
+        let hint_rect = egui::Rect::from_center_size(center, egui::Vec2::splat(HINT_SIZE));
+        let hint_id = panel_id.with(("dock_hint", position));
+        let r = ui.interact(hint_rect, hint_id, egui::Sense::hover());
+        ui.painter().rect_filled(hint_rect, rounding, bg);
+        ui.painter()
+            .rect_filled(position.preview_rect(hint_rect.shrink(4.0)), 1.0, fill);
+        dnd.drop_zone(ui, &r, (panel.clone(), position));
+    }
+}
@@ -0,0 +1,220 @@
+//! Debug window showing hcegui's internal state, similar to egui's own
+//! [`egui::Context::memory_ui()`]. **Requires `inspector` feature.**
+//!
+//! With this feature enabled, hcegui widgets record a small amount of
+//! information about themselves each pass, which is shown by
+//! [`inspector_ui()`]. Information is shown for the most recent complete pass,
+//! so it lags one frame behind. The inspector does not request repaints of its
+//! own.
+//!
+//! Each section has buttons to clear stored state, which is useful if
+//! something gets stuck during development.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! // Store this in your app state.
+//! let mut show_inspector = true;
+//!
+//! hcegui::inspector::inspector_window(ui.ctx(), &mut show_inspector);
+//! # });
+//! ```
+
+fn stats_id() -> egui::Id {
+    egui::Id::new("hcegui::inspector::stats")
+}
+
+/// Information recorded during a single pass.
+#[derive(Debug, Default, Clone)]
+struct PassStats {
+    pass_nr: u64,
+    #[cfg(feature = "dnd")]
+    dnds: Vec<DndStats>,
+    #[cfg(feature = "ansi")]
+    ansi: AnsiStats,
+    wrap_groups: Vec<(egui::Id, f32)>,
+    baseline_rows: Vec<(egui::Id, Vec<f32>)>,
+}
+
+/// Information recorded for the current and previous pass.
+#[derive(Debug, Default, Clone)]
+struct Stats {
+    current: PassStats,
+    previous: PassStats,
+}
+
+/// Information about a [`crate::dnd::Dnd`] environment.
+#[cfg(feature = "dnd")]
+#[derive(Debug, Clone)]
+pub(crate) struct DndStats {
+    pub id: egui::Id,
+    /// ID of the payload being dragged and its drop position.
+    pub drag: Option<(egui::Id, egui::Pos2)>,
+    pub drop_zones: usize,
+    pub reorder_drop_zones: usize,
+    pub has_target: bool,
+}
+
+/// Information about [`crate::ansi::AnsiLabel`]s.
+#[cfg(feature = "ansi")]
+#[derive(Debug, Default, Clone)]
+struct AnsiStats {
+    labels: usize,
+    bytes: usize,
+    sections: usize,
+    errors: usize,
+}
+
+/// Records information about the current pass.
+fn record(ctx: &egui::Context, f: impl FnOnce(&mut PassStats)) {
+    let pass_nr = ctx.cumulative_pass_nr();
+    ctx.data_mut(|data| {
+        let stats = data.get_temp_mut_or_default::<Stats>(stats_id());
+        if stats.current.pass_nr != pass_nr {
+            stats.previous = std::mem::take(&mut stats.current);
+            stats.current.pass_nr = pass_nr;
+        }
+        f(&mut stats.current);
+    });
+}
+
+#[cfg(feature = "dnd")]
+pub(crate) fn record_dnd(ctx: &egui::Context, dnd: DndStats) {
+    record(ctx, |stats| stats.dnds.push(dnd));
+}
+
+#[cfg(feature = "ansi")]
+pub(crate) fn record_ansi_label(ctx: &egui::Context, bytes: usize, sections: usize, errors: usize) {
+    record(ctx, |stats| {
+        stats.ansi.labels += 1;
+        stats.ansi.bytes += bytes;
+        stats.ansi.sections += sections;
+        stats.ansi.errors += errors;
+    });
+}
+
+pub(crate) fn record_wrap_group(ctx: &egui::Context, id: egui::Id, width: f32) {
+    record(ctx, |stats| stats.wrap_groups.push((id, width)));
+}
+
+pub(crate) fn record_baseline_row(ctx: &egui::Context, id: egui::Id, baselines: &[f32]) {
+    record(ctx, |stats| {
+        stats.baseline_rows.push((id, baselines.to_vec()));
+    });
+}
+
+/// Returns the information for the most recent complete pass.
+fn latest(ctx: &egui::Context) -> PassStats {
+    let pass_nr = ctx.cumulative_pass_nr();
+    ctx.data(|data| data.get_temp::<Stats>(stats_id()))
+        .map(|stats| {
+            if stats.current.pass_nr == pass_nr {
+                stats.previous
+            } else {
+                stats.current
+            }
+        })
+        .unwrap_or_default()
+}
+
+/// Shows the inspector in a window.
+pub fn inspector_window(ctx: &egui::Context, open: &mut bool) {
+    egui::Window::new("hcegui inspector")
+        .open(open)
+        .vscroll(true)
+        .default_width(320.0)
+        .show(ctx, inspector_ui);
+}
+
+/// Shows the inspector.
+pub fn inspector_ui(ui: &mut egui::Ui) {
+    let stats = latest(ui.ctx());
+
+    #[cfg(feature = "dnd")]
+    egui::CollapsingHeader::new(format!("Drag and drop ({})", stats.dnds.len()))
+        .default_open(true)
+        .show(ui, |ui| dnd_ui(ui, &stats.dnds));
+
+    #[cfg(feature = "ansi")]
+    egui::CollapsingHeader::new("ANSI labels")
+        .default_open(true)
+        .show(ui, |ui| {
+            let AnsiStats {
+                labels,
+                bytes,
+                sections,
+                errors,
+            } = stats.ansi;
+            crate::util::kv_table(
+                ui,
+                [
+                    ("Labels", labels.to_string()),
+                    ("Text bytes", bytes.to_string()),
+                    ("Text sections", sections.to_string()),
+                    ("Unsupported codes", errors.to_string()),
+                ],
+            );
+        });
+
+    let header = format!("Wrap groups ({})", stats.wrap_groups.len());
+    egui::CollapsingHeader::new(header).show(ui, |ui| {
+        if ui.button("Clear measurements").clicked() {
+            ui.data_mut(|data| {
+                for (id, _) in &stats.wrap_groups {
+                    data.remove_temp::<f32>(*id);
+                }
+            });
+        }
+        let rows = (stats.wrap_groups.iter())
+            .map(|(id, width)| (id.short_debug_format(), format!("{width:.1}")));
+        crate::util::kv_table(ui, rows);
+    });
+
+    let header = format!("Baseline rows ({})", stats.baseline_rows.len());
+    egui::CollapsingHeader::new(header).show(ui, |ui| {
+        if ui.button("Clear measurements").clicked() {
+            crate::util::clear_baseline_state(ui.ctx());
+        }
+        let rows = stats.baseline_rows.iter().map(|(id, baselines)| {
+            let baselines: Vec<String> = baselines.iter().map(|b| format!("{b:.1}")).collect();
+            (id.short_debug_format(), baselines.join(", "))
+        });
+        crate::util::kv_table(ui, rows);
+    });
+}
+
+#[cfg(feature = "dnd")]
+fn dnd_ui(ui: &mut egui::Ui, dnds: &[DndStats]) {
+    if ui.button("Cancel all drags").clicked() {
+        for dnd in dnds {
+            crate::dnd::clear_state(ui.ctx(), dnd.id);
+        }
+    }
+    let mut debug_paint = crate::dnd::debug_paint(ui.ctx());
+    if ui.checkbox(&mut debug_paint, "Paint drop zones").changed() {
+        crate::dnd::set_debug_paint(ui.ctx(), debug_paint);
+    }
+    for dnd in dnds {
+        ui.separator();
+        let drag = match dnd.drag {
+            Some((payload_id, drop_pos)) => format!(
+                "{} at ({:.0}, {:.0})",
+                payload_id.short_debug_format(),
+                drop_pos.x,
+                drop_pos.y,
+            ),
+            None => "none".to_owned(),
+        };
+        crate::util::kv_table(
+            ui,
+            [
+                ("ID", dnd.id.short_debug_format()),
+                ("Payload", drag),
+                ("Drop zones", dnd.drop_zones.to_string()),
+                ("Reorder drop zones", dnd.reorder_drop_zones.to_string()),
+                ("Hovering target", dnd.has_target.to_string()),
+            ],
+        );
+    }
+}
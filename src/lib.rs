@@ -1,9 +1,56 @@
 //! HactarCE's egui utilities.
 //!
 //! Read more at <https://github.com/HactarCE/hcegui>.
+//!
+//! With the `profiling` feature, expensive operations are wrapped in scopes
+//! using the [`profiling`](https://docs.rs/profiling) crate. Enable a backend
+//! in your own `Cargo.toml` (such as `profiling/profile-with-puffin`) to see
+//! them.
+//!
+//! hcegui uses the latest version of egui by default. To use an older version,
+//! disable default features and enable one of the `egui_0_32` or `egui_0_33`
+//! features instead. A few items, such as the `persistence` and `extras`
+//! features, require the latest version.
+
+#[cfg(not(any(feature = "egui_0_32", feature = "egui_0_33", feature = "egui_0_34")))]
+compile_error!("one of the `egui_0_32`, `egui_0_33`, or `egui_0_34` features must be enabled");
+
+// If multiple egui versions are enabled, the newest one is used.
+#[cfg(all(
+    feature = "egui_0_32",
+    not(any(feature = "egui_0_33", feature = "egui_0_34")),
+))]
+extern crate egui_0_32 as egui;
+#[cfg(all(feature = "egui_0_33", not(feature = "egui_0_34")))]
+extern crate egui_0_33 as egui;
+
+/// Opens a profiling scope that lasts until the end of the current block.
+/// Does nothing unless the `profiling` feature is enabled.
+macro_rules! profile_scope {
+    ($name:expr) => {
+        #[cfg(feature = "profiling")]
+        profiling::scope!($name);
+    };
+}
 
+pub mod a11y;
+pub mod anim;
 #[cfg(feature = "ansi")]
 pub mod ansi;
+pub mod clipboard;
+#[cfg(feature = "command_palette")]
+pub mod command_palette;
+mod compat;
 #[cfg(feature = "dnd")]
 pub mod dnd;
+#[cfg(feature = "dock")]
+pub mod dock;
+#[cfg(feature = "inspector")]
+pub mod inspector;
+#[cfg(feature = "log_console")]
+pub mod log_console;
+#[cfg(feature = "style_editor")]
+pub mod style_editor;
+#[cfg(feature = "theme")]
+pub mod theme;
 pub mod util;
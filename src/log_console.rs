@@ -0,0 +1,607 @@
+//! In-app log console with filtering and search. **Requires `log_console`
+//! feature.**
+//!
+//! Messages are stored in a [`LogBuffer`], which can be shared between threads,
+//! and displayed using a [`LogConsole`]. Messages may contain ANSI escape codes,
+//! which are rendered using [`crate::ansi::AnsiLabel`].
+//!
+//! With the `log` feature, [`LogBuffer::install_logger()`] captures messages
+//! from the [`log`](https://docs.rs/log) crate. With the `tracing` feature,
+//! [`LogBuffer::tracing_layer()`] returns a
+//! [`tracing_subscriber::Layer`](https://docs.rs/tracing-subscriber) that
+//! captures events from [`tracing`](https://docs.rs/tracing).
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::log_console::{LogBuffer, LogConsole, LogLevel};
+//!
+//! let buffer = LogBuffer::new(1000);
+//! buffer.push(LogLevel::Info, "app", "Hello, world!");
+//! buffer.push(LogLevel::Error, "app", "\x1b[31mSomething broke\x1b[0m");
+//!
+//! // Store this in your app state.
+//! let mut console = LogConsole::new(buffer);
+//! console.show(ui);
+//! # });
+//! ```
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use smallvec::SmallVec;
+
+use crate::ansi::{AnsiLabel, strip_ansi};
+
+/// Severity of a log message.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[allow(missing_docs)]
+pub enum LogLevel {
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl LogLevel {
+    /// All log levels, from most to least severe.
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Error,
+        LogLevel::Warn,
+        LogLevel::Info,
+        LogLevel::Debug,
+        LogLevel::Trace,
+    ];
+
+    /// Returns the uppercase name of the level, such as `"WARN"`.
+    pub fn name(self) -> &'static str {
+        match self {
+            LogLevel::Error => "ERROR",
+            LogLevel::Warn => "WARN",
+            LogLevel::Info => "INFO",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Trace => "TRACE",
+        }
+    }
+
+    /// Returns the color used to display the level.
+    pub fn color(self, visuals: &egui::Visuals) -> egui::Color32 {
+        match self {
+            LogLevel::Error => visuals.error_fg_color,
+            LogLevel::Warn => visuals.warn_fg_color,
+            LogLevel::Info => visuals.text_color(),
+            LogLevel::Debug | LogLevel::Trace => visuals.weak_text_color(),
+        }
+    }
+}
+
+#[cfg(feature = "log")]
+impl From<log::Level> for LogLevel {
+    fn from(level: log::Level) -> Self {
+        match level {
+            log::Level::Error => LogLevel::Error,
+            log::Level::Warn => LogLevel::Warn,
+            log::Level::Info => LogLevel::Info,
+            log::Level::Debug => LogLevel::Debug,
+            log::Level::Trace => LogLevel::Trace,
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl From<tracing::Level> for LogLevel {
+    fn from(level: tracing::Level) -> Self {
+        match level {
+            tracing::Level::ERROR => LogLevel::Error,
+            tracing::Level::WARN => LogLevel::Warn,
+            tracing::Level::INFO => LogLevel::Info,
+            tracing::Level::DEBUG => LogLevel::Debug,
+            tracing::Level::TRACE => LogLevel::Trace,
+        }
+    }
+}
+
+/// Single message in a [`LogBuffer`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogEntry {
+    /// Severity of the message.
+    pub level: LogLevel,
+    /// Module or subsystem that emitted the message.
+    pub target: String,
+    /// Message text, which may contain ANSI escape codes.
+    pub message: String,
+}
+
+impl LogEntry {
+    /// Returns the entry as a single line of plain text, without ANSI escape
+    /// codes.
+    pub fn to_plain_text(&self) -> String {
+        let message = strip_ansi(&self.message);
+        format!("[{}] {}: {message}", self.level.name(), self.target)
+    }
+}
+
+/// Default capacity of a [`LogBuffer`].
+const DEFAULT_CAPACITY: usize = 10_000;
+
+#[derive(Debug)]
+struct LogBufferInner {
+    entries: VecDeque<BufferedEntry>,
+    /// Sequence number of the oldest entry. Each entry's sequence number is one
+    /// more than the previous entry's, so it identifies the entry even after
+    /// older entries are removed.
+    first_seq: u64,
+    capacity: usize,
+    /// Context to repaint when a message is added from another thread.
+    repaint_ctx: Option<(egui::Context, std::thread::ThreadId)>,
+}
+
+impl LogBufferInner {
+    /// Returns the entry with sequence number `seq`, if it is still in the
+    /// buffer.
+    fn get(&self, seq: u64) -> Option<&LogEntry> {
+        let i = usize::try_from(seq.checked_sub(self.first_seq)?).ok()?;
+        Some(&self.entries.get(i)?.entry)
+    }
+}
+
+/// Entry in a [`LogBuffer`], with lowercase text for searching.
+#[derive(Debug)]
+struct BufferedEntry {
+    entry: LogEntry,
+    /// Message without ANSI escape codes, in lowercase.
+    message_lowercase: String,
+    /// Target in lowercase.
+    target_lowercase: String,
+}
+
+impl BufferedEntry {
+    fn new(entry: LogEntry) -> Self {
+        Self {
+            message_lowercase: strip_ansi(&entry.message).to_lowercase(),
+            target_lowercase: entry.target.to_lowercase(),
+            entry,
+        }
+    }
+
+    /// Returns whether the entry contains `search`, which must be lowercase.
+    fn matches(&self, search: &str) -> bool {
+        search.is_empty()
+            || self.message_lowercase.contains(search)
+            || self.target_lowercase.contains(search)
+    }
+}
+
+/// Thread-safe ring buffer of log messages.
+///
+/// Cloning a `LogBuffer` returns another handle to the same buffer. When the
+/// buffer is full, the oldest messages are discarded. The default buffer holds
+/// up to 10,000 messages.
+#[derive(Debug, Clone)]
+pub struct LogBuffer(Arc<Mutex<LogBufferInner>>);
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(DEFAULT_CAPACITY)
+    }
+}
+
+impl LogBuffer {
+    /// Constructs an empty buffer that holds up to `capacity` messages.
+    pub fn new(capacity: usize) -> Self {
+        Self(Arc::new(Mutex::new(LogBufferInner {
+            entries: VecDeque::new(),
+            first_seq: 0,
+            capacity,
+            repaint_ctx: None,
+        })))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, LogBufferInner> {
+        // If another thread panicked while logging, the buffer is still fine.
+        self.0.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Adds a message to the buffer. See [`LogBuffer::push_entry()`].
+    pub fn push(&self, level: LogLevel, target: impl Into<String>, message: impl Into<String>) {
+        self.push_entry(LogEntry {
+            level,
+            target: target.into(),
+            message: message.into(),
+        });
+    }
+
+    /// Adds an entry to the buffer.
+    ///
+    /// If this is called from a thread other than the one displaying the
+    /// buffer, it requests a repaint of the UI. (It doesn't on the UI thread
+    /// because egui may be holding a lock on the context, such as when egui
+    /// itself emits a log message.)
+    pub fn push_entry(&self, entry: LogEntry) {
+        let entry = BufferedEntry::new(entry);
+        let repaint_ctx = {
+            let mut inner = self.lock();
+            while inner.entries.len() >= inner.capacity.max(1) {
+                inner.entries.pop_front();
+                inner.first_seq += 1;
+            }
+            inner.entries.push_back(entry);
+            inner
+                .repaint_ctx
+                .clone()
+                .filter(|(_, ui_thread)| *ui_thread != std::thread::current().id())
+        };
+        if let Some((ctx, _)) = repaint_ctx {
+            ctx.request_repaint();
+        }
+    }
+
+    /// Removes all messages.
+    pub fn clear(&self) {
+        let mut inner = self.lock();
+        inner.first_seq += inner.entries.len() as u64;
+        inner.entries.clear();
+    }
+
+    /// Returns the number of messages in the buffer.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// Returns whether the buffer is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns a copy of all messages in the buffer, from oldest to newest.
+    pub fn entries(&self) -> Vec<LogEntry> {
+        self.lock()
+            .entries
+            .iter()
+            .map(|e| e.entry.clone())
+            .collect()
+    }
+
+    /// Returns all messages as plain text, one per line. See
+    /// [`LogEntry::to_plain_text()`].
+    pub fn to_plain_text(&self) -> String {
+        let inner = self.lock();
+        let lines: Vec<String> = (inner.entries.iter())
+            .map(|e| e.entry.to_plain_text())
+            .collect();
+        lines.join("\n")
+    }
+
+    /// Sets the context to repaint when a message is added from another
+    /// thread. This must be called from the UI thread. [`LogConsole`] calls
+    /// this automatically.
+    pub fn set_repaint_ctx(&self, ctx: &egui::Context) {
+        self.lock().repaint_ctx = Some((ctx.clone(), std::thread::current().id()));
+    }
+
+    /// Installs a global logger for the [`log`](https://docs.rs/log) crate that
+    /// adds messages to this buffer. **Requires `log` feature.**
+    ///
+    /// Messages less severe than `max_level` are ignored.
+    #[cfg(feature = "log")]
+    pub fn install_logger(&self, max_level: log::LevelFilter) -> Result<(), log::SetLoggerError> {
+        log::set_boxed_logger(Box::new(LogCapture {
+            buffer: self.clone(),
+            max_level,
+        }))?;
+        log::set_max_level(max_level);
+        Ok(())
+    }
+
+    /// Returns a [`tracing_subscriber::Layer`] that adds events from the
+    /// [`tracing`](https://docs.rs/tracing) crate to this buffer. **Requires
+    /// `tracing` feature.**
+    ///
+    /// Events less severe than `max_level` are ignored.
+    ///
+    /// ```
+    /// use hcegui::log_console::LogBuffer;
+    /// use tracing_subscriber::layer::SubscriberExt;
+    ///
+    /// let buffer = LogBuffer::new(1000);
+    /// let subscriber = tracing_subscriber::registry()
+    ///     .with(buffer.tracing_layer(tracing::Level::INFO));
+    /// tracing::subscriber::set_global_default(subscriber).expect("error setting subscriber");
+    /// ```
+    #[cfg(feature = "tracing")]
+    pub fn tracing_layer(&self, max_level: tracing::Level) -> TracingCapture {
+        TracingCapture {
+            buffer: self.clone(),
+            max_level,
+        }
+    }
+}
+
+/// Logger that adds messages to a [`LogBuffer`]. **Requires `log` feature.**
+///
+/// Use [`LogBuffer::install_logger()`] to install it as the global logger, or
+/// combine it with other loggers.
+#[cfg(feature = "log")]
+#[derive(Debug, Clone)]
+pub struct LogCapture {
+    /// Buffer to add messages to.
+    pub buffer: LogBuffer,
+    /// Messages less severe than this are ignored.
+    pub max_level: log::LevelFilter,
+}
+
+#[cfg(feature = "log")]
+impl log::Log for LogCapture {
+    fn enabled(&self, metadata: &log::Metadata<'_>) -> bool {
+        metadata.level() <= self.max_level
+    }
+
+    fn log(&self, record: &log::Record<'_>) {
+        if self.enabled(record.metadata()) {
+            self.buffer.push(
+                record.level().into(),
+                record.target(),
+                record.args().to_string(),
+            );
+        }
+    }
+
+    fn flush(&self) {}
+}
+
+/// [`tracing_subscriber::Layer`] that adds events to a [`LogBuffer`].
+/// **Requires `tracing` feature.**
+///
+/// Use [`LogBuffer::tracing_layer()`] to construct one. Fields other than the
+/// message are appended to it as `key=value`. Spans are ignored.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone)]
+pub struct TracingCapture {
+    /// Buffer to add events to.
+    pub buffer: LogBuffer,
+    /// Events less severe than this are ignored.
+    pub max_level: tracing::Level,
+}
+
+#[cfg(feature = "tracing")]
+impl<S: tracing::Subscriber> tracing_subscriber::Layer<S> for TracingCapture {
+    fn enabled(
+        &self,
+        metadata: &tracing::Metadata<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) -> bool {
+        *metadata.level() <= self.max_level
+    }
+
+    fn on_event(
+        &self,
+        event: &tracing::Event<'_>,
+        _ctx: tracing_subscriber::layer::Context<'_, S>,
+    ) {
+        let metadata = event.metadata();
+        if *metadata.level() > self.max_level {
+            return;
+        }
+        let mut visitor = TracingVisitor::default();
+        event.record(&mut visitor);
+        let mut message = visitor.message;
+        for (key, value) in visitor.fields {
+            if !message.is_empty() {
+                message.push(' ');
+            }
+            message.push_str(&format!("{key}={value}"));
+        }
+        self.buffer
+            .push((*metadata.level()).into(), metadata.target(), message);
+    }
+}
+
+/// Collects the fields of a [`tracing::Event`].
+#[cfg(feature = "tracing")]
+#[derive(Debug, Default)]
+struct TracingVisitor {
+    message: String,
+    fields: Vec<(&'static str, String)>,
+}
+
+#[cfg(feature = "tracing")]
+impl tracing::field::Visit for TracingVisitor {
+    fn record_str(&mut self, field: &tracing::field::Field, value: &str) {
+        if field.name() == "message" {
+            self.message = value.to_owned();
+        } else {
+            self.fields.push((field.name(), value.to_owned()));
+        }
+    }
+
+    fn record_debug(&mut self, field: &tracing::field::Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        } else {
+            self.fields.push((field.name(), format!("{value:?}")));
+        }
+    }
+}
+
+/// Log console with level filters, search, and follow-tail.
+///
+/// Each message is displayed on a single line; hover over a message to see all
+/// of it. The console fills the available space, so it is typically placed in
+/// a [`egui::Panel`] or [`egui::Window`].
+#[derive(Debug)]
+pub struct LogConsole {
+    buffer: LogBuffer,
+    shown_levels: [bool; 5],
+    search: String,
+    follow_tail: bool,
+    #[cfg(not(target_arch = "wasm32"))]
+    save_path: Option<std::path::PathBuf>,
+}
+
+impl LogConsole {
+    /// Constructs a console displaying messages from `buffer`.
+    pub fn new(buffer: LogBuffer) -> Self {
+        Self {
+            buffer,
+            shown_levels: [true, true, true, true, false],
+            search: String::new(),
+            follow_tail: true,
+            #[cfg(not(target_arch = "wasm32"))]
+            save_path: None,
+        }
+    }
+
+    /// Sets the file that the "Save" button writes to. If this is not set,
+    /// there is no "Save" button.
+    #[cfg(not(target_arch = "wasm32"))]
+    #[must_use]
+    pub fn save_path(mut self, path: impl Into<std::path::PathBuf>) -> Self {
+        self.save_path = Some(path.into());
+        self
+    }
+
+    /// Returns the buffer displayed by the console.
+    pub fn buffer(&self) -> &LogBuffer {
+        &self.buffer
+    }
+
+    /// Returns whether messages of `level` are shown. By default, all levels
+    /// except [`LogLevel::Trace`] are shown.
+    pub fn is_level_shown(&self, level: LogLevel) -> bool {
+        self.shown_levels[level as usize]
+    }
+
+    /// Sets whether messages of `level` are shown.
+    pub fn set_level_shown(&mut self, level: LogLevel, shown: bool) {
+        self.shown_levels[level as usize] = shown;
+    }
+
+    /// Displays the console.
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        profile_scope!("hcegui::log_console::LogConsole");
+        self.buffer.set_repaint_ctx(ui.ctx());
+
+        // Reuse the list of visible sequence numbers from the last frame to
+        // avoid allocating a new one every frame.
+        let scratch_id = ui.id().with("hcegui::log_console::visible");
+        let mut visible = ui
+            .data_mut(|data| std::mem::take(data.get_temp_mut_or_default::<Vec<u64>>(scratch_id)));
+        visible.clear();
+
+        let mut level_counts = [0; LogLevel::ALL.len()];
+        {
+            let search = self.search.to_lowercase();
+            let inner = self.buffer.lock();
+            for (seq, e) in (inner.first_seq..).zip(&inner.entries) {
+                let level = e.entry.level;
+                level_counts[level as usize] += 1;
+                if self.is_level_shown(level) && e.matches(&search) {
+                    visible.push(seq);
+                }
+            }
+        }
+
+        ui.horizontal_wrapped(|ui| self.show_toolbar(ui, level_counts));
+        ui.separator();
+
+        let font_id = egui::FontId::monospace(14.0);
+        let row_height = crate::compat::row_height(ui.ctx(), &font_id);
+
+        let r = egui::ScrollArea::both()
+            .auto_shrink(false)
+            .stick_to_bottom(self.follow_tail)
+            .show_rows(ui, row_height, visible.len(), |ui, range| {
+                ui.style_mut().wrap_mode = Some(egui::TextWrapMode::Extend);
+                ui.spacing_mut().item_spacing.y = 0.0;
+                // Copy only the rows on screen so that the buffer isn't locked
+                // while drawing, in case something logs a message meanwhile.
+                // Messages removed since counting are skipped.
+                let rows: SmallVec<[LogEntry; 32]> = {
+                    let inner = self.buffer.lock();
+                    (visible[range].iter())
+                        .filter_map(|&seq| inner.get(seq).cloned())
+                        .collect()
+                };
+                for entry in &rows {
+                    show_entry(ui, entry, &font_id);
+                }
+            });
+        ui.data_mut(|data| *data.get_temp_mut_or_default::<Vec<u64>>(scratch_id) = visible);
+
+        // Stop following the tail when the user scrolls up, and resume when
+        // they scroll back to the bottom.
+        let max_offset = r.content_size.y - r.inner_rect.height();
+        let scrolled = ui.input(|input| input.smooth_scroll_delta.y != 0.0);
+        if scrolled && ui.rect_contains_pointer(r.inner_rect) {
+            self.follow_tail = r.state.offset.y >= max_offset - 1.0;
+        }
+    }
+
+    fn show_toolbar(&mut self, ui: &mut egui::Ui, level_counts: [usize; LogLevel::ALL.len()]) {
+        for level in LogLevel::ALL {
+            let count = level_counts[level as usize];
+            let text = egui::RichText::new(format!("{} {count}", level.name()))
+                .color(level.color(ui.visuals()))
+                .monospace();
+            let shown = &mut self.shown_levels[level as usize];
+            if ui.selectable_label(*shown, text).clicked() {
+                *shown = !*shown;
+            }
+        }
+
+        ui.separator();
+
+        ui.add(
+            egui::TextEdit::singleline(&mut self.search)
+                .hint_text("Search")
+                .desired_width(150.0),
+        );
+
+        ui.checkbox(&mut self.follow_tail, "Follow");
+
+        if ui.button("Clear").clicked() {
+            self.buffer.clear();
+        }
+
+        if ui.button("Copy").clicked() {
+            ui.ctx().copy_text(self.buffer.to_plain_text());
+        }
+
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(path) = &self.save_path
+            && ui
+                .button("Save")
+                .on_hover_text(path.display().to_string())
+                .clicked()
+            && let Err(e) = std::fs::write(path, self.buffer.to_plain_text())
+        {
+            let message = format!("error saving log to {}: {e}", path.display());
+            self.buffer.push(LogLevel::Error, "hcegui", message);
+        }
+    }
+}
+
+fn show_entry(ui: &mut egui::Ui, entry: &LogEntry, font_id: &egui::FontId) {
+    ui.horizontal(|ui| {
+        let level = egui::RichText::new(format!("{:5}", entry.level.name()))
+            .font(font_id.clone())
+            .color(entry.level.color(ui.visuals()));
+        ui.label(level);
+        ui.label(
+            egui::RichText::new(&entry.target)
+                .font(font_id.clone())
+                .weak(),
+        );
+
+        // Only display the first line.
+        let first_line = entry.message.lines().next().unwrap_or_default();
+        let r = ui.add(AnsiLabel::new(first_line));
+        if entry.message.contains('\n') {
+            r.on_hover_ui(|ui| {
+                ui.add(AnsiLabel::new(&entry.message));
+            });
+        }
+    });
+}
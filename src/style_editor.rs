@@ -0,0 +1,413 @@
+//! Interactive editor for hcegui styles, similar to egui's own style settings.
+//! **Requires `style_editor` feature.**
+//!
+//! Each style is shown with a live preview, and can be exported as Rust code or
+//! JSON to paste into your app.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! # #[cfg(all(feature = "dnd", feature = "ansi"))] {
+//! use hcegui::style_editor::StyleEditor;
+//!
+//! // Store these in your app state.
+//! let mut dnd_style = hcegui::dnd::DndStyle::default();
+//! let mut ansi_palette = hcegui::ansi::AnsiPalette::default();
+//!
+//! StyleEditor::new()
+//!     .dnd_style(&mut dnd_style)
+//!     .ansi_palette(&mut ansi_palette)
+//!     .show(ui);
+//! # }
+//! # });
+//! ```
+
+#[cfg(any(feature = "dnd", feature = "ansi"))]
+use std::fmt::Write;
+
+#[cfg(feature = "ansi")]
+use crate::ansi::{AnsiLabel, AnsiPalette};
+#[cfg(feature = "dnd")]
+use crate::dnd::{Dnd, DndStyle, DropZoneAnimation};
+
+/// Format for exporting styles.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum ExportFormat {
+    /// Rust struct literal.
+    #[default]
+    Rust,
+    /// JSON, which can be deserialized using `serde`.
+    Json,
+}
+
+/// Interactive editor for hcegui styles.
+///
+/// Only the styles passed to the editor are shown.
+#[derive(Debug, Default)]
+pub struct StyleEditor<'a> {
+    #[cfg(feature = "dnd")]
+    dnd_style: Option<&'a mut DndStyle>,
+    #[cfg(feature = "ansi")]
+    ansi_palette: Option<&'a mut AnsiPalette>,
+    #[cfg(not(any(feature = "dnd", feature = "ansi")))]
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl<'a> StyleEditor<'a> {
+    /// Constructs an empty style editor.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a section for editing a [`DndStyle`]. **Requires `dnd` feature.**
+    #[cfg(feature = "dnd")]
+    #[must_use]
+    pub fn dnd_style(mut self, style: &'a mut DndStyle) -> Self {
+        self.dnd_style = Some(style);
+        self
+    }
+
+    /// Adds a section for editing an [`AnsiPalette`]. **Requires `ansi`
+    /// feature.**
+    #[cfg(feature = "ansi")]
+    #[must_use]
+    pub fn ansi_palette(mut self, palette: &'a mut AnsiPalette) -> Self {
+        self.ansi_palette = Some(palette);
+        self
+    }
+
+    /// Displays the editor. Returns whether any style was changed.
+    #[cfg_attr(
+        not(any(feature = "dnd", feature = "ansi")),
+        allow(unused_mut, unused_variables)
+    )]
+    pub fn show(self, ui: &mut egui::Ui) -> bool {
+        let mut changed = false;
+
+        #[cfg(feature = "dnd")]
+        if let Some(style) = self.dnd_style {
+            egui::CollapsingHeader::new("Drag and drop")
+                .default_open(true)
+                .show(ui, |ui| changed |= dnd_style_section(ui, style));
+        }
+
+        #[cfg(feature = "ansi")]
+        if let Some(palette) = self.ansi_palette {
+            egui::CollapsingHeader::new("ANSI palette")
+                .default_open(true)
+                .show(ui, |ui| changed |= ansi_palette_section(ui, palette));
+        }
+
+        changed
+    }
+}
+
+#[cfg(feature = "dnd")]
+fn dnd_style_section(ui: &mut egui::Ui, style: &mut DndStyle) -> bool {
+    let mut changed = false;
+
+    let mut edited = *style;
+    let DndStyle {
+        payload_hole_rounding,
+        payload_hole_opacity,
+        payload_opacity,
+        payload_scale,
+        payload_rotation,
+        payload_shadow,
+        invalid_target_tint,
+        drop_zone_stroke_width,
+        drop_zone_rounding,
+        drop_zone_animation,
+        reorder_stroke_width,
+        reorder_gap,
+        settle_animation,
+        snap_back_animation,
+        drop_zone_active_color,
+        drop_zone_inactive_color,
+        reorder_line_color,
+        payload_hole_color,
+    } = &mut edited;
+    let fields = [
+        ("payload_hole_rounding", payload_hole_rounding, 0.0..=16.0),
+        ("payload_hole_opacity", payload_hole_opacity, 0.0..=1.0),
+        ("payload_opacity", payload_opacity, 0.0..=1.0),
+        ("payload_scale", payload_scale, 0.5..=2.0),
+        ("payload_rotation", payload_rotation, -0.5..=0.5),
+        ("drop_zone_stroke_width", drop_zone_stroke_width, 0.0..=8.0),
+        ("drop_zone_rounding", drop_zone_rounding, 0.0..=16.0),
+        ("reorder_stroke_width", reorder_stroke_width, 0.0..=8.0),
+    ];
+
+    let mut rust = "DndStyle {\n".to_owned();
+    egui::Grid::new("dnd_style").num_columns(2).show(ui, |ui| {
+        for (name, value, range) in fields {
+            ui.label(name);
+            changed |= ui.add(egui::Slider::new(value, range)).changed();
+            ui.end_row();
+            let _ = writeln!(rust, "    {name}: {value:?},");
+        }
+        for (name, value) in [
+            ("reorder_gap", reorder_gap),
+            ("settle_animation", settle_animation),
+            ("snap_back_animation", snap_back_animation),
+        ] {
+            ui.label(name);
+            changed |= ui.checkbox(value, "").changed();
+            ui.end_row();
+            let _ = writeln!(rust, "    {name}: {value:?},");
+        }
+        ui.label("drop_zone_animation");
+        egui::ComboBox::from_id_salt("drop_zone_animation")
+            .selected_text(format!("{drop_zone_animation:?}"))
+            .show_ui(ui, |ui| {
+                for animation in [
+                    DropZoneAnimation::None,
+                    DropZoneAnimation::Pulse,
+                    DropZoneAnimation::MarchingAnts,
+                ] {
+                    changed |= ui
+                        .selectable_value(drop_zone_animation, animation, format!("{animation:?}"))
+                        .changed();
+                }
+            });
+        ui.end_row();
+        let _ = writeln!(
+            rust,
+            "    drop_zone_animation: DropZoneAnimation::{drop_zone_animation:?},"
+        );
+        ui.label("payload_shadow");
+        let mut has_shadow = payload_shadow.is_some();
+        if ui.checkbox(&mut has_shadow, "").changed() {
+            *payload_shadow = has_shadow.then_some(ui.visuals().popup_shadow);
+            changed = true;
+        }
+        ui.end_row();
+        write_optional_shadow(&mut rust, "payload_shadow", *payload_shadow);
+        let defaults = DndStyle::from_visuals(ui.visuals());
+        for (name, color, default) in [
+            (
+                "drop_zone_active_color",
+                drop_zone_active_color,
+                defaults.drop_zone_active_color,
+            ),
+            (
+                "drop_zone_inactive_color",
+                drop_zone_inactive_color,
+                defaults.drop_zone_inactive_color,
+            ),
+            (
+                "reorder_line_color",
+                reorder_line_color,
+                defaults.reorder_line_color,
+            ),
+            (
+                "payload_hole_color",
+                payload_hole_color,
+                defaults.payload_hole_color,
+            ),
+            (
+                "invalid_target_tint",
+                invalid_target_tint,
+                Some(ui.visuals().error_fg_color.gamma_multiply(0.25)),
+            ),
+        ] {
+            ui.label(name);
+            changed |= optional_color_ui(ui, color, default.unwrap_or_default());
+            ui.end_row();
+            write_optional_color(&mut rust, name, *color);
+        }
+    });
+    rust.push('}');
+    *style = edited;
+
+    if ui.button("Reset").clicked() {
+        *style = DndStyle::default();
+        changed = true;
+    }
+
+    ui.label("Preview (drag to reorder, or drop on the box):");
+    dnd_preview(ui, *style);
+
+    export_ui(ui, "dnd_style_export", rust, || to_json(style));
+
+    changed
+}
+
+#[cfg(feature = "dnd")]
+fn dnd_preview(ui: &mut egui::Ui, style: DndStyle) {
+    let id = ui.id().with("dnd_preview");
+    let mut items: Vec<&str> = ui
+        .data(|data| data.get_temp(id))
+        .unwrap_or_else(|| vec!["Alpha", "Beta", "Gamma"]);
+    let mut drops: usize = ui.data(|data| data.get_temp(id.with("drops")).unwrap_or(0));
+
+    egui::Frame::group(ui.style()).show(ui, |ui| {
+        let mut dnd = Dnd::new(ui.ctx(), id).with_style(style);
+        for (i, &item) in items.iter().enumerate() {
+            dnd.reorderable_with_handle(ui, i, |ui, _| ui.label(item));
+        }
+        let r = egui::Frame::NONE
+            .inner_margin(8.0)
+            .show(ui, |ui| ui.label(format!("Drop zone ({drops} drops)")))
+            .response;
+        dnd.drop_zone(ui, &r, (usize::MAX, crate::dnd::BeforeOrAfter::Before));
+        if let Some(r) = dnd.finish(ui).if_done_dragging() {
+            if r.target.0 == usize::MAX {
+                drops += 1;
+            } else {
+                r.reorder(&mut items);
+            }
+        }
+    });
+
+    ui.data_mut(|data| {
+        data.insert_temp(id, items);
+        data.insert_temp(id.with("drops"), drops);
+    });
+}
+
+#[cfg(feature = "ansi")]
+fn ansi_palette_section(ui: &mut egui::Ui, palette: &mut AnsiPalette) -> bool {
+    let mut changed = false;
+
+    let mut edited = *palette;
+    let AnsiPalette {
+        red,
+        yellow,
+        light_mode_brightness,
+    } = &mut edited;
+    let colors = [
+        ("red", red, ui.visuals().error_fg_color),
+        ("yellow", yellow, ui.visuals().warn_fg_color),
+    ];
+
+    let mut rust = "AnsiPalette {\n".to_owned();
+    egui::Grid::new("ansi_palette")
+        .num_columns(2)
+        .show(ui, |ui| {
+            for (name, color, default) in colors {
+                ui.label(name);
+                changed |= optional_color_ui(ui, color, default);
+                ui.end_row();
+                write_optional_color(&mut rust, name, *color);
+            }
+
+            ui.label("light_mode_brightness");
+            let slider = egui::Slider::new(light_mode_brightness, 0.0..=1.0);
+            changed |= ui.add(slider).changed();
+            ui.end_row();
+            let _ = writeln!(
+                rust,
+                "    light_mode_brightness: {light_mode_brightness:?},"
+            );
+        });
+    rust.push('}');
+    *palette = edited;
+
+    if ui.button("Reset").clicked() {
+        *palette = AnsiPalette::default();
+        changed = true;
+    }
+
+    ui.label("Preview:");
+    egui::Frame::canvas(ui.style()).show(ui, |ui| {
+        const SAMPLE: &str = "\x1b[31merror\x1b[0m: something broke\n\
+                              \x1b[33mwarning\x1b[0m: something looks wrong\n\
+                              \x1b[38;5;10m   Compiling\x1b[0m \x1b[38;5;208m256-color\x1b[0m text";
+        ui.add(AnsiLabel::new(SAMPLE).palette(*palette));
+    });
+
+    export_ui(ui, "ansi_palette_export", rust, || to_json(palette));
+
+    changed
+}
+
+/// Shows a color that can be left as `None` to use `default`. Returns whether
+/// it was changed.
+#[cfg(any(feature = "dnd", feature = "ansi"))]
+fn optional_color_ui(
+    ui: &mut egui::Ui,
+    color: &mut Option<egui::Color32>,
+    default: egui::Color32,
+) -> bool {
+    let mut changed = false;
+    ui.horizontal(|ui| {
+        let mut custom = color.is_some();
+        changed |= ui.checkbox(&mut custom, "Custom").changed();
+        let mut c = color.unwrap_or(default);
+        ui.add_enabled_ui(custom, |ui| {
+            changed |= ui.color_edit_button_srgba(&mut c).changed();
+        });
+        *color = custom.then_some(c);
+    });
+    changed
+}
+
+/// Appends a field containing an optional color to exported Rust code.
+#[cfg(any(feature = "dnd", feature = "ansi"))]
+fn write_optional_color(rust: &mut String, name: &str, color: Option<egui::Color32>) {
+    let _ = match color {
+        Some(c) => {
+            let [r, g, b, a] = c.to_array();
+            let c = format!("egui::Color32::from_rgba_premultiplied({r}, {g}, {b}, {a})");
+            writeln!(rust, "    {name}: Some({c}),")
+        }
+        None => writeln!(rust, "    {name}: None,"),
+    };
+}
+
+/// Appends a field containing an optional shadow to exported Rust code.
+#[cfg(feature = "dnd")]
+fn write_optional_shadow(rust: &mut String, name: &str, shadow: Option<egui::Shadow>) {
+    let _ = match shadow {
+        Some(egui::Shadow {
+            offset,
+            blur,
+            spread,
+            color,
+        }) => {
+            let [r, g, b, a] = color.to_array();
+            let color = format!("egui::Color32::from_rgba_premultiplied({r}, {g}, {b}, {a})");
+            writeln!(
+                rust,
+                "    {name}: Some(egui::Shadow {{ offset: {offset:?}, blur: {blur}, spread: {spread}, color: {color} }}),",
+            )
+        }
+        None => writeln!(rust, "    {name}: None,"),
+    };
+}
+
+/// Shows exported code for a style, with a button to copy it.
+#[cfg(any(feature = "dnd", feature = "ansi"))]
+fn export_ui(ui: &mut egui::Ui, id_salt: &str, rust: String, json: impl FnOnce() -> String) {
+    let id = ui.id().with(id_salt);
+    let mut format: ExportFormat = ui.data(|data| data.get_temp(id).unwrap_or_default());
+
+    ui.horizontal(|ui| {
+        ui.label("Export as");
+        ui.selectable_value(&mut format, ExportFormat::Rust, "Rust");
+        ui.selectable_value(&mut format, ExportFormat::Json, "JSON");
+    });
+    let mut text = match format {
+        ExportFormat::Rust => rust,
+        ExportFormat::Json => json(),
+    };
+    ui.add(
+        egui::TextEdit::multiline(&mut text)
+            .code_editor()
+            .interactive(false)
+            .desired_rows(1)
+            .desired_width(f32::INFINITY),
+    );
+    if ui.button("Copy").clicked() {
+        ui.ctx().copy_text(text);
+    }
+
+    ui.data_mut(|data| data.insert_temp(id, format));
+}
+
+#[cfg(any(feature = "dnd", feature = "ansi"))]
+fn to_json(value: &impl serde::Serialize) -> String {
+    serde_json::to_string_pretty(value).unwrap_or_else(|e| format!("error: {e}"))
+}
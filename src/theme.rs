@@ -0,0 +1,202 @@
+//! Loading hcegui styles from a theme file at runtime. **Requires `theme`
+//! feature.**
+//!
+//! A theme file is TOML or JSON with one optional table per style. Missing
+//! tables and fields keep their default values. hcegui's utility widgets use
+//! egui's [`egui::Visuals`], so they have no section of their own.
+//!
+//! ```toml
+//! [dnd]
+//! drop_zone_stroke_width = 3.0
+//! payload_opacity = 0.8
+//!
+//! [ansi]
+//! red = [255, 80, 80, 255]
+//! light_mode_brightness = 0.5
+//! ```
+//!
+//! With the `theme_watch` feature, [`ThemeWatcher`] reloads the theme whenever
+//! the file changes, which is useful for iterating on a theme without
+//! recompiling.
+//!
+//! # Examples
+//!
+//! ```
+//! # egui::__run_test_ui(|ui| {
+//! use hcegui::theme::Theme;
+//!
+//! let theme = Theme::from_toml("[dnd]\npayload_opacity = 0.5").unwrap();
+//! theme.apply(ui.ctx());
+//! # });
+//! ```
+
+use std::path::Path;
+
+/// Styles loaded from a theme file.
+#[derive(Debug, Default, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(default)]
+pub struct Theme {
+    /// Default style for [`crate::dnd::Dnd`]. **Requires `dnd` feature.**
+    #[cfg(feature = "dnd")]
+    pub dnd: crate::dnd::DndStyle,
+    /// Default palette for [`crate::ansi::AnsiLabel`]. **Requires `ansi`
+    /// feature.**
+    #[cfg(feature = "ansi")]
+    pub ansi: crate::ansi::AnsiPalette,
+}
+impl Theme {
+    /// Parses a theme from TOML.
+    pub fn from_toml(s: &str) -> Result<Self, ThemeError> {
+        toml::from_str(s).map_err(ThemeError::Toml)
+    }
+
+    /// Parses a theme from JSON.
+    pub fn from_json(s: &str) -> Result<Self, ThemeError> {
+        serde_json::from_str(s).map_err(ThemeError::Json)
+    }
+
+    /// Loads a theme from a file. Files ending in `.json` are parsed as JSON;
+    /// all other files are parsed as TOML.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ThemeError> {
+        let path = path.as_ref();
+        let contents = std::fs::read_to_string(path).map_err(ThemeError::Io)?;
+        if path.extension().is_some_and(|ext| ext == "json") {
+            Self::from_json(&contents)
+        } else {
+            Self::from_toml(&contents)
+        }
+    }
+
+    /// Serializes the theme to TOML.
+    pub fn to_toml(&self) -> String {
+        toml::to_string(self).unwrap_or_else(|e| format!("# error: {e}"))
+    }
+
+    /// Sets the theme's styles as the defaults for `ctx`.
+    #[cfg_attr(not(any(feature = "dnd", feature = "ansi")), allow(unused_variables))]
+    pub fn apply(&self, ctx: &egui::Context) {
+        #[cfg(feature = "dnd")]
+        crate::dnd::set_default_style(ctx, self.dnd);
+        #[cfg(feature = "ansi")]
+        crate::ansi::set_palette(ctx, self.ansi);
+    }
+}
+
+/// Error loading a [`Theme`].
+#[derive(Debug)]
+pub enum ThemeError {
+    /// Error reading the file.
+    Io(std::io::Error),
+    /// Error parsing TOML.
+    Toml(toml::de::Error),
+    /// Error parsing JSON.
+    Json(serde_json::Error),
+}
+impl std::fmt::Display for ThemeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(e) => write!(f, "error reading theme: {e}"),
+            Self::Toml(e) => write!(f, "error parsing theme: {e}"),
+            Self::Json(e) => write!(f, "error parsing theme: {e}"),
+        }
+    }
+}
+impl std::error::Error for ThemeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(e) => Some(e),
+            Self::Toml(e) => Some(e),
+            Self::Json(e) => Some(e),
+        }
+    }
+}
+
+/// Reloads a theme file whenever it changes. **Requires `theme_watch`
+/// feature.**
+///
+/// The file's modification time is checked periodically, so this works
+/// without any platform-specific file notification API. It is meant for
+/// development and does nothing useful on the web.
+#[cfg(feature = "theme_watch")]
+#[derive(Debug)]
+pub struct ThemeWatcher {
+    path: std::path::PathBuf,
+    interval: std::time::Duration,
+    last_modified: Option<std::time::SystemTime>,
+    last_check: Option<f64>,
+    last_error: Option<ThemeError>,
+}
+#[cfg(feature = "theme_watch")]
+impl ThemeWatcher {
+    /// Constructs a watcher for a theme file. The file is loaded on the first
+    /// call to [`ThemeWatcher::update()`].
+    pub fn new(path: impl Into<std::path::PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            interval: std::time::Duration::from_millis(500),
+            last_modified: None,
+            last_check: None,
+            last_error: None,
+        }
+    }
+
+    /// Sets how often to check the file for changes. Default: 500 ms.
+    #[must_use]
+    pub fn with_interval(mut self, interval: std::time::Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Returns the path of the theme file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the error from the most recent reload, if it failed.
+    pub fn last_error(&self) -> Option<&ThemeError> {
+        self.last_error.as_ref()
+    }
+
+    /// Checks whether the file has changed, and if so reloads and
+    /// [applies](Theme::apply) it. Returns the new theme if it was reloaded.
+    ///
+    /// Call this once per frame.
+    pub fn update(&mut self, ctx: &egui::Context) -> Option<Theme> {
+        ctx.request_repaint_after(self.interval);
+
+        let now = ctx.input(|input| input.time);
+        if self
+            .last_check
+            .is_some_and(|t| now - t < self.interval.as_secs_f64())
+        {
+            return None;
+        }
+        self.last_check = Some(now);
+
+        let modified = std::fs::metadata(&self.path).and_then(|m| m.modified());
+        let modified = match modified {
+            Ok(t) => t,
+            Err(e) => {
+                self.last_error = Some(ThemeError::Io(e));
+                self.last_modified = None;
+                return None;
+            }
+        };
+        if self.last_modified == Some(modified) {
+            return None;
+        }
+        self.last_modified = Some(modified);
+
+        match Theme::load(&self.path) {
+            Ok(theme) => {
+                self.last_error = None;
+                theme.apply(ctx);
+                Some(theme)
+            }
+            Err(e) => {
+                self.last_error = Some(e);
+                None
+            }
+        }
+    }
+}
@@ -5,6 +5,43 @@
 //! See
 //! [`bin/demo/util.rs`](https://github.com/HactarCE/hcegui/blob/main/src/bin/demo/util.rs).
 
+mod auto_flow_grid;
+mod baseline;
+mod clamped_text;
+mod color;
+mod confirm_button;
+mod fuzzy;
+mod icon;
+mod kv_table;
+mod list_nav;
+mod pixel_snap;
+mod progress;
+mod saved_layout;
+mod skeleton;
+mod smoothing;
+mod text;
+
+pub use auto_flow_grid::AutoFlowGrid;
+#[cfg(feature = "inspector")]
+pub(crate) use baseline::clear_baseline_state;
+pub use baseline::{BaselineRow, baseline_aligned};
+pub use clamped_text::ClampedText;
+pub use color::{ColorSwatch, MAX_RECENT_COLORS, PaletteStrip, add_recent_color, recent_colors};
+pub use confirm_button::ConfirmButton;
+pub use fuzzy::{FuzzyMatch, fuzzy_match, highlighted_text};
+pub use icon::{Icon, icon_button, icon_label};
+pub use kv_table::kv_table;
+pub use list_nav::ListNav;
+pub use pixel_snap::{
+    is_odd_pixel_width, snap_line_segment, snap_pos, snap_rect, snap_stroke_coord, snap_stroke_pos,
+    snap_stroke_rect,
+};
+pub use progress::{Progress, format_eta};
+pub use saved_layout::{SavedLayout, SavedPanel, SavedWindow};
+pub use skeleton::{Skeleton, paint_shimmer_rect};
+pub use smoothing::{exp_decay, smooth_damp, smooth_toward};
+pub use text::{text_size, text_size_in_ui, text_width, widest_text};
+
 /// Displays UI in a wrapping layout, pushing this widget onto the next line if
 /// it cannot be displayed on the current line without wrapping.
 pub fn show_on_one_line<R>(
@@ -13,7 +50,7 @@ pub fn show_on_one_line<R>(
 ) -> R {
     if ui.layout().main_wrap
         && ui.layout().is_horizontal()
-        && ui.cursor().left() > ui.max_rect().left()
+        && is_mid_line(ui)
         && non_wrapping_size_of_ui(ui, &mut add_contents).x >= ui.available_size_before_wrap().x
     {
         force_horizontal_wrap(ui);
@@ -22,12 +59,76 @@ pub fn show_on_one_line<R>(
     add_contents(ui)
 }
 
+/// Displays a group of widgets as a single unbreakable unit in a wrapping
+/// layout, pushing the whole group onto the next line if it does not fit on
+/// the current line.
+///
+/// This is useful for related widgets that should stay together, such as a
+/// checkbox and its explanatory label or an icon and a button.
+///
+/// Unlike [`show_on_one_line()`], the contents are never wrapped internally
+/// unless they are wider than an entire line. The width of the group is
+/// remembered between frames, so `add_contents` is only called twice (once for
+/// measurement) on the first frame. Wrap groups may be nested.
+pub fn wrap_group<R>(
+    ui: &mut egui::Ui,
+    mut add_contents: impl FnMut(&mut egui::Ui) -> R,
+) -> egui::InnerResponse<R> {
+    let id = ui.next_auto_id().with("wrap_group");
+    let stored_width = ui.data(|data| data.get_temp::<f32>(id));
+    let width = match stored_width {
+        Some(w) => w,
+        None => non_wrapping_size_of_ui(ui, &mut add_contents).x,
+    };
+
+    let is_wrapping = ui.layout().main_wrap && ui.layout().is_horizontal();
+    if is_wrapping && is_mid_line(ui) && width >= ui.available_size_before_wrap().x {
+        force_horizontal_wrap(ui);
+    }
+
+    if is_wrapping && width > ui.max_rect().width() {
+        // Too wide to fit on one line, so allow wrapping and measure it again
+        // next frame.
+        ui.data_mut(|data| data.remove_temp::<f32>(id));
+        ui.scope(add_contents)
+    } else {
+        let layout = egui::Layout {
+            main_wrap: false,
+            main_justify: false,
+            ..*ui.layout()
+        };
+        let r = ui.with_layout(layout, add_contents);
+        let new_width = r.response.rect.width();
+        if stored_width != Some(new_width) {
+            ui.data_mut(|data| data.insert_temp(id, new_width));
+        }
+        #[cfg(feature = "inspector")]
+        crate::inspector::record_wrap_group(ui.ctx(), id, new_width);
+        r
+    }
+}
+
+/// Adds a horizontal separator spanning the full width of the UI.
+///
+/// In a horizontal wrapping layout, [`egui::Ui::separator()`] draws a short
+/// vertical line. This instead starts a new line, draws a horizontal line
+/// across the whole row, and then continues on the next line. In any other
+/// layout, this is equivalent to a horizontal [`egui::Separator`].
+pub fn wrapped_separator(ui: &mut egui::Ui) -> egui::Response {
+    if ui.layout().main_wrap && ui.layout().is_horizontal() && is_mid_line(ui) {
+        force_horizontal_wrap(ui);
+    }
+
+    ui.add(egui::Separator::default().horizontal())
+}
+
 /// Returns the size used `add_contents()` in a non-wrapping, non-justified
 /// layout.
 pub fn non_wrapping_size_of_ui<R>(
     ui: &mut egui::Ui,
     add_contents: impl FnOnce(&mut egui::Ui) -> R,
 ) -> egui::Vec2 {
+    profile_scope!("hcegui::util::non_wrapping_size_of_ui");
     let ui_builder = egui::UiBuilder::new()
         .layout(egui::Layout {
             main_wrap: false,
@@ -40,6 +141,27 @@ pub fn non_wrapping_size_of_ui<R>(
     r.response.rect.size()
 }
 
+/// Returns whether the cursor is partway through a line in a horizontal
+/// layout, accounting for right-to-left layouts.
+fn is_mid_line(ui: &egui::Ui) -> bool {
+    if ui.layout().main_dir == egui::Direction::RightToLeft {
+        ui.cursor().right() < ui.max_rect().right()
+    } else {
+        ui.cursor().left() > ui.max_rect().left()
+    }
+}
+
+/// Returns the part of `rect` spanning `fraction` of its width, starting from
+/// the left or from the right in a right-to-left layout.
+fn leading_part_of_rect(ui: &egui::Ui, rect: egui::Rect, fraction: f32) -> egui::Rect {
+    let width = rect.width() * fraction;
+    if ui.layout().prefer_right_to_left() {
+        egui::Rect::from_x_y_ranges(rect.right() - width..=rect.right(), rect.y_range())
+    } else {
+        egui::Rect::from_x_y_ranges(rect.left()..=rect.left() + width, rect.y_range())
+    }
+}
+
 /// Wraps to the next line in a horizontal wrapping layout.
 fn force_horizontal_wrap(ui: &mut egui::Ui) {
     // This is really hacky but I don't know anything else that works.
@@ -0,0 +1,92 @@
+/// Grid that fits as many columns as possible into the available width, such as
+/// for icons or cards.
+///
+/// The number of columns is recomputed every frame, so the grid reflows when
+/// the UI is resized.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// let names = ["apple", "banana", "cherry", "durian", "elderberry"];
+/// hcegui::util::AutoFlowGrid::new(80.0).show(ui, names.len(), |ui, i| {
+///     ui.label(names[i]);
+/// });
+/// # });
+/// ```
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AutoFlowGrid {
+    cell_width: Option<f32>,
+    spacing: Option<egui::Vec2>,
+}
+
+impl AutoFlowGrid {
+    /// Constructs a grid where every cell has the same fixed width.
+    pub fn new(cell_width: f32) -> Self {
+        Self {
+            cell_width: Some(cell_width),
+            spacing: None,
+        }
+    }
+
+    /// Constructs a grid where every cell is as wide as the widest item.
+    ///
+    /// Every item is measured using [`super::non_wrapping_size_of_ui()`], so
+    /// `add_item` is called twice for each item.
+    pub fn measured() -> Self {
+        Self::default()
+    }
+
+    /// Sets the spacing between cells. Default:
+    /// [`egui::style::Spacing::item_spacing`].
+    #[must_use]
+    pub fn spacing(mut self, spacing: impl Into<egui::Vec2>) -> Self {
+        self.spacing = Some(spacing.into());
+        self
+    }
+
+    /// Returns the number of columns of width `cell_width` that fit in
+    /// `available_width`, which is always at least 1.
+    pub fn columns_that_fit(available_width: f32, cell_width: f32, spacing: f32) -> usize {
+        let columns = (available_width + spacing) / (cell_width + spacing);
+        (columns.floor() as usize).max(1)
+    }
+
+    /// Displays the grid with `len` items.
+    ///
+    /// `add_item` is called with the index of each item. Returns the number of
+    /// columns.
+    pub fn show(
+        self,
+        ui: &mut egui::Ui,
+        len: usize,
+        mut add_item: impl FnMut(&mut egui::Ui, usize),
+    ) -> egui::InnerResponse<usize> {
+        let spacing = self.spacing.unwrap_or(ui.spacing().item_spacing);
+        let cell_width = self.cell_width.unwrap_or_else(|| {
+            profile_scope!("hcegui::util::AutoFlowGrid measure");
+            (0..len)
+                .map(|i| super::non_wrapping_size_of_ui(ui, |ui| add_item(ui, i)).x)
+                .fold(0.0, f32::max)
+        });
+        let columns = Self::columns_that_fit(ui.available_width(), cell_width, spacing.x);
+
+        ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing = spacing;
+            for row_start in (0..len).step_by(columns) {
+                ui.horizontal_top(|ui| {
+                    for i in row_start..(row_start + columns).min(len) {
+                        ui.allocate_ui_with_layout(
+                            egui::vec2(cell_width, 0.0),
+                            egui::Layout::top_down(egui::Align::Min),
+                            |ui| {
+                                ui.set_width(cell_width);
+                                add_item(ui, i);
+                            },
+                        );
+                    }
+                });
+            }
+            columns
+        })
+    }
+}
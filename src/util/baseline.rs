@@ -0,0 +1,162 @@
+use smallvec::SmallVec;
+
+/// Displays a horizontal row of widgets with their text aligned on a shared
+/// baseline, instead of being vertically centered.
+///
+/// Vertical centering looks subtly wrong when mixing widgets with different
+/// font sizes or padding, such as a label next to a small button. Add widgets
+/// using [`BaselineRow::add()`] and they will be shifted vertically so that
+/// their text baselines line up.
+///
+/// Baselines are measured each frame and applied on the next frame. If they
+/// change, the frame is discarded using [`egui::Context::request_discard()`].
+pub fn baseline_aligned<R>(
+    ui: &mut egui::Ui,
+    add_contents: impl FnOnce(&mut BaselineRow<'_>) -> R,
+) -> egui::InnerResponse<R> {
+    let layout = if ui.layout().prefer_right_to_left() {
+        egui::Layout::right_to_left(egui::Align::Min)
+    } else {
+        egui::Layout::left_to_right(egui::Align::Min)
+    };
+    ui.with_layout(layout, |ui| {
+        let id = ui.id();
+        let old_state = ui
+            .data_mut(|data| std::mem::take(data.get_temp_mut_or_default::<BaselineRowState>(id)));
+
+        let mut row = BaselineRow {
+            ui,
+            old_state,
+            new_state: BaselineRowState::default(),
+        };
+        let ret = add_contents(&mut row);
+
+        let BaselineRow {
+            ui,
+            old_state,
+            new_state,
+        } = row;
+        #[cfg(feature = "inspector")]
+        crate::inspector::record_baseline_row(ui.ctx(), id, &new_state.baselines);
+
+        if new_state != old_state {
+            ui.ctx().request_discard("baseline alignment changed");
+        }
+        ui.data_mut(|data| *data.get_temp_mut_or_default::<BaselineRowState>(id) = new_state);
+
+        ret
+    })
+}
+
+/// Clears the baseline measurements for all rows.
+#[cfg(feature = "inspector")]
+pub(crate) fn clear_baseline_state(ctx: &egui::Context) {
+    ctx.data_mut(|data| data.remove_by_type::<BaselineRowState>());
+}
+
+/// Row of widgets aligned on a shared baseline. See [`baseline_aligned()`].
+pub struct BaselineRow<'a> {
+    ui: &'a mut egui::Ui,
+    old_state: BaselineRowState,
+    new_state: BaselineRowState,
+}
+
+impl BaselineRow<'_> {
+    /// Returns the underlying [`egui::Ui`].
+    ///
+    /// Widgets added directly to it are aligned to the top of the row.
+    pub fn ui(&mut self) -> &mut egui::Ui {
+        self.ui
+    }
+
+    /// Adds a widget whose text uses `font` and is vertically centered in the
+    /// widget, which is true of most built-in egui widgets.
+    ///
+    /// For example, labels use [`egui::TextStyle::Body`] and buttons and
+    /// [`egui::DragValue`]s use [`egui::TextStyle::Button`].
+    pub fn add(
+        &mut self,
+        font: impl Into<egui::FontSelection>,
+        widget: impl egui::Widget,
+    ) -> egui::Response {
+        let font_id = font.into().resolve(self.ui.style());
+        let r = self.add_with_baseline(
+            |ui, r| centered_text_baseline(ui, &font_id, r.rect.height()),
+            |ui| ui.add(widget),
+        );
+        r.inner
+    }
+
+    /// Adds a label with the [`egui::TextStyle::Body`] font.
+    pub fn label(&mut self, text: impl Into<egui::WidgetText>) -> egui::Response {
+        self.add(egui::TextStyle::Body, egui::Label::new(text))
+    }
+
+    /// Adds a button with the [`egui::TextStyle::Button`] font.
+    pub fn button(&mut self, text: impl Into<egui::WidgetText>) -> egui::Response {
+        self.add(egui::TextStyle::Button, egui::Button::new(text))
+    }
+
+    /// Adds a small button with the [`egui::TextStyle::Small`] font.
+    pub fn small_button(&mut self, text: impl Into<egui::WidgetText>) -> egui::Response {
+        let text = text.into().text_style(egui::TextStyle::Small);
+        let button = egui::Button::new(text).small();
+        self.add(egui::TextStyle::Small, button)
+    }
+
+    /// Adds arbitrary contents with a custom baseline.
+    ///
+    /// `baseline` takes the response from `add_contents` and returns the
+    /// distance from the top of the response rectangle to its baseline. For
+    /// example, an icon that should sit on the baseline can return
+    /// `r.rect.height()`.
+    pub fn add_with_baseline(
+        &mut self,
+        baseline: impl FnOnce(&egui::Ui, &egui::Response) -> f32,
+        add_contents: impl FnOnce(&mut egui::Ui) -> egui::Response,
+    ) -> egui::InnerResponse<egui::Response> {
+        let index = self.new_state.baselines.len();
+        let old_baseline = self.old_state.baselines.get(index).copied();
+        let shift = old_baseline.map_or(0.0, |b| self.old_state.max_baseline() - b);
+
+        let r = self.ui.vertical(|ui| {
+            ui.spacing_mut().item_spacing.y = 0.0;
+            ui.add_space(shift);
+            add_contents(ui)
+        });
+
+        let b = baseline(self.ui, &r.inner);
+        self.new_state.baselines.push(b);
+        r
+    }
+}
+
+/// Baseline measurements persisted between frames for each
+/// [`baseline_aligned()`] row.
+#[derive(Debug, Default, Clone, PartialEq)]
+struct BaselineRowState {
+    /// Distance from the top of each widget to its baseline.
+    baselines: SmallVec<[f32; 8]>,
+}
+impl BaselineRowState {
+    fn max_baseline(&self) -> f32 {
+        self.baselines.iter().copied().fold(0.0, f32::max)
+    }
+}
+
+/// Returns the distance from the top of a widget of height `height` to the
+/// baseline of a single line of vertically centered text in `font_id`.
+fn centered_text_baseline(ui: &egui::Ui, font_id: &egui::FontId, height: f32) -> f32 {
+    let galley = crate::compat::layout_no_wrap(
+        ui.ctx(),
+        "x".to_owned(),
+        font_id.clone(),
+        egui::Color32::PLACEHOLDER,
+    );
+    let ascent = galley
+        .rows
+        .first()
+        .and_then(|row| Some(row.pos.y + row.row.glyphs.first()?.pos.y))
+        .unwrap_or(galley.size().y);
+    (height - galley.size().y) / 2.0 + ascent
+}
@@ -0,0 +1,124 @@
+/// Text that is clamped to a maximum number of lines, with a fading bottom
+/// edge and a "Show more" / "Show less" toggle.
+///
+/// Whether the text is expanded is remembered per ID.
+pub struct ClampedText {
+    text: egui::WidgetText,
+    max_lines: usize,
+    id_salt: Option<egui::Id>,
+    fade_color: Option<egui::Color32>,
+}
+
+impl ClampedText {
+    /// Constructs clamped text showing at most 3 lines.
+    pub fn new(text: impl Into<egui::WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            max_lines: 3,
+            id_salt: None,
+            fade_color: None,
+        }
+    }
+
+    /// Sets the maximum number of lines to show while collapsed. Default: `3`.
+    #[must_use]
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.max_lines = max_lines.max(1);
+        self
+    }
+
+    /// Sets a source for the ID used to remember whether the text is expanded.
+    /// By default, the ID is based on the position in the UI.
+    #[must_use]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(egui::Id::new(id_salt));
+        self
+    }
+
+    /// Sets the background color that the bottom edge fades to. Default:
+    /// [`egui::Visuals::panel_fill`].
+    #[must_use]
+    pub fn fade_color(mut self, fade_color: egui::Color32) -> Self {
+        self.fade_color = Some(fade_color);
+        self
+    }
+
+    /// Returns whether the text with the given ID is expanded.
+    pub fn is_expanded(ctx: &egui::Context, id: egui::Id) -> bool {
+        ctx.data_mut(|data| data.get_persisted(id).unwrap_or(false))
+    }
+
+    /// Sets whether the text with the given ID is expanded.
+    pub fn set_expanded(ctx: &egui::Context, id: egui::Id, expanded: bool) {
+        ctx.data_mut(|data| data.insert_persisted(id, expanded));
+    }
+}
+
+impl egui::Widget for ClampedText {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        profile_scope!("hcegui::util::ClampedText");
+        let id = match self.id_salt {
+            Some(id_salt) => ui.make_persistent_id(id_salt),
+            None => ui.next_auto_id(),
+        };
+        let mut expanded = Self::is_expanded(ui.ctx(), id);
+
+        let galley = self.text.into_galley(
+            ui,
+            Some(egui::TextWrapMode::Wrap),
+            ui.available_width(),
+            egui::TextStyle::Body,
+        );
+
+        let is_clamped = galley.rows.len() > self.max_lines;
+        let visible_height = match galley.rows.get(self.max_lines - 1) {
+            Some(last_row) if is_clamped && !expanded => last_row.rect().bottom(),
+            _ => galley.size().y,
+        };
+
+        let r = ui
+            .vertical(|ui| {
+                let size = egui::vec2(galley.size().x, visible_height);
+                let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                if ui.is_rect_visible(rect) {
+                    let painter = ui.painter_at(rect);
+                    painter.galley(rect.min, galley.clone(), ui.visuals().text_color());
+
+                    if is_clamped && !expanded {
+                        let fade_height = ui.text_style_height(&egui::TextStyle::Body) * 1.5;
+                        let fade_rect = egui::Rect::from_x_y_ranges(
+                            rect.x_range(),
+                            (rect.bottom() - fade_height)..=rect.bottom(),
+                        );
+                        let fade_color = self.fade_color.unwrap_or(ui.visuals().panel_fill);
+                        paint_vertical_fade(&painter, fade_rect, fade_color);
+                    }
+                }
+
+                if is_clamped {
+                    let link_text = if expanded { "Show less" } else { "Show more" };
+                    if ui.link(link_text).clicked() {
+                        expanded = !expanded;
+                    }
+                }
+            })
+            .response;
+
+        Self::set_expanded(ui.ctx(), id, expanded);
+
+        r
+    }
+}
+
+/// Paints a gradient in `rect` that goes from transparent at the top to
+/// `color` at the bottom.
+fn paint_vertical_fade(painter: &egui::Painter, rect: egui::Rect, color: egui::Color32) {
+    let mut mesh = egui::Mesh::default();
+    mesh.colored_vertex(rect.left_top(), egui::Color32::TRANSPARENT);
+    mesh.colored_vertex(rect.right_top(), egui::Color32::TRANSPARENT);
+    mesh.colored_vertex(rect.left_bottom(), color);
+    mesh.colored_vertex(rect.right_bottom(), color);
+    mesh.add_triangle(0, 1, 2);
+    mesh.add_triangle(1, 2, 3);
+    painter.add(mesh);
+}
@@ -0,0 +1,213 @@
+use egui::color_picker::{Alpha, color_picker_color32, show_color_at};
+use egui::{Color32, Popup, PopupCloseBehavior};
+
+/// Maximum number of recently used colors remembered by [`add_recent_color()`].
+pub const MAX_RECENT_COLORS: usize = 12;
+
+/// Compact color swatch that opens egui's color picker in a popup when clicked.
+///
+/// Colors chosen using the popup are added to the list of [recently used
+/// colors](recent_colors), which is shown below the picker.
+pub struct ColorSwatch<'a> {
+    color: &'a mut Color32,
+    alpha: bool,
+    size: Option<egui::Vec2>,
+}
+
+impl<'a> ColorSwatch<'a> {
+    /// Constructs a color swatch editing `color`.
+    pub fn new(color: &'a mut Color32) -> Self {
+        Self {
+            color,
+            alpha: false,
+            size: None,
+        }
+    }
+
+    /// Sets whether the color picker allows editing alpha. Default: `false`.
+    #[must_use]
+    pub fn alpha(mut self, alpha: bool) -> Self {
+        self.alpha = alpha;
+        self
+    }
+
+    /// Sets the size of the swatch. Default: square with side length equal to
+    /// [`egui::style::Spacing::interact_size`]`.y`.
+    #[must_use]
+    pub fn size(mut self, size: impl Into<egui::Vec2>) -> Self {
+        self.size = Some(size.into());
+        self
+    }
+}
+
+impl egui::Widget for ColorSwatch<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let size = self
+            .size
+            .unwrap_or(egui::Vec2::splat(ui.spacing().interact_size.y));
+        let (rect, mut r) = ui.allocate_exact_size(size, egui::Sense::click());
+        let popup_id = r.id.with("popup");
+        let was_open = Popup::is_id_open(ui.ctx(), popup_id);
+
+        if ui.is_rect_visible(rect) {
+            paint_swatch(ui, &r, rect, *self.color, was_open);
+        }
+
+        let alpha = if self.alpha {
+            Alpha::OnlyBlend
+        } else {
+            Alpha::Opaque
+        };
+        Popup::menu(&r)
+            .id(popup_id)
+            .close_behavior(PopupCloseBehavior::CloseOnClickOutside)
+            .show(|ui| {
+                if color_picker_color32(ui, self.color, alpha) {
+                    r.mark_changed();
+                }
+                let recent = recent_colors(ui.ctx());
+                if !recent.is_empty() {
+                    ui.separator();
+                    if let Some(c) = color_strip(ui, &recent, Some(*self.color)) {
+                        *self.color = c;
+                        r.mark_changed();
+                    }
+                }
+            });
+
+        // Remember the color when the popup was opened, and add the new color
+        // to the recently used colors when it is closed.
+        let is_open = Popup::is_id_open(ui.ctx(), popup_id);
+        if is_open && !was_open {
+            ui.data_mut(|data| data.insert_temp(popup_id, *self.color));
+        } else if was_open && !is_open {
+            let initial_color = ui.data_mut(|data| data.remove_temp::<Color32>(popup_id));
+            if initial_color != Some(*self.color) {
+                add_recent_color(ui.ctx(), *self.color);
+            }
+        }
+
+        r
+    }
+}
+
+/// Strip of predefined colors to choose from.
+///
+/// Choosing a color adds it to the list of [recently used
+/// colors](recent_colors).
+pub struct PaletteStrip<'a> {
+    color: &'a mut Color32,
+    palette: &'a [Color32],
+    show_recent: bool,
+}
+
+impl<'a> PaletteStrip<'a> {
+    /// Constructs a palette strip editing `color`.
+    pub fn new(color: &'a mut Color32, palette: &'a [Color32]) -> Self {
+        Self {
+            color,
+            palette,
+            show_recent: false,
+        }
+    }
+
+    /// Sets whether to also show recently used colors after the palette.
+    /// Default: `false`.
+    #[must_use]
+    pub fn show_recent(mut self, show_recent: bool) -> Self {
+        self.show_recent = show_recent;
+        self
+    }
+}
+
+impl egui::Widget for PaletteStrip<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let egui::InnerResponse {
+            inner: chosen,
+            response: mut r,
+        } = ui.horizontal_wrapped(|ui| {
+            let mut chosen = color_strip(ui, self.palette, Some(*self.color));
+            if self.show_recent {
+                let recent = recent_colors(ui.ctx());
+                if !recent.is_empty() {
+                    ui.separator();
+                    chosen = color_strip(ui, &recent, Some(*self.color)).or(chosen);
+                }
+            }
+            chosen
+        });
+
+        if let Some(c) = chosen {
+            *self.color = c;
+            add_recent_color(ui.ctx(), c);
+            r.mark_changed();
+        }
+        r
+    }
+}
+
+/// Returns the list of recently used colors, most recent first.
+pub fn recent_colors(ctx: &egui::Context) -> Vec<Color32> {
+    ctx.data(|data| data.get_temp(recent_colors_id()))
+        .unwrap_or_default()
+}
+
+/// Adds a color to the front of the list of recently used colors, removing any
+/// duplicates and limiting the list to [`MAX_RECENT_COLORS`].
+pub fn add_recent_color(ctx: &egui::Context, color: Color32) {
+    ctx.data_mut(|data| {
+        let recent = data.get_temp_mut_or_default::<Vec<Color32>>(recent_colors_id());
+        recent.retain(|&c| c != color);
+        recent.insert(0, color);
+        recent.truncate(MAX_RECENT_COLORS);
+    });
+}
+
+fn recent_colors_id() -> egui::Id {
+    egui::Id::new("hcegui::util::recent_colors")
+}
+
+/// Displays a clickable swatch for each color and returns the one that was
+/// clicked, if any.
+fn color_strip(
+    ui: &mut egui::Ui,
+    colors: &[Color32],
+    selected: Option<Color32>,
+) -> Option<Color32> {
+    let size = egui::Vec2::splat(ui.spacing().interact_size.y);
+    let mut clicked = None;
+    for &c in colors {
+        let (rect, r) = ui.allocate_exact_size(size, egui::Sense::click());
+        if ui.is_rect_visible(rect) {
+            paint_swatch(ui, &r, rect, c, selected == Some(c));
+        }
+        if r.clicked() {
+            clicked = Some(c);
+        }
+    }
+    clicked
+}
+
+fn paint_swatch(
+    ui: &egui::Ui,
+    r: &egui::Response,
+    rect: egui::Rect,
+    color: Color32,
+    highlighted: bool,
+) {
+    let visuals = if highlighted {
+        &ui.visuals().widgets.open
+    } else {
+        ui.style().interact(r)
+    };
+    let rect = rect.expand(visuals.expansion);
+    let stroke_width = 1.0;
+    show_color_at(ui.painter(), color, rect.shrink(stroke_width));
+    let stroke = if highlighted || r.hovered() {
+        visuals.fg_stroke
+    } else {
+        egui::Stroke::new(stroke_width, visuals.bg_fill)
+    };
+    ui.painter()
+        .rect_stroke(rect, 2.0, stroke, egui::StrokeKind::Inside);
+}
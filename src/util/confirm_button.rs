@@ -0,0 +1,114 @@
+use std::time::Duration;
+
+/// Button for destructive actions that must be clicked twice.
+///
+/// On the first click, the button changes to display confirmation text (such
+/// as "Really delete?") with a fill that shrinks as time runs out. Clicking it
+/// again before the timeout reports [`egui::Response::clicked()`]. Clicking
+/// anywhere else or waiting for the timeout resets the button.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use hcegui::util::ConfirmButton;
+///
+/// if ui.add(ConfirmButton::new("🗑 Delete").confirm_text("Really delete?")).clicked() {
+///     // delete the thing
+/// }
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+pub struct ConfirmButton {
+    text: egui::WidgetText,
+    confirm_text: egui::WidgetText,
+    timeout: Duration,
+    id_salt: Option<egui::Id>,
+}
+
+impl ConfirmButton {
+    /// Constructs a confirm button.
+    pub fn new(text: impl Into<egui::WidgetText>) -> Self {
+        Self {
+            text: text.into(),
+            confirm_text: "Confirm?".into(),
+            timeout: Duration::from_secs(3),
+            id_salt: None,
+        }
+    }
+
+    /// Sets the text to display while waiting for confirmation. Default:
+    /// `"Confirm?"`.
+    #[must_use]
+    pub fn confirm_text(mut self, confirm_text: impl Into<egui::WidgetText>) -> Self {
+        self.confirm_text = confirm_text.into();
+        self
+    }
+
+    /// Sets how long to wait for confirmation before resetting. Default: 3
+    /// seconds.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets an ID salt used to store the confirmation state. By default, the
+    /// button's auto ID is used, which may not be stable if widgets before it
+    /// appear or disappear.
+    #[must_use]
+    pub fn id_salt(mut self, id_salt: impl std::hash::Hash) -> Self {
+        self.id_salt = Some(egui::Id::new(id_salt));
+        self
+    }
+}
+
+impl egui::Widget for ConfirmButton {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let id = match self.id_salt {
+            Some(id_salt) => ui.make_persistent_id(id_salt),
+            None => ui.next_auto_id(),
+        }
+        .with("confirm_button");
+
+        let now = ui.input(|input| input.time);
+        let timeout = self.timeout.as_secs_f64();
+        let armed_at = ui
+            .data(|data| data.get_temp::<f64>(id))
+            .filter(|&t| now - t < timeout);
+
+        let button = match armed_at {
+            Some(_) => {
+                let color = ui.visuals().error_fg_color;
+                egui::Button::new(self.confirm_text.color(color))
+            }
+            None => egui::Button::new(self.text),
+        };
+        let mut r = ui.add(button);
+
+        if r.clicked() {
+            if armed_at.is_some() {
+                ui.data_mut(|data| data.remove_temp::<f64>(id));
+            } else {
+                ui.data_mut(|data| data.insert_temp(id, now));
+                // Only report a click when confirmed.
+                r.flags.remove(egui::response::Flags::CLICKED);
+                r.flags.remove(egui::response::Flags::FAKE_PRIMARY_CLICKED);
+            }
+        } else if let Some(armed_at) = armed_at {
+            if r.clicked_elsewhere() {
+                ui.data_mut(|data| data.remove_temp::<f64>(id));
+            } else if ui.is_rect_visible(r.rect) {
+                let remaining = 1.0 - ((now - armed_at) / timeout) as f32;
+                let fill_rect = super::leading_part_of_rect(ui, r.rect, remaining);
+                let fill_color = ui.visuals().error_fg_color.gamma_multiply(0.15);
+                ui.painter().with_clip_rect(r.rect).rect_filled(
+                    fill_rect,
+                    ui.visuals().widgets.inactive.corner_radius,
+                    fill_color,
+                );
+                ui.ctx().request_repaint();
+            }
+        }
+
+        r
+    }
+}
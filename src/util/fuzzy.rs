@@ -0,0 +1,103 @@
+/// Result of [`fuzzy_match()`].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct FuzzyMatch {
+    /// How well the pattern matched. Higher is better.
+    pub score: i32,
+    /// Indices of the matched characters (not bytes) in the text.
+    pub indices: Vec<usize>,
+}
+
+/// Matches `pattern` against `text` case-insensitively, returning `None` if
+/// the characters of `pattern` do not all appear in `text` in order.
+///
+/// Matches at the start of words and runs of consecutive characters score
+/// higher, so `"op"` matches `"Open project"` better than `"Stop"`. An empty
+/// pattern matches everything with a score of zero.
+///
+/// ```
+/// use hcegui::util::fuzzy_match;
+///
+/// let m = fuzzy_match("opr", "Open project").unwrap();
+/// assert_eq!(m.indices, [0, 1, 6]);
+/// assert!(fuzzy_match("xyz", "Open project").is_none());
+/// ```
+pub fn fuzzy_match(pattern: &str, text: &str) -> Option<FuzzyMatch> {
+    const MATCH: i32 = 1;
+    const CONSECUTIVE_BONUS: i32 = 5;
+    const WORD_START_BONUS: i32 = 10;
+    const GAP_PENALTY: i32 = 1;
+    const MAX_GAP_PENALTY: i32 = 5;
+
+    let mut pattern = pattern
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .flat_map(char::to_lowercase)
+        .peekable();
+
+    let mut ret = FuzzyMatch::default();
+    let mut prev: Option<char> = None;
+    let mut last_match: Option<usize> = None;
+    for (i, c) in text.chars().enumerate() {
+        let Some(&p) = pattern.peek() else { break };
+        if c.to_lowercase().eq(std::iter::once(p)) {
+            pattern.next();
+            ret.score += MATCH;
+            let is_word_start = prev.is_none_or(|prev| {
+                !prev.is_alphanumeric() || (prev.is_lowercase() && c.is_uppercase())
+            });
+            if is_word_start {
+                ret.score += WORD_START_BONUS;
+            }
+            match last_match {
+                Some(j) if j + 1 == i => ret.score += CONSECUTIVE_BONUS,
+                Some(j) => ret.score -= ((i - j - 1) as i32 * GAP_PENALTY).min(MAX_GAP_PENALTY),
+                None => ret.score -= (i as i32 * GAP_PENALTY).min(MAX_GAP_PENALTY),
+            }
+            ret.indices.push(i);
+            last_match = Some(i);
+        }
+        prev = Some(c);
+    }
+
+    pattern.peek().is_none().then_some(ret)
+}
+
+/// Returns `text` as a layout job with the characters at `indices` highlighted,
+/// such as for showing the result of [`fuzzy_match()`].
+///
+/// Highlighted characters use [`egui::Visuals::strong_text_color()`] and are
+/// underlined.
+pub fn highlighted_text(ui: &egui::Ui, text: &str, indices: &[usize]) -> egui::text::LayoutJob {
+    let font_id = egui::TextStyle::Button.resolve(ui.style());
+    let normal = egui::TextFormat::simple(font_id, ui.visuals().text_color());
+    let highlighted = egui::TextFormat {
+        color: ui.visuals().strong_text_color(),
+        underline: egui::Stroke::new(1.0, ui.visuals().strong_text_color()),
+        ..normal.clone()
+    };
+
+    let mut job = egui::text::LayoutJob::default();
+    let mut indices = indices.iter().copied().peekable();
+    let mut start = 0;
+    let mut start_is_highlighted = false;
+    for (i, (byte_index, _)) in text.char_indices().enumerate() {
+        let is_highlighted = indices.next_if_eq(&i).is_some();
+        if is_highlighted != start_is_highlighted {
+            let format = if start_is_highlighted {
+                &highlighted
+            } else {
+                &normal
+            };
+            job.append(&text[start..byte_index], 0.0, format.clone());
+            start = byte_index;
+            start_is_highlighted = is_highlighted;
+        }
+    }
+    let format = if start_is_highlighted {
+        highlighted
+    } else {
+        normal
+    };
+    job.append(&text[start..], 0.0, format);
+    job
+}
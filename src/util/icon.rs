@@ -0,0 +1,151 @@
+/// Icon sized relative to the current text height.
+///
+/// An icon is either a glyph from a font (such as an icon font) or an image.
+#[derive(Debug, Clone)]
+pub struct Icon<'a> {
+    source: IconSource<'a>,
+    scale: f32,
+    color: Option<egui::Color32>,
+    text_style: Option<egui::TextStyle>,
+}
+
+#[derive(Debug, Clone)]
+enum IconSource<'a> {
+    Glyph { chr: char, family: egui::FontFamily },
+    Image(egui::ImageSource<'a>),
+}
+
+impl<'a> Icon<'a> {
+    /// Constructs an icon from a glyph in the proportional font family.
+    pub fn glyph(chr: char) -> Self {
+        Self::glyph_from_family(chr, egui::FontFamily::Proportional)
+    }
+
+    /// Constructs an icon from a glyph in a specific font family, such as an
+    /// icon font.
+    pub fn glyph_from_family(chr: char, family: egui::FontFamily) -> Self {
+        Self::new(IconSource::Glyph { chr, family })
+    }
+
+    /// Constructs an icon from an image.
+    pub fn image(source: impl Into<egui::ImageSource<'a>>) -> Self {
+        Self::new(IconSource::Image(source.into()))
+    }
+
+    /// Constructs an icon from a texture.
+    pub fn texture(texture_id: egui::TextureId) -> Self {
+        let sized_texture = egui::load::SizedTexture::new(texture_id, egui::Vec2::splat(1.0));
+        Self::new(IconSource::Image(egui::ImageSource::Texture(sized_texture)))
+    }
+
+    fn new(source: IconSource<'a>) -> Self {
+        Self {
+            source,
+            scale: 1.0,
+            color: None,
+            text_style: None,
+        }
+    }
+
+    /// Sets the size of the icon relative to the text height. Default: `1.0`.
+    #[must_use]
+    pub fn scale(mut self, scale: f32) -> Self {
+        self.scale = scale;
+        self
+    }
+
+    /// Overrides the color of the icon.
+    ///
+    /// By default, glyphs use the text color and images are not tinted.
+    #[must_use]
+    pub fn color(mut self, color: impl Into<egui::Color32>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+
+    /// Sets the text style used to determine the size of the icon.
+    ///
+    /// By default, [`icon_button()`] uses [`egui::TextStyle::Button`] and
+    /// everything else uses [`egui::TextStyle::Body`].
+    #[must_use]
+    pub fn text_style(mut self, text_style: egui::TextStyle) -> Self {
+        self.text_style = Some(text_style);
+        self
+    }
+
+    /// Returns the size of the icon in `ui`.
+    pub fn size(&self, ui: &egui::Ui) -> egui::Vec2 {
+        let text_style = self.text_style.as_ref().unwrap_or(&egui::TextStyle::Body);
+        egui::Vec2::splat(ui.text_style_height(text_style) * self.scale)
+    }
+
+    /// Paints the icon in `rect`.
+    ///
+    /// `text_color` is used for glyphs if no color has been set.
+    pub fn paint_at(&self, ui: &egui::Ui, rect: egui::Rect, text_color: egui::Color32) {
+        if !ui.is_rect_visible(rect) {
+            return;
+        }
+        match &self.source {
+            IconSource::Glyph { chr, family } => {
+                let font_id = egui::FontId::new(rect.height(), family.clone());
+                ui.painter().text(
+                    rect.center(),
+                    egui::Align2::CENTER_CENTER,
+                    chr,
+                    font_id,
+                    self.color.unwrap_or(text_color),
+                );
+            }
+            IconSource::Image(source) => {
+                let mut image = egui::Image::new(source.clone());
+                if let Some(color) = self.color {
+                    image = image.tint(color);
+                }
+                image.paint_at(ui, rect);
+            }
+        }
+    }
+}
+
+impl egui::Widget for Icon<'_> {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let (rect, r) = ui.allocate_exact_size(self.size(ui), egui::Sense::hover());
+        self.paint_at(ui, rect, ui.visuals().text_color());
+        r
+    }
+}
+
+/// Displays a label with an icon before it, vertically centered and spaced
+/// according to [`egui::style::Spacing::icon_spacing`].
+pub fn icon_label(
+    ui: &mut egui::Ui,
+    icon: Icon<'_>,
+    text: impl Into<egui::WidgetText>,
+) -> egui::Response {
+    let icon_id = ui.next_auto_id().with("icon");
+    let r =
+        egui::AtomLayout::new((egui::Atom::custom(icon_id, icon.size(ui)), text.into())).show(ui);
+    if let Some(icon_rect) = r.rect(icon_id) {
+        icon.paint_at(ui, icon_rect, ui.visuals().text_color());
+    }
+    r.response
+}
+
+/// Displays a button with an icon before its text, vertically centered and
+/// spaced according to [`egui::style::Spacing::icon_spacing`].
+pub fn icon_button(
+    ui: &mut egui::Ui,
+    mut icon: Icon<'_>,
+    text: impl Into<egui::WidgetText>,
+) -> egui::Response {
+    icon.text_style.get_or_insert(egui::TextStyle::Button);
+    let icon_id = ui.next_auto_id().with("icon");
+    let r =
+        egui::Button::new((egui::Atom::custom(icon_id, icon.size(ui)), text.into())).atom_ui(ui);
+    if let Some(icon_rect) = r.rect(icon_id) {
+        let text_color = ui.style().interact(&r.response).text_color();
+        icon.paint_at(ui, icon_rect, text_color);
+    }
+    r.response
+}
@@ -0,0 +1,41 @@
+/// Displays a table of key-value pairs, such as for a "properties" or
+/// "details" pane.
+///
+/// Keys are right-aligned. Values are selectable and can be copied using the
+/// context menu. Long values are truncated with the full text shown in a
+/// tooltip.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// hcegui::util::kv_table(ui, [("Name", "hcegui"), ("License", "MIT OR Apache-2.0")]);
+/// # });
+/// ```
+pub fn kv_table<K, V>(ui: &mut egui::Ui, pairs: impl IntoIterator<Item = (K, V)>) -> egui::Response
+where
+    K: Into<egui::WidgetText>,
+    V: Into<egui::WidgetText>,
+{
+    let id = ui.next_auto_id();
+    egui::Grid::new(id)
+        .num_columns(2)
+        .striped(false)
+        .show(ui, |ui| {
+            for (k, v) in pairs {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.add(egui::Label::new(k.into().weak()).selectable(false));
+                });
+
+                let v = v.into();
+                let text = v.text().to_owned();
+                let r = ui.add(egui::Label::new(v).truncate().selectable(true));
+                r.context_menu(|ui| {
+                    if ui.button("Copy").clicked() {
+                        ui.ctx().copy_text(text);
+                    }
+                });
+
+                ui.end_row();
+            }
+        })
+        .response
+}
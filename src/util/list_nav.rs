@@ -0,0 +1,184 @@
+use egui::{Key, Modifiers};
+
+/// How long to wait between typed characters before starting a new type-ahead
+/// search, in seconds.
+const TYPE_AHEAD_TIMEOUT: f64 = 1.0;
+
+/// Keyboard navigation for custom list widgets.
+///
+/// While the list has keyboard focus, this handles the following keys to move
+/// the highlighted index:
+///
+/// - <kbd>↑</kbd> / <kbd>↓</kbd> move by one item
+/// - <kbd>Home</kbd> / <kbd>End</kbd> move to the first/last item
+/// - <kbd>Page Up</kbd> / <kbd>Page Down</kbd> move by [`ListNav::page_size()`]
+///   items
+/// - Typing jumps to the next item whose text starts with the typed characters
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use hcegui::util::ListNav;
+///
+/// let items = ["apple", "banana", "cherry"];
+/// let mut highlighted = Some(0);
+///
+/// let r = ui.vertical(|ui| {
+///     for (i, item) in items.iter().enumerate() {
+///         let item_response = ui.selectable_label(highlighted == Some(i), *item);
+///         if item_response.clicked() {
+///             highlighted = Some(i);
+///         }
+///     }
+/// });
+/// let list_response = ui.interact(r.response.rect, ui.id().with("list"), egui::Sense::focusable_noninteractive());
+/// ListNav::new().show(ui, &list_response, &mut highlighted, items.len(), |i| items[i].to_string());
+/// # });
+/// ```
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ListNav {
+    page_size: usize,
+    type_ahead: bool,
+}
+
+impl Default for ListNav {
+    fn default() -> Self {
+        Self {
+            page_size: 10,
+            type_ahead: true,
+        }
+    }
+}
+
+impl ListNav {
+    /// Constructs a new keyboard navigation helper.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the number of items to move when pressing <kbd>Page Up</kbd> or
+    /// <kbd>Page Down</kbd>. Default: `10`.
+    #[must_use]
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// Sets whether typing jumps to matching items. Default: `true`.
+    #[must_use]
+    pub fn type_ahead(mut self, type_ahead: bool) -> Self {
+        self.type_ahead = type_ahead;
+        self
+    }
+
+    /// Handles keyboard input for a list with `len` items, if `list_response`
+    /// has keyboard focus. Returns whether `highlighted` changed.
+    ///
+    /// `item_text` returns the text of an item, which is used for type-ahead.
+    ///
+    /// When this returns `true`, call [`egui::Response::scroll_to_me()`] on the
+    /// response for the newly highlighted item to scroll it into view.
+    pub fn show(
+        self,
+        ui: &egui::Ui,
+        list_response: &egui::Response,
+        highlighted: &mut Option<usize>,
+        len: usize,
+        item_text: impl Fn(usize) -> String,
+    ) -> bool {
+        if !list_response.has_focus() || len == 0 {
+            return false;
+        }
+
+        let id = list_response.id;
+        ui.memory_mut(|mem| {
+            let event_filter = egui::EventFilter {
+                vertical_arrows: true,
+                ..Default::default()
+            };
+            mem.set_focus_lock_filter(id, event_filter);
+        });
+
+        let old = *highlighted;
+        let last = len - 1;
+        let current = highlighted.map(|i| i.min(last));
+        let page = self.page_size;
+
+        let new = ui.input_mut(|input| {
+            let mut pressed = |key| input.consume_key(Modifiers::NONE, key);
+            if pressed(Key::ArrowUp) {
+                Some(current.map_or(last, |i| i.saturating_sub(1)))
+            } else if pressed(Key::ArrowDown) {
+                Some(current.map_or(0, |i| (i + 1).min(last)))
+            } else if pressed(Key::Home) {
+                Some(0)
+            } else if pressed(Key::End) {
+                Some(last)
+            } else if pressed(Key::PageUp) {
+                Some(current.map_or(0, |i| i.saturating_sub(page)))
+            } else if pressed(Key::PageDown) {
+                Some(current.map_or(0, |i| (i + page).min(last)))
+            } else {
+                None
+            }
+        });
+
+        if let Some(new) = new {
+            *highlighted = Some(new);
+        } else if self.type_ahead
+            && let Some(new) = type_ahead(ui, id, current, len, item_text)
+        {
+            *highlighted = Some(new);
+        }
+
+        *highlighted != old
+    }
+}
+
+/// Reads typed text and returns the index of the first item at or after
+/// `current` whose text starts with everything typed recently.
+fn type_ahead(
+    ui: &egui::Ui,
+    id: egui::Id,
+    current: Option<usize>,
+    len: usize,
+    item_text: impl Fn(usize) -> String,
+) -> Option<usize> {
+    let typed: String = ui.input(|input| {
+        input
+            .events
+            .iter()
+            .filter_map(|event| match event {
+                egui::Event::Text(s) => Some(s.as_str()),
+                _ => None,
+            })
+            .collect()
+    });
+    if typed.is_empty() {
+        return None;
+    }
+
+    let now = ui.input(|input| input.time);
+    let state_id = id.with("type_ahead");
+    let (query, is_new_query) = ui.data_mut(|data| {
+        let (query, time) = data.get_temp_mut_or_default::<(String, f64)>(state_id);
+        if now - *time >= TYPE_AHEAD_TIMEOUT {
+            query.clear();
+        }
+        let is_new_query = query.is_empty();
+        query.push_str(&typed.to_lowercase());
+        *time = now;
+        (query.clone(), is_new_query)
+    });
+
+    // When starting a new search, skip the current item so that typing the
+    // same letter repeatedly cycles through matching items.
+    let start = match current {
+        Some(i) if is_new_query => i + 1,
+        Some(i) => i,
+        None => 0,
+    };
+    (start..len)
+        .chain(0..start)
+        .find(|&i| item_text(i).to_lowercase().starts_with(&query))
+}
@@ -0,0 +1,85 @@
+use egui::emath::GuiRounding;
+
+/// Snaps a point to the nearest physical pixel corner.
+///
+/// Use [`egui::Painter::pixels_per_point()`] or
+/// [`egui::Context::pixels_per_point()`] for `pixels_per_point`.
+pub fn snap_pos(pos: egui::Pos2, pixels_per_point: f32) -> egui::Pos2 {
+    pos.round_to_pixels(pixels_per_point)
+}
+
+/// Snaps the corners of a rectangle to the nearest physical pixel corners, so
+/// that a filled rectangle has crisp edges.
+pub fn snap_rect(rect: egui::Rect, pixels_per_point: f32) -> egui::Rect {
+    rect.round_to_pixels(pixels_per_point)
+}
+
+/// Returns whether a stroke of `stroke_width` points covers an odd number of
+/// physical pixels.
+///
+/// ```
+/// use hcegui::util::is_odd_pixel_width;
+///
+/// assert!(is_odd_pixel_width(1.0, 1.0));
+/// assert!(!is_odd_pixel_width(2.0, 1.0));
+/// // At 1.5 pixels per point, 1 point is 2 pixels and 2 points are 3 pixels.
+/// assert!(!is_odd_pixel_width(1.0, 1.5));
+/// assert!(is_odd_pixel_width(2.0, 1.5));
+/// ```
+pub fn is_odd_pixel_width(stroke_width: f32, pixels_per_point: f32) -> bool {
+    (stroke_width * pixels_per_point).round() as i64 % 2 == 1
+}
+
+/// Snaps a coordinate on the center line of a stroke so that the edges of the
+/// stroke land on physical pixel boundaries.
+///
+/// Strokes that are an odd number of physical pixels wide are centered on a
+/// pixel center (0.5 offset); strokes that are an even number of pixels wide
+/// are centered on a pixel boundary.
+///
+/// ```
+/// use hcegui::util::snap_stroke_coord;
+///
+/// assert_eq!(snap_stroke_coord(10.2, 1.0, 1.0), 10.5);
+/// assert_eq!(snap_stroke_coord(10.2, 2.0, 1.0), 10.0);
+///
+/// // At 1.5 pixels per point, 10.2 points is 15.3 pixels.
+/// assert_eq!(snap_stroke_coord(10.2, 1.0, 1.5), 15.0 / 1.5); // 2 pixels wide
+/// assert_eq!(snap_stroke_coord(10.2, 2.0, 1.5), 15.5 / 1.5); // 3 pixels wide
+/// ```
+pub fn snap_stroke_coord(coord: f32, stroke_width: f32, pixels_per_point: f32) -> f32 {
+    if is_odd_pixel_width(stroke_width, pixels_per_point) {
+        coord.round_to_pixel_center(pixels_per_point)
+    } else {
+        coord.round_to_pixels(pixels_per_point)
+    }
+}
+
+/// Snaps a point on the center line of a stroke. See [`snap_stroke_coord()`].
+pub fn snap_stroke_pos(pos: egui::Pos2, stroke_width: f32, pixels_per_point: f32) -> egui::Pos2 {
+    egui::pos2(
+        snap_stroke_coord(pos.x, stroke_width, pixels_per_point),
+        snap_stroke_coord(pos.y, stroke_width, pixels_per_point),
+    )
+}
+
+/// Snaps the endpoints of a line segment so that it renders crisply. See
+/// [`snap_stroke_coord()`].
+///
+/// This is mainly useful for horizontal and vertical lines.
+pub fn snap_line_segment(
+    points: [egui::Pos2; 2],
+    stroke_width: f32,
+    pixels_per_point: f32,
+) -> [egui::Pos2; 2] {
+    points.map(|p| snap_stroke_pos(p, stroke_width, pixels_per_point))
+}
+
+/// Snaps a rectangle outlined using [`egui::StrokeKind::Middle`] so that the
+/// outline renders crisply. See [`snap_stroke_coord()`].
+pub fn snap_stroke_rect(rect: egui::Rect, stroke_width: f32, pixels_per_point: f32) -> egui::Rect {
+    egui::Rect::from_min_max(
+        snap_stroke_pos(rect.min, stroke_width, pixels_per_point),
+        snap_stroke_pos(rect.max, stroke_width, pixels_per_point),
+    )
+}
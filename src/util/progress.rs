@@ -0,0 +1,180 @@
+use std::time::Duration;
+
+/// Progress indicator that fills the available width.
+///
+/// Unlike [`egui::ProgressBar`], this supports an indeterminate mode that
+/// displays an animated sweep when the amount of progress is unknown. With
+/// [reduced motion](crate::anim::set_reduced_motion), the sweep is replaced by
+/// a static, partially transparent fill.
+#[derive(Debug, Clone)]
+pub struct Progress {
+    fraction: Option<f32>,
+    text: Option<egui::WidgetText>,
+    eta: Option<Duration>,
+    show_percentage: bool,
+    desired_height: Option<f32>,
+}
+
+impl Progress {
+    /// Constructs a determinate progress indicator. `fraction` is clamped to the
+    /// range `0.0..=1.0`.
+    pub fn new(fraction: f32) -> Self {
+        Self {
+            fraction: Some(fraction.clamp(0.0, 1.0)),
+            ..Self::indeterminate()
+        }
+    }
+
+    /// Constructs an indeterminate progress indicator.
+    pub fn indeterminate() -> Self {
+        Self {
+            fraction: None,
+            text: None,
+            eta: None,
+            show_percentage: false,
+            desired_height: None,
+        }
+    }
+
+    /// Sets text to display on the progress indicator.
+    #[must_use]
+    pub fn text(mut self, text: impl Into<egui::WidgetText>) -> Self {
+        self.text = Some(text.into());
+        self
+    }
+
+    /// Displays the percentage of progress. Ignored for indeterminate
+    /// progress.
+    #[must_use]
+    pub fn show_percentage(mut self) -> Self {
+        self.show_percentage = true;
+        self
+    }
+
+    /// Displays an estimate of the remaining time, formatted using
+    /// [`format_eta()`].
+    #[must_use]
+    pub fn eta(mut self, eta: Duration) -> Self {
+        self.eta = Some(eta);
+        self
+    }
+
+    /// Sets the height of the progress indicator. Default:
+    /// [`egui::style::Spacing::interact_size`]`.y`.
+    #[must_use]
+    pub fn desired_height(mut self, desired_height: f32) -> Self {
+        self.desired_height = Some(desired_height);
+        self
+    }
+
+    fn full_text(&self) -> Option<String> {
+        use std::fmt::Write;
+
+        let mut s = String::new();
+        let separate = |s: &mut String| {
+            if !s.is_empty() {
+                s.push_str(" · ");
+            }
+        };
+        if let Some(text) = &self.text {
+            s.push_str(text.text());
+        }
+        if self.show_percentage
+            && let Some(fraction) = self.fraction
+        {
+            separate(&mut s);
+            let _ = write!(s, "{}%", (fraction * 100.0).floor());
+        }
+        if let Some(eta) = self.eta {
+            separate(&mut s);
+            let _ = write!(s, "{} remaining", format_eta(eta));
+        }
+        (!s.is_empty()).then_some(s)
+    }
+}
+
+impl egui::Widget for Progress {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        let height = self.desired_height.unwrap_or(ui.spacing().interact_size.y);
+        let size = egui::vec2(ui.available_size_before_wrap().x, height);
+        let (rect, r) = ui.allocate_exact_size(size, egui::Sense::hover());
+        let text = self.full_text();
+        let label = text.as_deref().unwrap_or("Progress");
+        crate::a11y::describe(&r, egui::WidgetType::ProgressIndicator, label);
+
+        if ui.is_rect_visible(rect) {
+            let visuals = ui.visuals();
+            let corner_radius = rect.height() / 2.0;
+            ui.painter()
+                .rect_filled(rect, corner_radius, visuals.extreme_bg_color);
+
+            let mut fill_color = visuals.selection.bg_fill;
+            let fill_rect = match self.fraction {
+                Some(fraction) => super::leading_part_of_rect(ui, rect, fraction),
+                None if crate::anim::reduced_motion(ui.ctx()) => {
+                    fill_color = fill_color.gamma_multiply(0.5);
+                    rect
+                }
+                None => {
+                    ui.ctx().request_repaint();
+                    let t = ui.input(|input| input.time);
+                    let [mut start, mut end] = indeterminate_sweep(t);
+                    if ui.layout().prefer_right_to_left() {
+                        [start, end] = [1.0 - end, 1.0 - start];
+                    }
+                    egui::Rect::from_x_y_ranges(
+                        rect.lerp_inside(egui::vec2(start, 0.0)).x
+                            ..=rect.lerp_inside(egui::vec2(end, 0.0)).x,
+                        rect.y_range(),
+                    )
+                }
+            };
+            if fill_rect.width() > 0.0 {
+                ui.painter()
+                    .with_clip_rect(rect)
+                    .rect_filled(fill_rect, corner_radius, fill_color);
+            }
+
+            if let Some(text) = text {
+                let galley = egui::WidgetText::from(text).into_galley(
+                    ui,
+                    Some(egui::TextWrapMode::Truncate),
+                    rect.width() - ui.spacing().button_padding.x * 2.0,
+                    egui::TextStyle::Button,
+                );
+                let text_pos = rect.center() - galley.size() / 2.0;
+                ui.painter()
+                    .galley(text_pos, galley, visuals.strong_text_color());
+            }
+        }
+
+        r
+    }
+}
+
+/// Returns the start and end of the indeterminate sweep at time `t` (in
+/// seconds), as fractions of the total width.
+fn indeterminate_sweep(t: f64) -> [f32; 2] {
+    const PERIOD: f64 = 1.5;
+    const SWEEP_WIDTH: f32 = 0.35;
+
+    let phase = (t / PERIOD).fract() as f32;
+    // Ease in and out so that the sweep slows down near the edges.
+    let eased = egui::emath::easing::cubic_in_out(phase);
+    let center = egui::lerp(-SWEEP_WIDTH..=1.0 + SWEEP_WIDTH, eased);
+    [center - SWEEP_WIDTH / 2.0, center + SWEEP_WIDTH / 2.0]
+}
+
+/// Formats an estimated remaining time compactly, such as `"45s"`, `"3m 20s"`,
+/// or `"2h 05m"`.
+pub fn format_eta(eta: Duration) -> String {
+    let total_secs = eta.as_secs();
+    let (h, m, s) = (total_secs / 3600, total_secs / 60 % 60, total_secs % 60);
+    if h > 0 {
+        format!("{h}h {m:02}m")
+    } else if m > 0 {
+        format!("{m}m {s:02}s")
+    } else {
+        format!("{s}s")
+    }
+}
@@ -0,0 +1,199 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Snapshot of the positions, sizes, and open state of windows and resizable
+/// panels.
+///
+/// Windows and panels are identified by name. Use [`SavedLayout::show_window()`]
+/// and [`SavedLayout::show_panel_inside()`] to display them; these apply the
+/// saved layout the first time each one is shown and update the snapshot every
+/// frame afterwards.
+///
+/// With the `persistence` feature, the layout can be saved to and loaded from
+/// [`eframe::Storage`] using [`SavedLayout::save()`] and [`SavedLayout::load()`].
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedLayout {
+    /// Saved state of each window, keyed by name.
+    pub windows: BTreeMap<String, SavedWindow>,
+    /// Saved state of each panel, keyed by name.
+    pub panels: BTreeMap<String, SavedPanel>,
+
+    /// Names of windows and panels whose saved state has already been applied.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    applied: BTreeSet<String>,
+}
+
+/// Layouts are equal if their windows and panels are equal, regardless of which
+/// ones have been shown.
+impl PartialEq for SavedLayout {
+    fn eq(&self, other: &Self) -> bool {
+        self.windows == other.windows && self.panels == other.panels
+    }
+}
+
+/// Saved state of a single [`egui::Window`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedWindow {
+    /// Position of the top left corner of the window.
+    pub pos: egui::Pos2,
+    /// Size of the window contents.
+    pub size: egui::Vec2,
+    /// Whether the window is open.
+    pub open: bool,
+    /// Whether the window is collapsed to its title bar.
+    pub collapsed: bool,
+}
+
+/// Saved state of a single [`egui::Panel`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SavedPanel {
+    /// Rectangle occupied by the panel.
+    pub rect: egui::Rect,
+}
+
+impl SavedLayout {
+    /// Constructs an empty layout.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Loads a layout from `storage`, or returns an empty layout if there is
+    /// none. **Requires `persistence` feature.**
+    ///
+    /// This is typically called from the [`eframe::App`] constructor using
+    /// [`eframe::CreationContext::storage`].
+    #[cfg(feature = "persistence")]
+    pub fn load(storage: Option<&dyn eframe::Storage>, key: &str) -> Self {
+        storage
+            .and_then(|storage| eframe::get_value(storage, key))
+            .unwrap_or_default()
+    }
+
+    /// Saves the layout to `storage`. **Requires `persistence` feature.**
+    ///
+    /// This is typically called from [`eframe::App::save()`].
+    #[cfg(feature = "persistence")]
+    pub fn save(&self, storage: &mut dyn eframe::Storage, key: &str) {
+        eframe::set_value(storage, key, self);
+    }
+
+    /// Shrinks and moves every saved window and panel so that it fits inside
+    /// `screen_rect`.
+    ///
+    /// Call this after loading a layout, in case it was saved on a larger
+    /// screen.
+    pub fn clamp_to_screen(&mut self, screen_rect: egui::Rect) {
+        for window in self.windows.values_mut() {
+            window.size = window.size.min(screen_rect.size());
+            window.pos = clamp_min_to_screen(window.pos, window.size, screen_rect);
+        }
+        for panel in self.panels.values_mut() {
+            let size = panel.rect.size().min(screen_rect.size());
+            let min = clamp_min_to_screen(panel.rect.min, size, screen_rect);
+            panel.rect = egui::Rect::from_min_size(min, size);
+        }
+    }
+
+    /// Returns whether the window `name` was open when the layout was saved,
+    /// or `None` if there is no saved state for it.
+    pub fn window_open(&self, name: &str) -> Option<bool> {
+        Some(self.windows.get(name)?.open)
+    }
+
+    /// Shows a window, applying the saved layout the first time it is shown and
+    /// updating the saved layout afterwards.
+    ///
+    /// If there is saved state for the window, `open` is overwritten the first
+    /// time this is called.
+    pub fn show_window<R>(
+        &mut self,
+        ctx: &egui::Context,
+        name: &str,
+        open: &mut bool,
+        mut window: egui::Window<'_>,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> Option<egui::InnerResponse<Option<R>>> {
+        if self.applied.insert(name.to_owned())
+            && let Some(saved) = self.windows.get(name)
+        {
+            *open = saved.open;
+            window = window
+                .default_pos(saved.pos)
+                .default_size(saved.size)
+                .default_open(!saved.collapsed);
+        }
+
+        let mut content_size = None;
+        let r = window.open(open).show(ctx, |ui| {
+            content_size = Some(ui.max_rect().size());
+            add_contents(ui)
+        });
+
+        match &r {
+            Some(r) => {
+                let saved = self.windows.entry(name.to_owned()).or_insert(SavedWindow {
+                    pos: r.response.rect.min,
+                    size: r.response.rect.size(),
+                    open: true,
+                    collapsed: false,
+                });
+                let collapsing_id = r.response.id.with("collapsing");
+                saved.pos = r.response.rect.min;
+                saved.size = content_size.unwrap_or(saved.size);
+                saved.open = *open;
+                saved.collapsed =
+                    egui::collapsing_header::CollapsingState::load(ctx, collapsing_id)
+                        .is_some_and(|state| !state.is_open());
+            }
+            None => {
+                if let Some(saved) = self.windows.get_mut(name) {
+                    saved.open = *open;
+                }
+            }
+        }
+
+        r
+    }
+
+    /// Shows a panel inside `ui`, applying the saved layout the first time it is
+    /// shown and updating the saved layout afterwards.
+    ///
+    /// `name` must be the same ID that was used to construct `panel`.
+    ///
+    /// **Requires `egui_0_34` feature.**
+    #[cfg(feature = "egui_0_34")]
+    pub fn show_panel_inside<R>(
+        &mut self,
+        ui: &mut egui::Ui,
+        name: &str,
+        panel: egui::Panel,
+        add_contents: impl FnOnce(&mut egui::Ui) -> R,
+    ) -> egui::InnerResponse<R> {
+        let id = egui::Id::new(name);
+
+        if self.applied.insert(name.to_owned())
+            && let Some(saved) = self.panels.get(name)
+        {
+            let state = egui::containers::panel::PanelState { rect: saved.rect };
+            ui.data_mut(|data| data.insert_persisted(id, state));
+        }
+
+        let r = panel.show_inside(ui, add_contents);
+
+        if let Some(state) = egui::containers::panel::PanelState::load(ui.ctx(), id) {
+            self.panels
+                .insert(name.to_owned(), SavedPanel { rect: state.rect });
+        }
+
+        r
+    }
+}
+
+/// Returns the top left corner of a rectangle of size `size` that is as close as
+/// possible to `pos` while fitting inside `screen_rect`.
+fn clamp_min_to_screen(pos: egui::Pos2, size: egui::Vec2, screen_rect: egui::Rect) -> egui::Pos2 {
+    let max = (screen_rect.max - size).max(screen_rect.min);
+    pos.clamp(screen_rect.min, max)
+}
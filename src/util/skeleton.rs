@@ -0,0 +1,188 @@
+/// Placeholder to display while content is loading.
+///
+/// Compose a skeleton that matches the layout of the eventual content to
+/// reduce layout jump when the content arrives. Each shape is painted with a
+/// shimmer effect that sweeps across the UI.
+///
+/// ```
+/// # egui::__run_test_ui(|ui| {
+/// use hcegui::util::Skeleton;
+///
+/// ui.add(
+///     Skeleton::row()
+///         .avatar(32.0)
+///         .child(Skeleton::new().text_line(0.4).text_lines(2)),
+/// );
+/// # });
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct Skeleton {
+    items: Vec<SkeletonItem>,
+    horizontal: bool,
+}
+
+#[derive(Debug, Clone)]
+enum SkeletonItem {
+    /// Line of text, with width as a fraction of the available width.
+    TextLine(f32),
+    /// Circle with the given diameter.
+    Avatar(f32),
+    /// Rectangle with the given size.
+    Block(egui::Vec2),
+    /// Full-width rectangle with the given height.
+    Card(f32),
+    /// Nested skeleton.
+    Child(Skeleton),
+}
+
+impl Skeleton {
+    /// Constructs an empty skeleton that lays out its items vertically.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Constructs an empty skeleton that lays out its items horizontally.
+    pub fn row() -> Self {
+        Self {
+            horizontal: true,
+            ..Self::default()
+        }
+    }
+
+    /// Adds a single line of text, with width as a fraction of the available
+    /// width.
+    #[must_use]
+    pub fn text_line(mut self, width_fraction: f32) -> Self {
+        self.items.push(SkeletonItem::TextLine(width_fraction));
+        self
+    }
+
+    /// Adds a paragraph of `n` lines of text, with the last line shorter than
+    /// the others.
+    #[must_use]
+    pub fn text_lines(mut self, n: usize) -> Self {
+        for i in 0..n {
+            let width_fraction = if i + 1 == n && n > 1 { 0.6 } else { 1.0 };
+            self = self.text_line(width_fraction);
+        }
+        self
+    }
+
+    /// Adds a circle, such as a profile picture.
+    #[must_use]
+    pub fn avatar(mut self, diameter: f32) -> Self {
+        self.items.push(SkeletonItem::Avatar(diameter));
+        self
+    }
+
+    /// Adds a rectangle of a fixed size, such as an image or button.
+    #[must_use]
+    pub fn block(mut self, size: impl Into<egui::Vec2>) -> Self {
+        self.items.push(SkeletonItem::Block(size.into()));
+        self
+    }
+
+    /// Adds a full-width rectangle with rounded corners.
+    #[must_use]
+    pub fn card(mut self, height: f32) -> Self {
+        self.items.push(SkeletonItem::Card(height));
+        self
+    }
+
+    /// Adds a nested skeleton.
+    #[must_use]
+    pub fn child(mut self, child: Skeleton) -> Self {
+        self.items.push(SkeletonItem::Child(child));
+        self
+    }
+
+    fn show(self, ui: &mut egui::Ui) {
+        for item in self.items {
+            match item {
+                SkeletonItem::TextLine(width_fraction) => {
+                    let height = ui.text_style_height(&egui::TextStyle::Body);
+                    let width = ui.available_width() * width_fraction.clamp(0.0, 1.0);
+                    let (rect, _) =
+                        ui.allocate_exact_size(egui::vec2(width, height), egui::Sense::hover());
+                    // Text is shorter than its row height.
+                    let rect = rect.shrink2(egui::vec2(0.0, height * 0.15));
+                    paint_shimmer_rect(ui, rect, rect.height() / 2.0);
+                }
+                SkeletonItem::Avatar(diameter) => {
+                    let size = egui::Vec2::splat(diameter);
+                    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                    paint_shimmer_rect(ui, rect, diameter / 2.0);
+                }
+                SkeletonItem::Block(size) => {
+                    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                    paint_shimmer_rect(ui, rect, ui.visuals().widgets.inactive.corner_radius);
+                }
+                SkeletonItem::Card(height) => {
+                    let size = egui::vec2(ui.available_width(), height);
+                    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+                    paint_shimmer_rect(ui, rect, ui.visuals().window_corner_radius);
+                }
+                SkeletonItem::Child(child) => {
+                    ui.add(child);
+                }
+            }
+        }
+    }
+}
+
+impl egui::Widget for Skeleton {
+    fn ui(self, ui: &mut egui::Ui) -> egui::Response {
+        if self.horizontal {
+            ui.horizontal_top(|ui| self.show(ui)).response
+        } else {
+            ui.vertical(|ui| self.show(ui)).response
+        }
+    }
+}
+
+/// Paints a rounded rectangle with a shimmer effect, for use in loading
+/// placeholders.
+///
+/// The shimmer sweeps horizontally across the clip rectangle of `ui`, so
+/// multiple shapes painted in the same UI shimmer together. With [reduced
+/// motion](crate::anim::set_reduced_motion), the rectangle is painted without
+/// the shimmer.
+pub fn paint_shimmer_rect(
+    ui: &egui::Ui,
+    rect: egui::Rect,
+    corner_radius: impl Into<egui::CornerRadius>,
+) {
+    if !ui.is_rect_visible(rect) {
+        return;
+    }
+
+    const PERIOD: f64 = 1.5;
+
+    let base_color = ui.visuals().widgets.inactive.bg_fill;
+    if crate::anim::reduced_motion(ui.ctx()) {
+        ui.painter().rect_filled(rect, corner_radius, base_color);
+        return;
+    }
+
+    ui.ctx().request_repaint();
+    let t = ui.input(|input| input.time);
+    let phase = (t / PERIOD).fract() as f32;
+
+    // Sweep from one sweep-width beyond the left edge to one sweep-width beyond
+    // the right edge.
+    let clip_rect = ui.clip_rect();
+    let sweep_width = clip_rect.width() / 3.0;
+    let sweep_x = egui::lerp(
+        clip_rect.left() - sweep_width..=clip_rect.right() + sweep_width,
+        phase,
+    );
+    let distance = ((rect.center().x - sweep_x) / sweep_width).abs();
+    let highlight = (1.0 - distance).clamp(0.0, 1.0);
+
+    let highlight_color = ui.visuals().widgets.hovered.bg_fill;
+    let color = base_color.lerp_to_gamma(
+        highlight_color,
+        egui::emath::easing::quadratic_in_out(highlight),
+    );
+    ui.painter().rect_filled(rect, corner_radius, color);
+}
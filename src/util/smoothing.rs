@@ -0,0 +1,114 @@
+/// Difference from the target below which [`smooth_toward()`] snaps to the
+/// target and stops requesting repaints.
+const SNAP_EPSILON: f32 = 1e-4;
+
+/// Moves `current` toward `target` using a critically damped spring, which
+/// never overshoots. Returns the new value.
+///
+/// `velocity` is the current rate of change, which should be stored between
+/// frames and initialized to zero. `smoothing_time` is approximately the time
+/// (in seconds) to reach the target. `dt` is the time since the last update,
+/// such as [`egui::InputState::stable_dt`].
+///
+/// This is frame-rate independent, so the motion is the same regardless of how
+/// often it is called.
+///
+/// ```
+/// use hcegui::util::smooth_damp;
+///
+/// // Returns the value after 1 second, and the largest value along the way.
+/// let simulate = |dt: f32| {
+///     let (mut value, mut velocity, mut max) = (0.0, 0.0, 0.0_f32);
+///     for _ in 0..(1.0 / dt).round() as usize {
+///         value = smooth_damp(value, 1.0, &mut velocity, 0.3, dt);
+///         max = max.max(value);
+///     }
+///     (value, max)
+/// };
+///
+/// let (at_30_fps, max_at_30_fps) = simulate(1.0 / 30.0);
+/// let (at_144_fps, max_at_144_fps) = simulate(1.0 / 144.0);
+/// assert!(max_at_30_fps <= 1.0 && max_at_144_fps <= 1.0);
+/// assert!((at_30_fps - at_144_fps).abs() < 0.01);
+/// assert!(at_30_fps > 0.9);
+/// ```
+pub fn smooth_damp(
+    current: f32,
+    target: f32,
+    velocity: &mut f32,
+    smoothing_time: f32,
+    dt: f32,
+) -> f32 {
+    if dt <= 0.0 {
+        return current;
+    }
+
+    // Based on Game Programming Gems 4, chapter 1.10.
+    let omega = 2.0 / smoothing_time.max(0.0001);
+    let x = omega * dt;
+    let decay = 1.0 / (1.0 + x + 0.48 * x * x + 0.235 * x * x * x);
+    let change = current - target;
+    let temp = (*velocity + omega * change) * dt;
+    *velocity = (*velocity - omega * temp) * decay;
+    let new = target + (change + temp) * decay;
+
+    // Don't overshoot.
+    if (target > current) == (new > target) {
+        *velocity = 0.0;
+        return target;
+    }
+    new
+}
+
+/// Moves `current` toward `target` by exponential decay, halving the remaining
+/// distance every `half_life` seconds. Returns the new value.
+///
+/// `dt` is the time since the last update, such as
+/// [`egui::InputState::stable_dt`]. This is frame-rate independent.
+///
+/// ```
+/// use hcegui::util::exp_decay;
+///
+/// let once = exp_decay(0.0, 1.0, 0.5, 0.5);
+/// assert!((once - 0.5).abs() < 1e-6);
+///
+/// let mut twice = 0.0;
+/// for _ in 0..2 {
+///     twice = exp_decay(twice, 1.0, 0.5, 0.25);
+/// }
+/// assert!((once - twice).abs() < 1e-6);
+///
+/// // Never overshoots, even with a large time step.
+/// assert_eq!(exp_decay(0.0, 1.0, 0.5, 100.0), 1.0);
+/// ```
+pub fn exp_decay(current: f32, target: f32, half_life: f32, dt: f32) -> f32 {
+    if half_life <= 0.0 {
+        return target;
+    }
+    target + (current - target) * (-dt / half_life).exp2()
+}
+
+/// Smoothly moves a value stored in egui memory toward `target` using
+/// [`exp_decay()`], requesting a repaint until it arrives. Returns the current
+/// value.
+///
+/// On the first call for a given `id`, this returns `target` immediately. If
+/// [reduced motion](crate::anim::set_reduced_motion) is enabled, this always
+/// returns `target`.
+pub fn smooth_toward(ctx: &egui::Context, id: egui::Id, target: f32, half_life: f32) -> f32 {
+    let dt = ctx.input(|input| input.stable_dt);
+    let reduced_motion = crate::anim::reduced_motion(ctx);
+
+    let (new, needs_repaint) = ctx.data_mut(|data| {
+        let value = data.get_temp_mut_or_insert_with(id, || target);
+        let new = exp_decay(*value, target, half_life, dt);
+        let arrived = reduced_motion || (new - target).abs() < SNAP_EPSILON;
+        *value = if arrived { target } else { new };
+        (*value, !arrived)
+    });
+
+    if needs_repaint {
+        ctx.request_repaint();
+    }
+    new
+}
@@ -0,0 +1,44 @@
+/// Returns the size of `text` laid out on a single line (except for explicit
+/// newlines) in `font_id`, without adding a widget.
+///
+/// Layout is cached by egui, so repeated calls with the same arguments are
+/// cheap.
+pub fn text_size(ctx: &egui::Context, text: &str, font_id: &egui::FontId) -> egui::Vec2 {
+    crate::compat::layout_no_wrap(
+        ctx,
+        text.to_owned(),
+        font_id.clone(),
+        egui::Color32::PLACEHOLDER,
+    )
+    .size()
+}
+
+/// Returns the width of `text` laid out on a single line in `font_id`. See
+/// [`text_size()`].
+pub fn text_width(ctx: &egui::Context, text: &str, font_id: &egui::FontId) -> f32 {
+    text_size(ctx, text, font_id).x
+}
+
+/// Returns the size of `text` laid out on a single line using a font from the
+/// style of `ui`, such as [`egui::TextStyle::Body`]. See [`text_size()`].
+pub fn text_size_in_ui(
+    ui: &egui::Ui,
+    text: &str,
+    font: impl Into<egui::FontSelection>,
+) -> egui::Vec2 {
+    text_size(ui.ctx(), text, &font.into().resolve(ui.style()))
+}
+
+/// Returns the index and width of the widest string in `strings` when laid out
+/// in `font_id`, or `None` if `strings` is empty. See [`text_size()`].
+pub fn widest_text<S: AsRef<str>>(
+    ctx: &egui::Context,
+    strings: &[S],
+    font_id: &egui::FontId,
+) -> Option<(usize, f32)> {
+    strings
+        .iter()
+        .map(|s| text_width(ctx, s.as_ref(), font_id))
+        .enumerate()
+        .max_by(|(_, w1), (_, w2)| f32::total_cmp(w1, w2))
+}
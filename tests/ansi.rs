@@ -0,0 +1,36 @@
+//! Snapshot tests for [`hcegui::ansi`].
+
+#![cfg(all(feature = "ansi", feature = "egui_0_34"))]
+
+use egui::Theme;
+use egui_kittest::kittest::Queryable;
+use egui_kittest::{Harness, SnapshotResults};
+use hcegui::ansi::AnsiLabel;
+
+const SAMPLE: &str = "\x1b[31merror\x1b[0m: mismatched types\n\
+                      \x1b[33mwarning\x1b[0m: unused variable\n\
+                      \x1b[38;5;12m  --> \x1b[0msrc/lib.rs:10:5\n\
+                      \x1b[38;5;208morange\x1b[0m \x1b[38;5;46mgreen\x1b[0m \x1b[38;5;255mwhite\x1b[0m";
+
+#[test]
+fn ansi_label_snapshot() {
+    let mut results = SnapshotResults::new();
+    for (theme, theme_name) in [(Theme::Dark, "dark"), (Theme::Light, "light")] {
+        let mut harness = Harness::builder().with_theme(theme).build_ui(|ui| {
+            ui.add(AnsiLabel::new(SAMPLE));
+        });
+        harness.fit_contents();
+        harness.snapshot(format!("ansi/label_{theme_name}"));
+        results.extend_harness(&mut harness);
+    }
+    results.unwrap();
+}
+
+#[test]
+fn ansi_label_unknown_escape_code() {
+    let mut harness = Harness::new_ui(|ui| {
+        ui.add(AnsiLabel::new("\x1b[5mblink\x1b[0m"));
+    });
+    harness.run();
+    harness.get_by_label_contains("unknown escape code \"5\"");
+}
@@ -0,0 +1,115 @@
+//! Interaction tests for [`hcegui::command_palette`].
+
+#![cfg(all(feature = "command_palette", feature = "egui_0_34"))]
+
+use egui::{Key, KeyboardShortcut, Modifiers};
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable;
+use hcegui::command_palette::{Command, CommandPalette};
+
+struct State {
+    palette: CommandPalette<Vec<&'static str>>,
+    log: Vec<&'static str>,
+}
+
+fn harness() -> Harness<'static, State> {
+    let mut palette = CommandPalette::new();
+    palette.add(Command::new("Open file", |log: &mut Vec<_>| {
+        log.push("open");
+    }));
+    palette.add(Command::new("Save file", |log: &mut Vec<_>| {
+        log.push("save");
+    }));
+    palette.add(
+        Command::new("Close window", |log: &mut Vec<_>| log.push("close"))
+            .shortcut(KeyboardShortcut::new(Modifiers::COMMAND, Key::W)),
+    );
+    palette.add(Command::new("Find in files", |log: &mut Vec<_>| {
+        log.push("find");
+    }));
+    Harness::new_ui_state(
+        |ui, state: &mut State| {
+            state.palette.show(ui.ctx(), &mut state.log);
+        },
+        State {
+            palette,
+            log: vec![],
+        },
+    )
+}
+
+#[test]
+fn search_and_run() {
+    let mut harness = harness();
+    harness.state_mut().palette.open();
+    harness.run();
+
+    harness
+        .get_by_role(egui::accesskit::Role::TextInput)
+        .type_text("sv");
+    harness.run();
+    assert!(harness.query_by_label("Open file").is_none());
+
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(harness.state().log, ["save"]);
+    assert!(!harness.state().palette.is_open());
+}
+
+#[test]
+fn arrow_keys_and_recent_ranking() {
+    let mut harness = harness();
+    harness.state_mut().palette.open();
+    harness.run();
+    harness.key_press(Key::ArrowDown);
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(harness.state().log, ["save"]);
+
+    // "Save file" was used recently, so it is now first.
+    harness.state_mut().palette.open();
+    harness.run();
+    harness.key_press(Key::Enter);
+    harness.run();
+    assert_eq!(harness.state().log, ["save", "save"]);
+}
+
+#[test]
+fn recent_ranking_with_query() {
+    let search = |harness: &mut Harness<'static, State>| {
+        harness.state_mut().palette.open();
+        harness.run();
+        harness
+            .get_by_role(egui::accesskit::Role::TextInput)
+            .type_text("fi");
+        harness.run();
+        harness.key_press(Key::Enter);
+        harness.run();
+    };
+
+    // "Find in files" matches best.
+    let mut harness = harness();
+    search(&mut harness);
+    assert_eq!(harness.state().log, ["find"]);
+
+    // "Save file" matches worse, but was used recently.
+    let mut harness = self::harness();
+    let state = harness.state_mut();
+    state.palette.run(1, &mut state.log);
+    search(&mut harness);
+    assert_eq!(harness.state().log, ["save", "save"]);
+}
+
+#[test]
+fn shortcut_runs_command() {
+    let mut harness = harness();
+    harness.run();
+    harness.key_press_modifiers(Modifiers::COMMAND, Key::W);
+    harness.run();
+    assert_eq!(harness.state().log, ["close"]);
+
+    harness.key_press_modifiers(Modifiers::COMMAND | Modifiers::SHIFT, Key::P);
+    harness.run();
+    assert!(harness.state().palette.is_open());
+    assert_eq!(harness.state().log, ["close"]);
+}
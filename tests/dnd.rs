@@ -0,0 +1,3264 @@
+//! Snapshot and interaction tests for [`hcegui::dnd`].
+
+#![cfg(all(feature = "dnd", feature = "egui_0_34"))]
+
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+use egui::{Theme, vec2};
+use egui_kittest::kittest::Queryable;
+use egui_kittest::{Harness, SnapshotResults};
+use hcegui::dnd::{
+    BeforeOrAfter, CrossListDndMove, Dnd, DndDragGeometry, DndDragMetrics, DndHover, DndHoverKind,
+    DndMove, DndResponse, DndStyle, DndText, DropZoneAnimation, MultiReorderDnd,
+    MultiReorderDndMove, OsFileDndResponse, ReorderDnd, ReorderDndMove, ReorderHandle, SwapDnd,
+    SwapDndMove, TreeDnd, TreeDndMove, TreeNode, TreeTarget, kanban::Kanban, persisted_order,
+    reorderable_list, tab_bar::TabBar,
+};
+
+const THEMES: [(Theme, &str); 2] = [(Theme::Dark, "dark"), (Theme::Light, "light")];
+
+fn reorder_harness(theme: Theme, items: Vec<&'static str>) -> Harness<'static, Vec<&'static str>> {
+    Harness::builder()
+        .with_theme(theme)
+        .with_size(vec2(120.0, 90.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| {
+                        let r = ui.label(item);
+                        (r, ())
+                    });
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            items,
+        )
+}
+
+/// Drags the label `from` and drops it at `to`.
+fn drag<State>(harness: &mut Harness<'_, State>, from: &str, to: egui::Pos2) {
+    let start = harness.get_by_label(from).rect().center();
+    start_drag(harness, start);
+    move_to(harness, to);
+    harness.drop_at(to);
+    harness.run();
+}
+
+/// Presses the pointer at `from` without moving it yet.
+fn start_drag<State>(harness: &mut Harness<'_, State>, from: egui::Pos2) {
+    harness.hover_at(from);
+    harness.run();
+    harness.drag_at(from);
+    harness.run();
+}
+
+/// Moves the pointer to `to` in several steps so that egui detects the drag,
+/// running until the UI settles after each step.
+fn move_to<State>(harness: &mut Harness<'_, State>, to: egui::Pos2) {
+    for pos in drag_path(harness, to) {
+        harness.hover_at(pos);
+        harness.run();
+    }
+}
+
+/// Moves the pointer to `to` like [`move_to()`], but runs only one frame per
+/// step, for UIs that keep animating or depend on the time.
+fn step_to<State>(harness: &mut Harness<'_, State>, to: egui::Pos2) {
+    for pos in drag_path(harness, to) {
+        harness.hover_at(pos);
+        harness.step();
+    }
+}
+
+/// Returns the positions that [`move_to()`] and [`step_to()`] move the pointer
+/// through. The first step is small so that the payload is picked up where the
+/// pointer was pressed.
+fn drag_path<State>(harness: &Harness<'_, State>, to: egui::Pos2) -> [egui::Pos2; 5] {
+    let from = harness.ctx.input(|input| input.pointer.latest_pos());
+    let from = from.unwrap_or(to);
+    [0.02, 0.25, 0.5, 0.75, 1.0].map(|t| from.lerp(to, t))
+}
+
+#[test]
+fn drag_down_to_reorder() {
+    let mut harness = reorder_harness(Theme::Dark, vec!["a", "b", "c", "d"]);
+    harness.run();
+
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    drag(&mut harness, "a", target);
+    assert_eq!(*harness.state(), ["b", "c", "a", "d"]);
+}
+
+#[test]
+fn drag_up_to_reorder() {
+    let mut harness = reorder_harness(Theme::Dark, vec!["a", "b", "c", "d"]);
+    harness.run();
+
+    let target = harness.get_by_label("a").rect().center_top() + vec2(0.0, 1.0);
+    drag(&mut harness, "d", target);
+    assert_eq!(*harness.state(), ["d", "a", "b", "c"]);
+}
+
+#[test]
+fn drop_in_place_keeps_order() {
+    let mut harness = reorder_harness(Theme::Dark, vec!["a", "b", "c"]);
+    harness.run();
+
+    let target = harness.get_by_label("b").rect().center() + vec2(4.0, 0.0);
+    drag(&mut harness, "b", target);
+    assert_eq!(*harness.state(), ["a", "b", "c"]);
+}
+
+#[test]
+fn live_preview() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_live_preview(true);
+                for i in dnd.preview_order(items.len()) {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(items[i]), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c", "d"],
+        );
+    harness.run();
+
+    let a = harness.get_by_label("a").rect();
+    let c = harness.get_by_label("c").rect();
+    let start = a.center();
+    let end = c.center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+
+    // `b` and `c` move up into the space left by `a`, but the order doesn't
+    // change until it is dropped.
+    assert_eq!(harness.get_by_label("b").rect().top(), a.top());
+    assert_eq!(
+        harness.get_by_label("c").rect().top(),
+        a.top() + (c.top() - a.top()) / 2.0
+    );
+    assert_eq!(*harness.state(), ["a", "b", "c", "d"]);
+
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["b", "c", "a", "d"]);
+}
+
+#[test]
+fn no_line_next_to_payload_snapshot() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| {
+                        (ui.add_sized([100.0, 16.0], egui::Label::new(item)), ())
+                    });
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    let start = harness.get_by_label("b").rect().center();
+    let end = start + vec2(30.0, 3.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.snapshot("dnd/no_line_next_to_payload");
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["a", "b", "c"]);
+}
+
+#[test]
+fn reorder_handle_snapshot() {
+    let mut results = SnapshotResults::new();
+    for (theme, theme_name) in THEMES {
+        let mut harness = Harness::builder().with_theme(theme).build_ui(|ui| {
+            ui.add(ReorderHandle::new());
+        });
+        harness.fit_contents();
+        harness.snapshot(format!("dnd/reorder_handle_{theme_name}"));
+        results.extend_harness(&mut harness);
+    }
+    results.unwrap();
+}
+
+#[test]
+fn reorder_handle_builder() {
+    const COLOR: egui::Color32 = egui::Color32::from_rgb(255, 0, 255);
+
+    let harness = Harness::builder().build_ui_state(
+        |ui, state: &mut (egui::Rect, usize)| {
+            let handle = ReorderHandle::new()
+                .size(vec2(8.0, 30.0))
+                .horizontal(true)
+                .bars(4)
+                .color(COLOR);
+            let rect = ui.add(handle).rect;
+            let bars = ui.ctx().graphics(|g| {
+                let shapes = g
+                    .get(ui.layer_id())
+                    .into_iter()
+                    .flat_map(|l| l.all_entries());
+                (shapes.filter(|s| {
+                    matches!(&s.shape, egui::Shape::LineSegment { stroke, points }
+                        if stroke.color == COLOR && points[0].x == points[1].x)
+                }))
+                .count()
+            });
+            *state = (rect, bars);
+        },
+        (egui::Rect::NOTHING, 0),
+    );
+    let (rect, bars) = *harness.state();
+    assert_eq!(rect.size(), vec2(30.0, 8.0));
+    assert_eq!(bars, 4);
+}
+
+#[test]
+fn reorder_handle_description() {
+    let harness = Harness::new_ui(|ui| {
+        ui.add(ReorderHandle::new());
+        ui.add(ReorderHandle::new().description("Glisser pour déplacer"));
+        let mut dnd = Dnd::new(ui.ctx(), "list").with_text(DndText {
+            reorder_handle_description: "Glisser pour réordonner".into(),
+            ..DndText::default()
+        });
+        dnd.reorderable_with_handle(ui, 0, |ui, _| ui.label("a"));
+        let _ = dnd.finish(ui);
+    });
+    assert!(harness.query_by_label("Drag to move").is_some());
+    assert!(harness.query_by_label("Glisser pour déplacer").is_some());
+    assert!(harness.query_by_label("Glisser pour réordonner").is_some());
+}
+
+#[test]
+fn drop_zone_snapshot() {
+    let mut results = SnapshotResults::new();
+    for (theme, theme_name) in THEMES {
+        let mut harness = Harness::builder()
+            .with_theme(theme)
+            .with_size(vec2(240.0, 120.0))
+            .build_ui(|ui| {
+                let mut dnd = Dnd::new(ui.ctx(), "drop_zones");
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    for target in ["A", "B"] {
+                        let r = ui.add_sized([60.0, 60.0], egui::Label::new(target));
+                        dnd.drop_zone(ui, &r, target);
+                    }
+                });
+                dnd.finish(ui);
+            });
+        harness.run();
+
+        let start = harness.get_by_label("Drag me").rect().center();
+        let end = harness.get_by_label("B").rect().center();
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        harness.snapshot(format!("dnd/drop_zones_{theme_name}"));
+        results.extend_harness(&mut harness);
+    }
+    results.unwrap();
+}
+
+#[test]
+fn reorder_line_snapshot() {
+    let mut results = SnapshotResults::new();
+    for (theme, theme_name) in THEMES {
+        let mut harness = reorder_harness(theme, vec!["a", "b", "c"]);
+        harness.run();
+
+        let start = harness.get_by_label("a").rect().center();
+        let end = harness.get_by_label("b").rect().center_bottom() - vec2(0.0, 1.0);
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        harness.snapshot(format!("dnd/reorder_line_{theme_name}"));
+        results.extend_harness(&mut harness);
+    }
+    results.unwrap();
+}
+
+#[test]
+fn lifted_payload_snapshot() {
+    let mut results = SnapshotResults::new();
+    for (theme, theme_name) in THEMES {
+        let mut harness = Harness::builder()
+            .with_theme(theme)
+            .with_size(vec2(120.0, 90.0))
+            .build_ui(|ui| {
+                let style = DndStyle {
+                    payload_scale: 1.25,
+                    payload_rotation: 0.1,
+                    payload_shadow: Some(ui.visuals().popup_shadow),
+                    ..Default::default()
+                };
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_style(style);
+                for (i, item) in ["a", "b", "c"].into_iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.button(item), ()));
+                }
+                dnd.finish(ui);
+            });
+        harness.run();
+
+        let start = harness.get_by_label("a").rect().center();
+        let end = start + vec2(40.0, 30.0);
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        harness.snapshot(format!("dnd/lifted_payload_{theme_name}"));
+        results.extend_harness(&mut harness);
+    }
+    results.unwrap();
+}
+
+#[test]
+fn hole_painter_snapshot() {
+    let mut results = SnapshotResults::new();
+    for (theme, theme_name) in THEMES {
+        let mut harness = Harness::builder()
+            .with_theme(theme)
+            .with_size(vec2(120.0, 90.0))
+            .build_ui(|ui| {
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_hole_painter(|painter, rect| {
+                    let stroke = egui::Stroke::new(1.0, egui::Color32::GRAY);
+                    let points = [
+                        rect.left_top(),
+                        rect.right_top(),
+                        rect.right_bottom(),
+                        rect.left_bottom(),
+                        rect.left_top(),
+                    ];
+                    painter.extend(egui::Shape::dashed_line(&points, stroke, 3.0, 2.0));
+                });
+                for (i, item) in ["a", "b", "c"].into_iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.button(item), ()));
+                }
+                dnd.finish(ui);
+            });
+        harness.run();
+
+        let start = harness.get_by_label("a").rect().center();
+        let end = start + vec2(40.0, 30.0);
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        harness.snapshot(format!("dnd/hole_painter_{theme_name}"));
+        results.extend_harness(&mut harness);
+    }
+    results.unwrap();
+}
+
+#[test]
+fn drop_zone_animation() {
+    for animation in [
+        DropZoneAnimation::None,
+        DropZoneAnimation::Pulse,
+        DropZoneAnimation::MarchingAnts,
+    ] {
+        let mut harness = Harness::builder()
+            .with_size(vec2(160.0, 90.0))
+            .build_ui(move |ui| {
+                let style = DndStyle {
+                    drop_zone_animation: animation,
+                    ..Default::default()
+                };
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_style(style);
+                ui.horizontal_top(|ui| {
+                    dnd.draggable(ui, 0_usize, |ui, _| (ui.label("a"), ()));
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Drop"));
+                    dnd.drop_zone(ui, &r, 1_usize);
+                });
+                dnd.finish(ui);
+            });
+        harness.run();
+
+        let start = harness.get_by_label("a").rect().center();
+        let end = harness.get_by_label("Drop").rect().center();
+        start_drag(&mut harness, start);
+        step_to(&mut harness, end);
+
+        // Animated drop zones keep repainting while active.
+        let is_animated = animation != DropZoneAnimation::None;
+        assert_eq!(is_animated, harness.try_run().is_err(), "{animation:?}");
+
+        if animation == DropZoneAnimation::MarchingAnts {
+            harness.snapshot("dnd/drop_zone_marching_ants");
+        }
+    }
+}
+
+#[test]
+fn debug_paint_snapshot() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(160.0, 90.0))
+        .build_ui(|ui| {
+            let mut dnd = Dnd::new(ui.ctx(), "list").with_debug_paint(true);
+            ui.horizontal_top(|ui| {
+                ui.vertical(|ui| {
+                    for (i, item) in ["a", "b", "c"].into_iter().enumerate() {
+                        dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                    }
+                });
+                let r = ui.add_sized([60.0, 60.0], egui::Label::new("Drop"));
+                dnd.drop_zone(ui, &r, (usize::MAX, BeforeOrAfter::Before));
+            });
+            dnd.finish(ui);
+        });
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let end = harness.get_by_label("b").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.snapshot("dnd/debug_paint");
+}
+
+#[test]
+fn drag_to_reorder_right_to_left() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(200.0, 60.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    let mut dnd = Dnd::new(ui.ctx(), "list");
+                    for (i, &item) in items.iter().enumerate() {
+                        dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                    }
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    // In a right-to-left layout, the left side of "c" is after it.
+    let target = harness.get_by_label("c").rect().left_center();
+    drag(&mut harness, "a", target);
+    assert_eq!(*harness.state(), ["b", "c", "a"]);
+}
+
+#[test]
+fn drag_near_edge_auto_scrolls() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, (items, offset): &mut (Vec<String>, f32)| {
+                let r = egui::ScrollArea::vertical().show(ui, |ui| {
+                    let mut dnd = Dnd::new(ui.ctx(), "list");
+                    for (i, item) in items.iter().enumerate() {
+                        dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                    }
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+                *offset = r.state.offset.y;
+            },
+            ((0..50).map(|i| format!("item {i}")).collect(), 0.0),
+        );
+    harness.run();
+
+    let start = harness.get_by_label("item 0").rect().center();
+    let bottom = egui::pos2(start.x, 118.0);
+    start_drag(&mut harness, start);
+    step_to(&mut harness, bottom);
+    for _ in 0..10 {
+        harness.step();
+    }
+    assert!(harness.state().1 > 0.0, "scroll area did not scroll");
+}
+
+#[test]
+fn scroll_to_drop() {
+    for scroll_to_drop in [false, true] {
+        let mut harness = Harness::builder()
+            .with_size(vec2(120.0, 120.0))
+            .build_ui_state(
+                |ui, (items, offset, scroll_to_drop): &mut (Vec<String>, f32, bool)| {
+                    let r = egui::ScrollArea::vertical().show(ui, |ui| {
+                        let mut dnd = Dnd::new(ui.ctx(), "list")
+                            .with_auto_scroll_margin(0.0)
+                            .with_scroll_to_drop(*scroll_to_drop);
+                        for (i, item) in items.iter().enumerate() {
+                            dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                        }
+                        if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                            r.reorder(items);
+                        }
+                    });
+                    *offset = r.state.offset.y;
+                },
+                (
+                    (0..50).map(|i| format!("item {i}")).collect(),
+                    0.0,
+                    scroll_to_drop,
+                ),
+            );
+        harness.run();
+
+        // Drop near the bottom edge, where the payload will be partly hidden.
+        let start = harness.get_by_label("item 0").rect().center();
+        let end = egui::pos2(start.x, 116.0);
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        harness.drop_at(end);
+        harness.run();
+        assert_ne!(harness.state().0[0], "item 0");
+        assert_eq!(harness.state().1 > 0.0, scroll_to_drop);
+    }
+}
+
+#[test]
+fn wheel_scrolls_while_dragging() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, (items, offset): &mut (Vec<String>, f32)| {
+                let r = egui::ScrollArea::vertical().show(ui, |ui| {
+                    let mut dnd = Dnd::new(ui.ctx(), "list").with_auto_scroll_margin(0.0);
+                    for (i, item) in items.iter().enumerate() {
+                        dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                    }
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+                *offset = r.state.offset.y;
+            },
+            ((0..50).map(|i| format!("item {i}")).collect(), 0.0),
+        );
+    harness.run();
+
+    let start = harness.get_by_label("item 0").rect().center();
+    let end = start + vec2(0.0, 30.0);
+    start_drag(&mut harness, start);
+    step_to(&mut harness, end);
+    assert_eq!(harness.state().1, 0.0);
+
+    harness.event(egui::Event::MouseWheel {
+        unit: egui::MouseWheelUnit::Point,
+        delta: vec2(0.0, -100.0),
+        phase: egui::TouchPhase::Move,
+        modifiers: egui::Modifiers::NONE,
+    });
+    for _ in 0..10 {
+        harness.step();
+    }
+    assert!(harness.state().1 > 0.0, "scroll area did not scroll");
+}
+
+#[test]
+fn reorder_gap_shifts_items() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let style = DndStyle {
+                    reorder_gap: true,
+                    ..Default::default()
+                };
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_style(style);
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c", "d"],
+        );
+    harness.run();
+
+    let b_top = harness.get_by_label("b").rect().top();
+    let c_top = harness.get_by_label("c").rect().top();
+    let start = harness.get_by_label("a").rect().center();
+    let target = egui::pos2(start.x, c_top);
+    start_drag(&mut harness, start);
+    step_to(&mut harness, target);
+    for _ in 0..20 {
+        harness.step();
+    }
+
+    // A gap opens between "b" and "c".
+    assert_eq!(harness.get_by_label("b").rect().top(), b_top);
+    assert!(harness.get_by_label("c").rect().top() > c_top + 10.0);
+
+    harness.drop_at(target);
+    for _ in 0..20 {
+        harness.step();
+    }
+    assert_eq!(*harness.state(), ["b", "a", "c", "d"]);
+    assert_eq!(harness.get_by_label("c").rect().top(), c_top);
+}
+
+#[test]
+fn keyboard_reorder() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable_with_handle(ui, i, |ui, _| ui.label(item));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c", "d"],
+        );
+    harness.run();
+    let press = |harness: &mut Harness<'_, Vec<&'static str>>, key| {
+        harness.key_press_modifiers(egui::Modifiers::ALT, key);
+        harness.run();
+    };
+
+    (harness.get_all_by_label("Drag to reorder, or press Alt and an arrow key"))
+        .nth(1)
+        .expect("missing handle")
+        .focus();
+    harness.run();
+    press(&mut harness, egui::Key::ArrowDown);
+    assert_eq!(*harness.state(), ["a", "c", "b", "d"]);
+
+    // Focus follows the moved item.
+    press(&mut harness, egui::Key::ArrowDown);
+    assert_eq!(*harness.state(), ["a", "c", "d", "b"]);
+    for _ in 0..3 {
+        press(&mut harness, egui::Key::ArrowUp);
+    }
+    assert_eq!(*harness.state(), ["b", "a", "c", "d"]);
+
+    // Moving past the start does nothing.
+    press(&mut harness, egui::Key::ArrowUp);
+    assert_eq!(*harness.state(), ["b", "a", "c", "d"]);
+}
+
+#[test]
+fn multi_reorder() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let selection = [0, 2];
+                let mut dnd = MultiReorderDnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable_multi(ui, i, &selection, |ui, _| {
+                        let r = ui.label(item);
+                        (r, ())
+                    });
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c", "d", "e"],
+        );
+    harness.run();
+
+    let target = harness.get_by_label("d").rect().center_bottom() - vec2(0.0, 1.0);
+    let start = harness.get_by_label("a").rect().center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(*harness.state(), ["b", "d", "a", "c", "e"]);
+}
+
+#[test]
+fn multi_reorder_move() {
+    let mut v = vec!['a', 'b', 'c', 'd', 'e'];
+    let range = MultiReorderDndMove::new(vec![3, 1], (0, BeforeOrAfter::Before)).reorder(&mut v);
+    assert_eq!(v, ['b', 'd', 'a', 'c', 'e']);
+    assert_eq!(range, 0..2);
+
+    let range = MultiReorderDndMove::new(vec![0, 1], (4, BeforeOrAfter::After)).reorder(&mut v);
+    assert_eq!(v, ['a', 'c', 'e', 'b', 'd']);
+    assert_eq!(range, 3..5);
+}
+
+#[test]
+fn cross_list_move() {
+    use BeforeOrAfter::{After, Before};
+
+    let mut lists = vec![vec!['a', 'b', 'c'], vec!['d'], vec![]];
+    let pos = CrossListDndMove::new((0, 0), ((0, Some(2)), After)).move_between(&mut lists);
+    assert_eq!(lists, [vec!['b', 'c', 'a'], vec!['d'], vec![]]);
+    assert_eq!(pos, (0, 2));
+
+    let pos = CrossListDndMove::new((0, 1), ((1, Some(0)), Before)).move_between(&mut lists);
+    assert_eq!(lists, [vec!['b', 'a'], vec!['c', 'd'], vec![]]);
+    assert_eq!(pos, (1, 0));
+
+    let pos = CrossListDndMove::new((1, 1), ((2, None), Before)).move_between(&mut lists);
+    assert_eq!(lists, [vec!['b', 'a'], vec!['c'], vec!['d']]);
+    assert_eq!(pos, (2, 0));
+
+    // Separate `Vec`s
+    let (mut a, mut b) = (vec![1, 2], vec![3]);
+    let pos =
+        CrossListDndMove::new((1, 0), ((0, Some(0)), After)).move_between(&mut [&mut a, &mut b]);
+    assert_eq!((a, b), (vec![1, 3, 2], vec![]));
+    assert_eq!(pos, (0, 1));
+}
+
+/// Two lists of strings in separate `Dnd`s. Strings can be moved from the
+/// first list into the second.
+fn group_harness(receiver_first: bool) -> Harness<'static, [Vec<&'static str>; 2]> {
+    Harness::builder()
+        .with_size(vec2(200.0, 120.0))
+        .build_ui_state(
+            move |ui, lists: &mut [Vec<&'static str>; 2]| {
+                let [source, receiver] = lists;
+                ui.columns(2, |uis| {
+                    let items = source.clone();
+                    let mut show_source = |ui: &mut egui::Ui| {
+                        let mut dnd = ReorderDnd::new(ui.ctx(), "source")
+                            .with_group("lists", |_: &'static str| None);
+                        for (i, &item) in source.iter().enumerate() {
+                            dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                        }
+                        match dnd.finish(ui) {
+                            DndResponse::DoneDragging(m) => m.reorder(source),
+                            DndResponse::DroppedElsewhere(m) => {
+                                source.remove(m.payload);
+                            }
+                            _ => (),
+                        }
+                    };
+                    let mut show_receiver = |ui: &mut egui::Ui| {
+                        let mut dnd = Dnd::<&'static str, usize>::new(ui.ctx(), "receiver")
+                            .with_group("lists", |i: usize| items.get(i).copied());
+                        for (i, &item) in receiver.iter().enumerate() {
+                            let r = ui.label(item);
+                            dnd.drop_zone(ui, &r, i);
+                        }
+                        if let Some(m) = dnd.finish(ui).if_done_dragging() {
+                            receiver.insert(m.target, m.payload);
+                        }
+                    };
+                    let [left, right] = uis else { unreachable!() };
+                    if receiver_first {
+                        show_receiver(right);
+                        show_source(left);
+                    } else {
+                        show_source(left);
+                        show_receiver(right);
+                    }
+                });
+            },
+            [vec!["a", "b", "c"], vec!["x", "y"]],
+        )
+}
+
+#[test]
+fn drag_between_dnds_in_group() {
+    for receiver_first in [false, true] {
+        let mut harness = group_harness(receiver_first);
+        harness.run();
+
+        let start = harness.get_by_label("b").rect().center();
+        let target = harness.get_by_label("y").rect().center();
+        start_drag(&mut harness, start);
+        move_to(&mut harness, target);
+        harness.drop_at(target);
+        harness.run();
+        assert_eq!(
+            *harness.state(),
+            [vec!["a", "c"], vec!["x", "b", "y"]],
+            "receiver_first = {receiver_first}",
+        );
+    }
+}
+
+#[test]
+fn rejected_drop_zone() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, dropped: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "drop_zones")
+                    .with_accept(|_: &&str, target: &&str| *target != "B");
+                ui.horizontal(|ui| {
+                    for target in ["A", "B"] {
+                        let r = ui.add_sized([60.0, 60.0], egui::Label::new(target));
+                        dnd.drop_zone(ui, &r, target);
+                    }
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                });
+                if let Some(m) = dnd.finish(ui).if_done_dragging() {
+                    dropped.push(m.target);
+                }
+            },
+            vec![],
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    for target in ["B", "A"] {
+        let end = harness.get_by_label(target).rect().center();
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        harness.drop_at(end);
+        harness.run();
+    }
+    assert_eq!(*harness.state(), ["A"]);
+}
+
+#[test]
+fn reorder_with_id_source_while_list_changes() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable_with_id_source(ui, i, item, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    let start = harness.get_by_label("b").rect().center();
+    start_drag(&mut harness, start);
+    harness.hover_at(start + vec2(0.0, 2.0));
+    harness.run();
+
+    // An item is added above while dragging.
+    harness.state_mut().insert(0, "z");
+    harness.run();
+    let end = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["z", "a", "c", "b"]);
+}
+
+#[test]
+fn begin_drag_from_code() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                let mut move_id = None;
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, id| {
+                        let r = ui.label(item);
+                        r.context_menu(|ui| {
+                            if ui.button("Move").clicked() {
+                                move_id = Some((id, i));
+                            }
+                        });
+                        (r, ())
+                    });
+                }
+                if let Some((id, i)) = move_id {
+                    dnd.begin_drag(id, i);
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    harness.get_by_label("a").click_secondary();
+    harness.run();
+    harness.get_by_label("Move").click();
+    harness.run();
+
+    // The payload follows the pointer without holding any button.
+    let end = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    move_to(&mut harness, end);
+    assert_eq!(*harness.state(), ["a", "b", "c"]);
+    harness.drag_at(end);
+    harness.run();
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["b", "c", "a"]);
+}
+
+#[test]
+fn sticky_drag() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                ui.style_mut().interaction.selectable_labels = false;
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_sticky_drag(true);
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    let click_at = |harness: &mut Harness<'_, Vec<&'static str>>, pos| {
+        harness.hover_at(pos);
+        harness.run();
+        harness.drag_at(pos);
+        harness.run();
+        harness.drop_at(pos);
+        harness.run();
+    };
+
+    // Clicking picks up the payload, which follows the pointer without holding
+    // any button until the next click.
+    let start = harness.get_by_label("a").rect().center();
+    let end = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    click_at(&mut harness, start);
+    move_to(&mut harness, end);
+    assert_eq!(*harness.state(), ["a", "b", "c"]);
+    click_at(&mut harness, end);
+    assert_eq!(*harness.state(), ["b", "c", "a"]);
+
+    // Escape cancels.
+    let start = harness.get_by_label("b").rect().center();
+    click_at(&mut harness, start);
+    harness.hover_at(end);
+    harness.run();
+    assert_eq!(
+        harness.output().platform_output.cursor_icon,
+        egui::CursorIcon::Grabbing,
+    );
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert_eq!(
+        harness.output().platform_output.cursor_icon,
+        egui::CursorIcon::Grab,
+    );
+    assert_eq!(*harness.state(), ["b", "c", "a"]);
+
+    // Dragging still works.
+    drag(&mut harness, "b", end);
+    assert_eq!(*harness.state(), ["c", "a", "b"]);
+}
+
+#[test]
+fn touch_scroll_or_drag() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                ui.style_mut().interaction.selectable_labels = false;
+                egui::ScrollArea::vertical().show(ui, |ui| {
+                    let mut dnd = Dnd::new(ui.ctx(), "list")
+                        .with_auto_scroll_margin(0.0)
+                        .with_touch_drag_axis(egui::Vec2b::new(true, false));
+                    for (i, &item) in items.iter().enumerate() {
+                        dnd.reorderable(ui, i, |ui, _| {
+                            (ui.add_sized([60.0, 30.0], egui::Label::new(item)), ())
+                        });
+                    }
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+            },
+            vec!["a", "b", "c", "d", "e", "f", "g", "h"],
+        );
+    harness.run();
+
+    let touch = |harness: &mut Harness<'_, Vec<&'static str>>, phase, pos| {
+        if phase == egui::TouchPhase::Start {
+            harness.hover_at(pos);
+            harness.run();
+        }
+        harness.event(egui::Event::Touch {
+            device_id: egui::TouchDeviceId(0),
+            id: egui::TouchId(0),
+            phase,
+            pos,
+            force: None,
+        });
+        harness.event(match phase {
+            egui::TouchPhase::Start => egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed: true,
+                modifiers: egui::Modifiers::NONE,
+            },
+            egui::TouchPhase::Move => egui::Event::PointerMoved(pos),
+            _ => egui::Event::PointerButton {
+                pos,
+                button: egui::PointerButton::Primary,
+                pressed: false,
+                modifiers: egui::Modifiers::NONE,
+            },
+        });
+        harness.run_steps(2);
+    };
+    let swipe = |harness: &mut Harness<'_, Vec<&'static str>>, path: &[egui::Pos2]| {
+        touch(harness, egui::TouchPhase::Start, path[0]);
+        for &pos in &path[1..] {
+            touch(harness, egui::TouchPhase::Move, pos);
+        }
+        touch(harness, egui::TouchPhase::End, path[path.len() - 1]);
+    };
+
+    // Swiping vertically scrolls.
+    let b = harness.get_by_label("b").rect().center();
+    let a_top = harness.get_by_label("a").rect().top();
+    let path = [
+        b,
+        b - vec2(0.0, 1.0),
+        b - vec2(0.0, 10.0),
+        b - vec2(0.0, 20.0),
+    ];
+    swipe(&mut harness, &path);
+    assert_eq!(*harness.state(), ["a", "b", "c", "d", "e", "f", "g", "h"]);
+    assert_eq!(harness.get_by_label("a").rect().top(), a_top - 20.0);
+
+    // Swiping horizontally first drags.
+    let b = harness.get_by_label("b").rect().center();
+    let c = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    let path = [
+        b,
+        b + vec2(1.0, 0.0),
+        b + vec2(10.0, 0.0),
+        b.lerp(c, 0.5),
+        c,
+    ];
+    swipe(&mut harness, &path);
+    assert_eq!(*harness.state(), ["a", "c", "b", "d", "e", "f", "g", "h"]);
+
+    // Holding still first drags in any direction.
+    let b = harness.get_by_label("b").rect().center();
+    let c = harness.get_by_label("c").rect().center_top() + vec2(0.0, 1.0);
+    touch(&mut harness, egui::TouchPhase::Start, b);
+    harness.run_steps(60);
+    for pos in [b - vec2(0.0, 1.0), b.lerp(c, 0.5), c] {
+        touch(&mut harness, egui::TouchPhase::Move, pos);
+    }
+    touch(&mut harness, egui::TouchPhase::End, c);
+    assert_eq!(*harness.state(), ["a", "b", "c", "d", "e", "f", "g", "h"]);
+}
+
+#[test]
+fn egui_drag_and_drop_interop() {
+    #[derive(Debug, Default, PartialEq)]
+    struct State {
+        native_dropped: Vec<&'static str>,
+        dnd_dropped: Vec<&'static str>,
+        dropped_elsewhere: Vec<&'static str>,
+    }
+    let mut harness = Harness::builder()
+        .with_size(vec2(480.0, 120.0))
+        .build_ui_state(
+            |ui, state: &mut State| {
+                let mut dnd = Dnd::new(ui.ctx(), "interop").with_egui_drag_and_drop();
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "from dnd", |ui, _| (ui.button("Dnd source"), ()));
+                    ui.dnd_drag_source(egui::Id::new("native"), "from egui", |ui| {
+                        ui.label("Native source")
+                    });
+                    let (_, dropped) = ui.dnd_drop_zone::<&'static str, _>(
+                        egui::Frame::NONE.inner_margin(20.0),
+                        |ui| ui.label("Native target"),
+                    );
+                    state.native_dropped.extend(dropped.map(|p| *p));
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Dnd target"));
+                    dnd.drop_zone(ui, &r, ());
+                });
+                match dnd.finish(ui) {
+                    DndResponse::DoneDragging(m) => state.dnd_dropped.push(m.payload),
+                    DndResponse::DroppedElsewhere(m) => state.dropped_elsewhere.push(m.payload),
+                    _ => (),
+                }
+            },
+            State::default(),
+        );
+    harness.run();
+
+    let native_target = harness.get_by_label("Native target").rect().center();
+    let dnd_target = harness.get_by_label("Dnd target").rect().center();
+    drag(&mut harness, "Dnd source", native_target);
+    drag(&mut harness, "Native source", dnd_target);
+    assert_eq!(
+        *harness.state(),
+        State {
+            native_dropped: vec!["from dnd"],
+            dnd_dropped: vec!["from egui"],
+            dropped_elsewhere: vec!["from dnd"],
+        },
+    );
+}
+
+#[test]
+fn not_allowed_cursor() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui(|ui| {
+            let mut dnd = Dnd::new(ui.ctx(), "drop_zones")
+                .with_accept(|_: &&str, target: &&str| *target != "B");
+            ui.horizontal(|ui| {
+                for target in ["A", "B"] {
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new(target));
+                    dnd.drop_zone(ui, &r, target);
+                }
+                dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+            });
+            dnd.finish(ui);
+        });
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let a = harness.get_by_label("A").rect().center();
+    let b = harness.get_by_label("B").rect().center();
+    start_drag(&mut harness, start);
+    let mut cursor_at = |pos| {
+        harness.hover_at(pos);
+        harness.run();
+        harness.output().platform_output.cursor_icon
+    };
+    cursor_at(start.lerp(a, 0.02));
+    assert_eq!(cursor_at(a), egui::CursorIcon::Grabbing);
+    assert_eq!(cursor_at(b), egui::CursorIcon::NotAllowed);
+    assert_eq!(cursor_at(b + vec2(0.0, 50.0)), egui::CursorIcon::NotAllowed);
+}
+
+#[test]
+fn escape_cancels_drag() {
+    let mut harness = reorder_harness(Theme::Dark, vec!["a", "b", "c", "d"]);
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    harness.hover_at(target + vec2(0.0, 1.0));
+    harness.run();
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(*harness.state(), ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn secondary_button_cancels_drag() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .build_ui_state(
+            |ui, (items, cancelled): &mut (Vec<&'static str>, Vec<usize>)| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                match dnd.finish(ui) {
+                    DndResponse::DoneDragging(r) => r.reorder(items),
+                    DndResponse::Cancelled(i) => cancelled.push(i),
+                    _ => (),
+                }
+            },
+            (vec!["a", "b", "c", "d"], vec![]),
+        );
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.event(egui::Event::PointerButton {
+        pos: target,
+        button: egui::PointerButton::Secondary,
+        pressed: true,
+        modifiers: egui::Modifiers::NONE,
+    });
+    harness.run();
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(*harness.state(), (vec!["a", "b", "c", "d"], vec![0]));
+}
+
+#[test]
+fn drag_with_middle_button() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd =
+                    Dnd::new(ui.ctx(), "list").with_drag_buttons(&[egui::PointerButton::Middle]);
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c", "d"],
+        );
+    harness.run();
+
+    let drag_with = |harness: &mut Harness<'_, Vec<&'static str>>, button, from, to| {
+        let press = |pos, pressed| egui::Event::PointerButton {
+            pos,
+            button,
+            pressed,
+            modifiers: egui::Modifiers::NONE,
+        };
+        harness.hover_at(from);
+        harness.run();
+        harness.event(press(from, true));
+        harness.run();
+        move_to(harness, to);
+        harness.event(press(to, false));
+        harness.run();
+    };
+
+    let start = harness.get_by_label("a").rect().center();
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    drag_with(&mut harness, egui::PointerButton::Primary, start, target);
+    assert_eq!(*harness.state(), ["a", "b", "c", "d"]);
+    drag_with(&mut harness, egui::PointerButton::Middle, start, target);
+    assert_eq!(*harness.state(), ["b", "c", "a", "d"]);
+}
+
+#[test]
+fn drag_with_overlay() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, dropped: &mut bool| {
+                let mut dnd = Dnd::new(ui.ctx(), "overlay");
+                ui.horizontal(|ui| {
+                    dnd.draggable_with_overlay(
+                        ui,
+                        "payload",
+                        |ui, _| (ui.add_sized([80.0, 80.0], egui::Button::new("Drag me")), ()),
+                        |ui| {
+                            ui.label("Overlay");
+                        },
+                    );
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Target"));
+                    dnd.drop_zone(ui, &r, ());
+                });
+                *dropped |= dnd.finish(ui).if_done_dragging().is_some();
+            },
+            false,
+        );
+    harness.run();
+    assert!(harness.query_by_label("Overlay").is_none());
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let end = harness.get_by_label("Target").rect().center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    let overlay = harness.get_by_label("Overlay").rect();
+    assert!(overlay.width() < 80.0);
+    harness.drop_at(end);
+    harness.run();
+    assert!(*harness.state());
+}
+
+#[test]
+fn drag_threshold() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .build_ui_state(
+            |ui, (items, was_dragging): &mut (Vec<&'static str>, bool)| {
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_drag_threshold(20.0);
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                *was_dragging |= dnd.is_dragging();
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            (vec!["a", "b", "c"], false),
+        );
+    harness.run();
+
+    // Moving less than the threshold does not start a drag.
+    let start = harness.get_by_label("a").rect().center();
+    start_drag(&mut harness, start);
+    harness.hover_at(start + vec2(0.0, 12.0));
+    harness.run();
+    harness.drop_at(start + vec2(0.0, 12.0));
+    harness.run();
+    assert!(!harness.state().1);
+
+    // Moving farther does.
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.run();
+    assert!(harness.state().1);
+    assert_eq!(harness.state().0, ["b", "c", "a"]);
+}
+
+#[test]
+fn grid_reorder() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(130.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                ui.set_max_width(120.0);
+                ui.horizontal_wrapped(|ui| {
+                    let mut dnd = Dnd::new(ui.ctx(), "grid");
+                    for (i, &item) in items.iter().enumerate() {
+                        dnd.grid_reorderable(ui, i, |ui, _| {
+                            let button =
+                                egui::Button::new(item).wrap_mode(egui::TextWrapMode::Extend);
+                            (ui.add(button), ())
+                        });
+                    }
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+            },
+            vec!["aaaa", "bbbb", "cccc", "dddd", "e\ne\ne", "ffff"],
+        );
+    harness.run();
+
+    // Objects wrap to the next row.
+    let a = harness.get_by_label("aaaa").rect();
+    let c = harness.get_by_label("cccc").rect();
+    assert!(c.top() > a.bottom());
+
+    // Drop below "cccc", which is shorter than the rest of its row.
+    let target = c.center_bottom() + vec2(5.0, 10.0);
+    let start = a.center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.run();
+    let expected = ["bbbb", "cccc", "aaaa", "dddd", "e\ne\ne", "ffff"];
+    assert_eq!(harness.state(), &expected);
+}
+
+/// Children of each node in a tree, with `None` for the root.
+type Tree = std::collections::HashMap<Option<&'static str>, Vec<&'static str>>;
+
+fn tree_harness() -> Harness<'static, Tree> {
+    fn show_children(
+        ui: &mut egui::Ui,
+        dnd: &mut TreeDnd<&'static str>,
+        tree: &Tree,
+        parent: Option<&'static str>,
+    ) {
+        for (index, &node) in tree.get(&parent).into_iter().flatten().enumerate() {
+            let tree_node = TreeNode {
+                node,
+                parent,
+                index,
+            };
+            dnd.tree_node(ui, tree_node, |ui, _| (ui.label(node), ()));
+            ui.indent(node, |ui| show_children(ui, dnd, tree, Some(node)));
+        }
+    }
+
+    Harness::builder()
+        .with_size(vec2(160.0, 120.0))
+        .build_ui_state(
+            |ui, tree: &mut Tree| {
+                let mut dnd = TreeDnd::new(ui.ctx(), "tree");
+                show_children(ui, &mut dnd, tree, None);
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    let m = r.tree_move();
+                    tree.entry(m.old_parent).or_default().remove(m.old_index);
+                    tree.entry(m.new_parent)
+                        .or_default()
+                        .insert(m.index, m.node);
+                }
+            },
+            Tree::from([(None, vec!["a", "b"]), (Some("a"), vec!["a1"])]),
+        )
+}
+
+/// Drags the label `from` and drops it at `to`, starting the drag at the left
+/// edge of the label.
+fn drag_tree_node(harness: &mut Harness<'_, Tree>, from: &str, to: egui::Pos2) {
+    let start = harness.get_by_label(from).rect().left_center() + vec2(2.0, 0.0);
+    start_drag(harness, start);
+    move_to(harness, to);
+    harness.drop_at(to);
+    harness.run();
+}
+
+#[test]
+fn tree_reorder() {
+    let mut harness = tree_harness();
+    harness.run();
+
+    // Drop before a sibling.
+    let a = harness.get_by_label("a").rect();
+    drag_tree_node(&mut harness, "b", a.left_top() + vec2(2.0, 2.0));
+    assert_eq!(harness.state()[&None], ["b", "a"]);
+
+    // Drop after a node, level with it.
+    let a1 = harness.get_by_label("a1").rect();
+    drag_tree_node(&mut harness, "b", a1.left_bottom() + vec2(2.0, -2.0));
+    assert_eq!(harness.state()[&None], ["a"]);
+    assert_eq!(harness.state()[&Some("a")], ["a1", "b"]);
+
+    // Drop into a node by moving to the right.
+    let a1 = harness.get_by_label("a1").rect();
+    let indent = a1.left() - harness.get_by_label("a").rect().left();
+    drag_tree_node(
+        &mut harness,
+        "b",
+        a1.left_bottom() + vec2(2.0 + indent, -2.0),
+    );
+    assert_eq!(harness.state()[&Some("a")], ["a1"]);
+    assert_eq!(harness.state()[&Some("a1")], ["b"]);
+
+    // A node can't be dropped into its own descendant.
+    let b = harness.get_by_label("b").rect();
+    drag_tree_node(
+        &mut harness,
+        "a",
+        b.left_bottom() + vec2(2.0 + indent, -2.0),
+    );
+    assert_eq!(harness.state()[&None], ["a"]);
+    assert_eq!(harness.state()[&Some("a1")], ["b"]);
+}
+
+#[test]
+fn reorder_hidden_rows() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<String>| {
+                let row_height = ui.text_style_height(&egui::TextStyle::Body);
+                egui::ScrollArea::vertical().show_rows(ui, row_height, items.len(), |ui, rows| {
+                    let mut dnd = ReorderDnd::new(ui.ctx(), "list");
+                    for i in rows.clone() {
+                        dnd.reorderable(ui, i, |ui, _| (ui.label(&items[i]), ()));
+                    }
+                    dnd.reorderable_hidden_rows(ui, row_height, items.len(), rows);
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+            },
+            (0..50).map(|i| format!("item {i}")).collect(),
+        );
+    harness.run();
+
+    // Drag near the bottom edge until the payload scrolls out of view.
+    let start = harness.get_by_label("item 0").rect().center();
+    let bottom = egui::pos2(start.x, 110.0);
+    start_drag(&mut harness, start);
+    step_to(&mut harness, bottom);
+    for _ in 0..10 {
+        harness.step();
+    }
+    assert!(harness.query_by_label("item 0").is_none());
+    harness.drop_at(bottom);
+    harness.step();
+
+    let index = harness.state().iter().position(|item| item == "item 0");
+    assert!(
+        index.is_some_and(|i| i > 5),
+        "payload was not moved: {index:?}"
+    );
+}
+
+#[test]
+fn settle_animation() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, (items, a_pos): &mut (Vec<&'static str>, Option<egui::Pos2>)| {
+                // The test harness disables animations by default.
+                ui.style_mut().animation_time = 0.2;
+                let style = DndStyle {
+                    settle_animation: true,
+                    ..Default::default()
+                };
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_style(style);
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+
+                // Find where "a" is painted.
+                *a_pos = ui.ctx().graphics(|g| {
+                    g.get(ui.layer_id())?
+                        .all_entries()
+                        .find_map(|s| match &s.shape {
+                            egui::Shape::Text(text) if text.galley.text() == "a" => Some(text.pos),
+                            _ => None,
+                        })
+                });
+            },
+            (vec!["a", "b", "c", "d"], None),
+        );
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.step();
+    assert_eq!(harness.state().0, ["b", "c", "a", "d"]);
+
+    // "a" starts where it was dropped and moves into place.
+    harness.step();
+    let final_pos = harness.get_by_label("a").rect().left_top();
+    let settling_pos = harness.state().1.expect("a is not painted");
+    assert!(
+        settling_pos.y < final_pos.y - 1.0,
+        "{settling_pos:?} {final_pos:?}"
+    );
+    harness.run_steps(30);
+    let settled_pos = harness.state().1.expect("a is not painted");
+    assert!(
+        (settled_pos.y - final_pos.y).abs() < 1.0,
+        "{settled_pos:?} {final_pos:?}"
+    );
+}
+
+#[test]
+fn snap_back_animation() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, (items, a_pos): &mut (Vec<&'static str>, Option<egui::Pos2>)| {
+                // The test harness disables animations by default.
+                ui.style_mut().animation_time = 0.2;
+                let style = DndStyle {
+                    snap_back_animation: true,
+                    ..Default::default()
+                };
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_style(style);
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+
+                // Find where "a" is painted above everything else.
+                let layer_id =
+                    egui::LayerId::new(egui::Order::Tooltip, egui::Id::new("list").with(0));
+                *a_pos = ui.ctx().graphics(|g| {
+                    g.get(layer_id)?.all_entries().find_map(|s| match &s.shape {
+                        egui::Shape::Text(text) if text.galley.text() == "a" => Some(text.pos),
+                        _ => None,
+                    })
+                });
+            },
+            (vec!["a", "b", "c", "d"], None),
+        );
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.key_press(egui::Key::Escape);
+    harness.step();
+    assert_eq!(harness.state().0, ["a", "b", "c", "d"]);
+
+    // "a" starts where it was released and moves back.
+    harness.step();
+    let final_pos = harness.get_by_label("a").rect().left_top();
+    let returning_pos = harness.state().1.expect("a is not returning");
+    assert!(
+        returning_pos.y > final_pos.y + 1.0,
+        "{returning_pos:?} {final_pos:?}"
+    );
+    harness.run_steps(30);
+    assert_eq!(harness.state().1, None);
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(harness.state().0, ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn drop_zone_custom_hit_test() {
+    // Two triangular drop zones that split a square along its diagonal
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, dropped: &mut Option<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "triangles");
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, (), |ui, _| (ui.button("Drag me"), ()));
+                    let r = ui.add_sized([80.0, 80.0], egui::Label::new("Square"));
+                    let rect = r.rect;
+                    let above_diagonal = move |pos: egui::Pos2| {
+                        let v = pos - rect.min;
+                        v.x > v.y
+                    };
+                    dnd.drop_zone_custom(ui, rect, "upper right", above_diagonal);
+                    dnd.drop_zone_custom(ui, rect, "lower left", move |pos| !above_diagonal(pos));
+                });
+                if let Some(m) = dnd.finish(ui).if_done_dragging() {
+                    *dropped = Some(m.target);
+                }
+            },
+            None,
+        );
+    harness.run();
+
+    let square = harness.get_by_label("Square").rect();
+    for (pos, expected) in [
+        (square.lerp_inside(vec2(0.8, 0.2)), "upper right"),
+        (square.lerp_inside(vec2(0.2, 0.8)), "lower left"),
+    ] {
+        drag(&mut harness, "Drag me", pos);
+        assert_eq!(harness.state_mut().take(), Some(expected));
+    }
+}
+
+#[test]
+fn hovered_target_geometry() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, hover: &mut Option<DndHover>| {
+                let mut dnd = Dnd::new(ui.ctx(), "hover");
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Target"));
+                    dnd.drop_zone(ui, &r, ());
+                });
+                let (response, h) = dnd.finish_with_hover(ui);
+                if matches!(response, DndResponse::MidDrag(_)) {
+                    *hover = h;
+                }
+            },
+            None,
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let target_rect = harness.get_by_label("Target").rect();
+    let target = target_rect.center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    let hover = harness.state().expect("no hovered target");
+    assert_eq!(hover.kind, DndHoverKind::DropZone);
+    assert_eq!(hover.rect, target_rect);
+    harness.drop_at(target);
+    harness.run();
+}
+
+#[test]
+fn drag_painter_geometry() {
+    let painted = Rc::new(Cell::new(None));
+    let painted_in_ui = Rc::clone(&painted);
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui(move |ui| {
+            let painted = Rc::clone(&painted_in_ui);
+            let mut dnd = Dnd::new(ui.ctx(), "drag_paint").with_drag_painter(
+                move |painter, geometry: &DndDragGeometry| {
+                    if let (Some(source), Some(hover)) = (geometry.source_rect, geometry.hover) {
+                        let stroke = egui::Stroke::new(2.0, egui::Color32::GRAY);
+                        painter.arrow(
+                            source.center(),
+                            hover.rect.center() - source.center(),
+                            stroke,
+                        );
+                    }
+                    painted.set(Some(*geometry));
+                },
+            );
+            ui.horizontal(|ui| {
+                dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                let r = ui.add_sized([60.0, 60.0], egui::Label::new("Target"));
+                dnd.drop_zone(ui, &r, ());
+            });
+            dnd.finish(ui);
+        });
+    harness.run();
+    assert_eq!(painted.get(), None);
+
+    let source_rect = harness.get_by_label("Drag me").rect();
+    let start = source_rect.center();
+    let target_rect = harness.get_by_label("Target").rect();
+    let target = target_rect.center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    let geometry = painted.take().expect("drag painter not called");
+    assert_eq!(geometry.source_rect, Some(source_rect));
+    let hover = geometry.hover.expect("no hovered target");
+    assert_eq!(geometry.payload_rect.center(), hover.pos);
+    assert!((geometry.payload_rect.size() - source_rect.size()).length() < 0.01);
+    assert_eq!(hover.kind, DndHoverKind::DropZone);
+    assert_eq!(hover.rect, target_rect);
+
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(painted.get(), None);
+}
+
+#[test]
+fn drop_zone_with_painter() {
+    const FILL: egui::Color32 = egui::Color32::from_rgb(255, 0, 255);
+
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, painted: &mut Option<(egui::Rect, bool)>| {
+                let mut dnd = Dnd::new(ui.ctx(), "painter");
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Target"));
+                    dnd.drop_zone_with_painter(ui, &r, (), |painter, rect, active| {
+                        painter.rect_filled(rect, 0.0, FILL);
+                        let id = egui::Id::new("active");
+                        painter.ctx().data_mut(|data| data.insert_temp(id, active));
+                    });
+                });
+                let _ = dnd.finish(ui);
+
+                // Find the custom drop zone highlight.
+                let active = ui.data(|data| data.get_temp(egui::Id::new("active")));
+                *painted = ui.ctx().graphics(|g| {
+                    g.get(ui.layer_id())?
+                        .all_entries()
+                        .find_map(|s| match &s.shape {
+                            egui::Shape::Vec(shapes) => {
+                                shapes.iter().find_map(|shape| match shape {
+                                    egui::Shape::Rect(rect) if rect.fill == FILL => {
+                                        Some((rect.rect, active?))
+                                    }
+                                    _ => None,
+                                })
+                            }
+                            _ => None,
+                        })
+                });
+            },
+            None,
+        );
+    harness.run();
+    assert_eq!(harness.state(), &None);
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let target_rect = harness.get_by_label("Target").rect();
+    start_drag(&mut harness, start);
+    harness.hover_at(start + vec2(0.0, 20.0));
+    harness.run();
+    assert_eq!(harness.state(), &Some((target_rect, false)));
+
+    let target = target_rect.center();
+    move_to(&mut harness, target);
+    assert_eq!(harness.state(), &Some((target_rect, true)));
+    harness.drop_at(target);
+    harness.run();
+}
+
+#[test]
+fn persisted_order_is_restored() {
+    /// Simulates a new session that loads `saved` from storage.
+    fn order_harness(
+        items: Vec<&'static str>,
+        mut saved: Option<Vec<egui::Id>>,
+    ) -> Harness<'static, Vec<&'static str>> {
+        Harness::builder()
+            .with_size(vec2(120.0, 90.0))
+            .build_ui_state(
+                move |ui, items: &mut Vec<&'static str>| {
+                    if let Some(saved) = saved.take() {
+                        ui.data_mut(|data| data.insert_persisted(egui::Id::new("order"), saved));
+                    }
+                    persisted_order(
+                        ui,
+                        "order",
+                        items,
+                        |&item| item,
+                        |ui, &item| {
+                            ui.label(item);
+                        },
+                    );
+                },
+                items,
+            )
+    }
+
+    let mut harness = order_harness(vec!["a", "b", "c"], None);
+    harness.run();
+    let handle = (harness.get_all_by_label("Drag to reorder, or press Alt and an arrow key"))
+        .next()
+        .map(|handle| handle.rect().center());
+    let start = handle.expect("no drag handle");
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    let target = egui::pos2(start.x, target.y);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(*harness.state(), ["b", "c", "a"]);
+
+    // Restore the saved order in a new session, with a new item.
+    let id = egui::Id::new("order");
+    let saved = harness
+        .ctx
+        .data_mut(|data| data.get_persisted::<Vec<egui::Id>>(id));
+    assert!(saved.is_some(), "order not saved");
+    let mut harness = order_harness(vec!["a", "b", "c", "d"], saved);
+    harness.run();
+    assert_eq!(*harness.state(), ["b", "c", "a", "d"]);
+}
+
+#[test]
+fn style_colors() {
+    const LINE: egui::Color32 = egui::Color32::from_rgb(255, 0, 255);
+    const HOLE: egui::Color32 = egui::Color32::from_rgb(0, 255, 255);
+
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 90.0))
+        .build_ui_state(
+            |ui, colors: &mut Vec<egui::Color32>| {
+                let style = DndStyle {
+                    reorder_line_color: Some(LINE),
+                    payload_hole_color: Some(HOLE),
+                    payload_hole_opacity: 1.0,
+                    ..DndStyle::from_visuals(ui.visuals())
+                };
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_style(style);
+                for (i, item) in ["a", "b", "c"].into_iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                let _ = dnd.finish(ui);
+
+                *colors = ui.ctx().graphics(|g| {
+                    let Some(shapes) = g.get(ui.layer_id()) else {
+                        return vec![];
+                    };
+                    (shapes.all_entries())
+                        .filter_map(|s| match &s.shape {
+                            egui::Shape::LineSegment { stroke, .. } => Some(stroke.color),
+                            egui::Shape::Rect(rect) => Some(rect.fill),
+                            _ => None,
+                        })
+                        .collect()
+                });
+            },
+            vec![],
+        );
+    harness.run();
+    assert!(!harness.state().contains(&LINE));
+    assert!(!harness.state().contains(&HOLE));
+
+    let start = harness.get_by_label("a").rect().center();
+    let end = harness.get_by_label("b").rect().center_bottom() - vec2(0.0, 1.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    assert!(harness.state().contains(&LINE));
+    assert!(harness.state().contains(&HOLE));
+}
+
+#[test]
+fn os_file_drop_zone() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, response: &mut OsFileDndResponse<&'static str>| {
+                let mut dnd = Dnd::<(), &'static str>::new(ui.ctx(), "files");
+                ui.horizontal(|ui| {
+                    for target in ["Images", "Documents"] {
+                        let r = ui.add_sized([80.0, 60.0], egui::Label::new(target));
+                        dnd.os_file_drop_zone(ui, &r, target);
+                    }
+                });
+                *response = dnd.take_os_file_response();
+                let _ = dnd.finish(ui);
+            },
+            DndResponse::Inactive,
+        );
+    harness.run();
+    assert_eq!(*harness.state(), DndResponse::Inactive);
+
+    let path = std::path::PathBuf::from("cat.png");
+    let file = egui::DroppedFile {
+        path: Some(path.clone()),
+        ..Default::default()
+    };
+
+    harness.input_mut().hovered_files = vec![egui::HoveredFile {
+        path: Some(path.clone()),
+        mime: String::new(),
+    }];
+    harness.hover_at(egui::pos2(230.0, 110.0));
+    harness.step();
+    let expected = DndMove::new(vec![file.clone()], None);
+    assert_eq!(*harness.state(), DndResponse::MidDrag(expected));
+
+    let target = harness.get_by_label("Documents").rect().center();
+    harness.hover_at(target);
+    harness.step();
+    let expected = DndMove::new(vec![file.clone()], Some("Documents"));
+    assert_eq!(*harness.state(), DndResponse::MidDrag(expected));
+
+    harness.input_mut().hovered_files.clear();
+    harness.input_mut().dropped_files = vec![file.clone()];
+    harness.step();
+    let expected = DndMove::new(vec![file], "Documents");
+    assert_eq!(*harness.state(), DndResponse::DoneDragging(expected));
+
+    harness.step();
+    assert_eq!(*harness.state(), DndResponse::Inactive);
+}
+
+#[test]
+fn drag_out() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, response: &mut DndResponse<&'static str, ()>| {
+                let rect = egui::Rect::from_min_size(egui::Pos2::ZERO, vec2(120.0, 120.0));
+                let mut dnd = Dnd::new(ui.ctx(), "tabs").with_drag_out_rect(rect);
+                dnd.draggable(ui, "tab", |ui, _| (ui.button("Tab"), ()));
+                let r = dnd.finish(ui);
+                if r != DndResponse::Inactive {
+                    *response = r;
+                }
+            },
+            DndResponse::Inactive,
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Tab").rect().center();
+    let end = egui::pos2(200.0, start.y);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), DndResponse::DraggedOut("tab"));
+}
+
+#[test]
+fn lifecycle_hooks() {
+    let events: Rc<RefCell<Vec<String>>> = Rc::default();
+    let events_in_ui = Rc::clone(&events);
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui(move |ui| {
+            let [e1, e2, e3, e4] = [(); 4].map(|()| Rc::clone(&events_in_ui));
+            let mut dnd = Dnd::new(ui.ctx(), "hooks")
+                .on_drag_start(move |payload| e1.borrow_mut().push(format!("start {payload}")))
+                .on_drop(move |m: &DndMove<&str, ()>| {
+                    e2.borrow_mut().push(format!("drop {}", m.payload));
+                })
+                .on_cancel(move |payload| e3.borrow_mut().push(format!("cancel {payload}")))
+                .on_drag_end(move || e4.borrow_mut().push("end".to_owned()));
+            ui.horizontal(|ui| {
+                dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                let r = ui.add_sized([60.0, 60.0], egui::Label::new("Target"));
+                dnd.drop_zone(ui, &r, ());
+            });
+            dnd.finish(ui);
+        });
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let drag_to = |harness: &mut Harness<'_>, end: egui::Pos2| {
+        start_drag(harness, start);
+        move_to(harness, end);
+    };
+
+    // Dropped on the target
+    let target = harness.get_by_label("Target").rect().center();
+    drag_to(&mut harness, target);
+    assert_eq!(*events.borrow(), ["start payload"]);
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(*events.borrow(), ["start payload", "drop payload", "end"]);
+    events.borrow_mut().clear();
+
+    // Released where it can't be dropped
+    let nowhere = start + vec2(0.0, 50.0);
+    drag_to(&mut harness, nowhere);
+    harness.drop_at(nowhere);
+    harness.run();
+    assert_eq!(*events.borrow(), ["start payload", "cancel payload", "end"]);
+    events.borrow_mut().clear();
+
+    // Cancelled using Escape
+    drag_to(&mut harness, nowhere);
+    harness.key_press(egui::Key::Escape);
+    harness.run();
+    assert_eq!(*events.borrow(), ["start payload", "cancel payload", "end"]);
+    harness.drop_at(nowhere);
+    harness.run();
+    assert_eq!(events.borrow().len(), 3);
+}
+
+#[test]
+fn drag_metrics() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 60.0))
+        .with_step_dt(1.0 / 60.0)
+        .build_ui_state(
+            |ui, metrics: &mut Vec<DndDragMetrics>| {
+                let mut dnd: Dnd<_, ()> = Dnd::new(ui.ctx(), "fling");
+                dnd.draggable(ui, "item", |ui, _| (ui.button("Item"), ()));
+                metrics.extend(dnd.drag_metrics());
+                dnd.finish(ui);
+            },
+            vec![],
+        );
+    harness.run();
+    assert!(harness.state().is_empty());
+
+    let start = harness.get_by_label("Item").rect().center();
+    let end = start + vec2(100.0, 0.0);
+    start_drag(&mut harness, start);
+    step_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run_steps(1);
+
+    // The payload was flung to the right.
+    let metrics = harness.state();
+    let last = metrics.last().expect("no drag metrics");
+    assert!((last.distance - 100.0).abs() < 0.01, "{last:?}");
+    assert!(last.elapsed > 0.0, "{last:?}");
+    assert!(last.velocity.x > 0.0, "{last:?}");
+    assert!(metrics.windows(2).all(|w| w[0].elapsed <= w[1].elapsed));
+
+    // Metrics are only available while dragging.
+    let len = metrics.len();
+    harness.run();
+    assert_eq!(harness.state().len(), len);
+}
+
+#[test]
+fn payload_clip_rect_snapshot() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 60.0))
+        .build_ui(|ui| {
+            let panel = egui::Rect::from_min_size(egui::Pos2::ZERO, vec2(60.0, 60.0));
+            ui.painter()
+                .rect_filled(panel, 0.0, egui::Color32::from_gray(40));
+            let mut dnd: Dnd<_, ()> = Dnd::new(ui.ctx(), "panel").with_payload_clip_rect(panel);
+            dnd.draggable(ui, "item", |ui, _| (ui.button("Item"), ()));
+            dnd.finish(ui);
+        });
+    harness.run();
+
+    let start = harness.get_by_label("Item").rect().center();
+    let end = egui::pos2(60.0, start.y + 20.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.snapshot("dnd/payload_clip_rect");
+    harness.drop_at(end);
+    harness.run();
+}
+
+#[test]
+fn swap() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 60.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = SwapDnd::new(ui.ctx(), "slots");
+                ui.horizontal(|ui| {
+                    for (i, &item) in items.iter().enumerate() {
+                        dnd.swappable(ui, i, |ui, _| {
+                            (ui.add_sized([40.0, 40.0], egui::Label::new(item)), ())
+                        });
+                    }
+                });
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.swap(items);
+                }
+            },
+            vec!["a", "b", "c", "d"],
+        );
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let end = harness.get_by_label("c").rect().center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["c", "b", "a", "d"]);
+}
+
+#[cfg(feature = "extras")]
+#[test]
+fn reorderable_table_row() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(200.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = ReorderDnd::new(ui.ctx(), "table");
+                egui_extras::TableBuilder::new(ui)
+                    .striped(true)
+                    .column(egui_extras::Column::auto())
+                    .column(egui_extras::Column::remainder())
+                    .body(|mut body| {
+                        for (i, &item) in items.iter().enumerate() {
+                            body.row(20.0, |mut row| {
+                                dnd.reorderable_table_row(&mut row, i, |row| {
+                                    row.col(|ui| {
+                                        ui.label(item);
+                                    });
+                                });
+                            });
+                        }
+                    });
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    let handle = (harness.get_all_by_label("Drag to move"))
+        .next()
+        .map(|handle| handle.rect().center());
+    let start = handle.unwrap_or_default();
+    let end = harness.get_by_label("c").rect().center_bottom();
+    let end = egui::pos2(start.x, end.y);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["b", "c", "a"]);
+}
+
+#[test]
+fn spring_loaded() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, springs: &mut usize| {
+                let mut dnd = Dnd::new(ui.ctx(), "spring").with_spring_load_delay(0.5);
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Folder"));
+                    dnd.spring_loaded(ui, &r, || *springs += 1);
+                    dnd.drop_zone(ui, &r, ());
+                });
+                let _ = dnd.finish(ui);
+            },
+            0,
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let target = harness.get_by_label("Folder").rect().center();
+    start_drag(&mut harness, start);
+    step_to(&mut harness, target);
+    assert_eq!(*harness.state(), 0);
+
+    // Springs once after hovering for the delay.
+    for _ in 0..4 {
+        harness.step();
+    }
+    assert_eq!(*harness.state(), 1);
+
+    // Springs again after leaving and coming back.
+    harness.hover_at(start);
+    harness.step();
+    harness.hover_at(target);
+    harness.step();
+    assert_eq!(*harness.state(), 1);
+    for _ in 0..4 {
+        harness.step();
+    }
+    assert_eq!(*harness.state(), 2);
+    harness.drop_at(target);
+    harness.run();
+}
+
+#[test]
+fn drop_zone_with_hover_delay() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, dwell_frames: &mut usize| {
+                let mut dnd = Dnd::new(ui.ctx(), "dwell");
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    let r = ui.add_sized([60.0, 60.0], egui::Label::new("Folder"));
+                    dnd.drop_zone_with_hover_delay(ui, &r, (), 0.5, || *dwell_frames += 1);
+                });
+                let _ = dnd.finish(ui);
+            },
+            0,
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let target = harness.get_by_label("Folder").rect().center();
+    start_drag(&mut harness, start);
+    step_to(&mut harness, target);
+    assert_eq!(*harness.state(), 0);
+
+    // Called on every frame after hovering for the delay.
+    for _ in 0..4 {
+        harness.step();
+    }
+    let dwell_frames = *harness.state();
+    assert!(dwell_frames > 0);
+    harness.step();
+    assert_eq!(*harness.state(), dwell_frames + 1);
+
+    // Not called after leaving.
+    harness.hover_at(start);
+    harness.step();
+    assert_eq!(*harness.state(), dwell_frames + 1);
+    harness.drop_at(start);
+    harness.run();
+}
+
+#[test]
+fn drop_zone_with_hover_delay_before_payload() {
+    // The folders are added before the payload, so the payload isn't known
+    // when they are added.
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 160.0))
+        .build_ui_state(
+            |ui, dwells: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "dwell")
+                    .with_accept(|_: &&str, &folder: &&str| folder != "Locked");
+                ui.horizontal(|ui| {
+                    for folder in ["Open", "Locked"] {
+                        let r = ui.add_sized([60.0, 60.0], egui::Label::new(folder));
+                        dnd.drop_zone_with_hover_delay(ui, &r, folder, 0.5, || dwells.push(folder));
+                    }
+                });
+                dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                let _ = dnd.finish(ui);
+            },
+            vec![],
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let locked = harness.get_by_label("Locked").rect().center();
+    let open = harness.get_by_label("Open").rect().center();
+    start_drag(&mut harness, start);
+    step_to(&mut harness, locked);
+    for _ in 0..6 {
+        harness.step();
+    }
+    assert!(harness.state().is_empty());
+
+    harness.hover_at(open);
+    for _ in 0..6 {
+        harness.step();
+    }
+    assert!(!harness.state().is_empty());
+    assert!(harness.state().iter().all(|&folder| folder == "Open"));
+    harness.drop_at(start);
+    harness.run();
+}
+
+#[test]
+fn drop_zone_with_hover_delay_nested() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 160.0))
+        .build_ui_state(
+            |ui, dwells: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "nested");
+                dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                // A folder inside another folder, and a second folder
+                // overlapping the outer one.
+                let outer_rect =
+                    egui::Rect::from_min_size(egui::pos2(20.0, 40.0), vec2(140.0, 100.0));
+                let inner_rect = outer_rect.shrink(30.0);
+                let overlapping_rect = outer_rect.translate(vec2(100.0, 0.0));
+                for (name, rect) in [
+                    ("outer", outer_rect),
+                    ("inner", inner_rect),
+                    ("overlapping", overlapping_rect),
+                ] {
+                    let r = ui.interact(rect, ui.id().with(name), egui::Sense::hover());
+                    dnd.drop_zone_with_hover_delay(ui, &r, name, 0.5, || dwells.push(name));
+                }
+                let _ = dnd.finish(ui);
+            },
+            vec![],
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let dwells_at = |harness: &mut Harness<'_, Vec<&'static str>>, pos: egui::Pos2| {
+        harness.hover_at(pos);
+        for _ in 0..6 {
+            harness.step();
+        }
+        std::mem::take(harness.state_mut())
+    };
+    start_drag(&mut harness, start);
+    step_to(&mut harness, egui::pos2(90.0, 90.0));
+    harness.state_mut().clear();
+
+    // Only the innermost folder dwells.
+    let dwells = dwells_at(&mut harness, egui::pos2(90.0, 90.0));
+    assert!(!dwells.is_empty());
+    assert!(dwells.iter().all(|&folder| folder == "inner"));
+
+    // The smaller folder wins where they overlap.
+    let dwells = dwells_at(&mut harness, egui::pos2(40.0, 90.0));
+    assert!(!dwells.is_empty());
+    assert!(dwells.iter().all(|&folder| folder == "outer"));
+    let dwells = dwells_at(&mut harness, egui::pos2(200.0, 90.0));
+    assert!(!dwells.is_empty());
+    assert!(dwells.iter().all(|&folder| folder == "overlapping"));
+
+    harness.drop_at(start);
+    harness.run();
+}
+
+#[test]
+fn overlapping_drop_zones() {
+    for (outer_priority, expected) in [(0, "inner"), (1, "outer")] {
+        let mut harness = Harness::builder()
+            .with_size(vec2(240.0, 160.0))
+            .build_ui_state(
+                |ui, target: &mut Option<&'static str>| {
+                    let mut dnd = Dnd::new(ui.ctx(), "nested");
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    let outer_rect =
+                        egui::Rect::from_min_size(egui::pos2(20.0, 40.0), vec2(200.0, 100.0));
+                    let inner_rect = outer_rect.shrink(30.0);
+                    let inner =
+                        ui.interact(inner_rect, ui.id().with("inner"), egui::Sense::hover());
+                    let outer =
+                        ui.interact(outer_rect, ui.id().with("outer"), egui::Sense::hover());
+                    // The inner zone is added first, so it only wins by size.
+                    dnd.drop_zone(ui, &inner, "inner");
+                    dnd.drop_zone_with_priority(ui, &outer, "outer", outer_priority);
+                    if let DndResponse::MidDrag(m) = dnd.finish(ui) {
+                        *target = m.target;
+                    }
+                },
+                None,
+            );
+        harness.run();
+
+        let start = harness.get_by_label("Drag me").rect().center();
+        let end = egui::pos2(120.0, 90.0);
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        assert_eq!(*harness.state(), Some(expected));
+        harness.drop_at(end);
+        harness.run();
+    }
+}
+
+#[test]
+fn reorder_hysteresis() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, target: &mut Option<(usize, BeforeOrAfter)>| {
+                let mut dnd = ReorderDnd::new(ui.ctx(), "list");
+                for (i, item) in ["a", "b", "c", "d"].into_iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                *target = match dnd.finish(ui) {
+                    DndResponse::MidDrag(m) => m.target,
+                    _ => None,
+                };
+            },
+            None,
+        );
+    harness.run();
+
+    let start = harness.get_by_label("a").rect().center();
+    let middle = harness.get_by_label("c").rect().center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, middle + vec2(0.0, 6.0));
+    let after = Some((2, BeforeOrAfter::After));
+    assert_eq!(*harness.state(), after);
+
+    // Slightly past the midpoint, the target stays the same.
+    for y in [1.0, -1.0] {
+        harness.hover_at(middle + vec2(0.0, y));
+        harness.run();
+        assert_eq!(*harness.state(), after);
+    }
+
+    // Further past the midpoint, it changes.
+    harness.hover_at(middle - vec2(0.0, 6.0));
+    harness.run();
+    assert_ne!(*harness.state(), after);
+
+    harness.drop_at(middle);
+    harness.run();
+}
+
+#[test]
+fn hover_position_within_target() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, hover: &mut Option<DndHover>| {
+                let mut dnd = Dnd::new(ui.ctx(), "hover");
+                ui.horizontal(|ui| {
+                    dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                    let r = ui.add_sized([60.0, 80.0], egui::Label::new("Target"));
+                    dnd.drop_zone(ui, &r, ());
+                });
+                *hover = dnd.finish_with_hover(ui).1;
+            },
+            None,
+        );
+    harness.run();
+
+    let start = harness.get_by_label("Drag me").rect().center();
+    let target_rect = harness.get_by_label("Target").rect();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target_rect.center());
+    let mut hover_at = |pos: egui::Pos2| {
+        harness.hover_at(pos);
+        harness.run();
+        harness.state().expect("no hovered target")
+    };
+
+    let dir = egui::Direction::TopDown;
+    let hover = hover_at(target_rect.lerp_inside(vec2(0.5, 0.1)));
+    assert!((hover.fraction().y - 0.1).abs() < 0.05);
+    assert_eq!(hover.edge(dir, 0.25), Some(BeforeOrAfter::Before));
+    let hover = hover_at(target_rect.center());
+    assert!((hover.fraction() - vec2(0.5, 0.5)).length() < 0.05);
+    assert_eq!(hover.edge(dir, 0.25), None);
+    let hover = hover_at(target_rect.lerp_inside(vec2(0.5, 0.9)));
+    assert_eq!(hover.edge(dir, 0.25), Some(BeforeOrAfter::After));
+    assert_eq!(
+        hover.edge(egui::Direction::BottomUp, 0.25),
+        Some(BeforeOrAfter::Before)
+    );
+
+    harness.drop_at(target_rect.center());
+    harness.run();
+}
+
+#[test]
+fn reorder_within_section() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 160.0))
+        .build_ui_state(
+            |ui, sections: &mut [Vec<&'static str>; 2]| {
+                let mut dnd = ReorderDnd::<(usize, usize)>::new(ui.ctx(), "sections");
+                for (s, items) in sections.iter().enumerate() {
+                    dnd.set_section(s);
+                    ui.heading(format!("Section {s}"));
+                    for (i, &item) in items.iter().enumerate() {
+                        dnd.reorderable(ui, (s, i), |ui, _| (ui.label(item), ()));
+                    }
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    let ((s1, i), ((s2, j), place)) = (r.payload, r.target);
+                    assert_eq!(s1, s2, "dropped in another section");
+                    ReorderDndMove::new(i, (j, place)).reorder(&mut sections[s1]);
+                }
+            },
+            [vec!["a", "b"], vec!["c", "d"]],
+        );
+    harness.run();
+
+    // Dropping in another section moves the item to the closest place in its
+    // own section.
+    let target = harness.get_by_label("d").rect().center_bottom() - vec2(0.0, 1.0);
+    drag(&mut harness, "a", target);
+    assert_eq!(*harness.state(), [vec!["b", "a"], vec!["c", "d"]]);
+
+    let target = harness.get_by_label("b").rect().center_top() + vec2(0.0, 1.0);
+    drag(&mut harness, "a", target);
+    assert_eq!(*harness.state(), [vec!["a", "b"], vec!["c", "d"]]);
+
+    let target = harness.get_by_label("c").rect().center_top() + vec2(0.0, 1.0);
+    drag(&mut harness, "d", target);
+    assert_eq!(*harness.state(), [vec!["a", "b"], vec!["d", "c"]]);
+}
+
+#[test]
+fn pinned_items_stay_in_place() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 160.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    // Uppercase items are pinned.
+                    if item.chars().all(char::is_uppercase) {
+                        dnd.pinned(ui, |ui| ui.label(item));
+                    } else {
+                        dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                    }
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+            },
+            vec!["H", "a", "b", "P", "c"],
+        );
+    harness.run();
+
+    let target = harness.get_by_label("H").rect().center_top() + vec2(0.0, 1.0);
+    drag(&mut harness, "b", target);
+    assert_eq!(*harness.state(), ["H", "b", "a", "P", "c"]);
+
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    drag(&mut harness, "a", target);
+    assert_eq!(*harness.state(), ["H", "b", "a", "P", "c"]);
+
+    let target = harness.get_by_label("H").rect().center_top() + vec2(0.0, 1.0);
+    drag(&mut harness, "c", target);
+    assert_eq!(*harness.state(), ["H", "b", "a", "P", "c"]);
+}
+
+#[test]
+fn reorder_collections() {
+    let m = ReorderDndMove::new(0, (2, BeforeOrAfter::After));
+
+    let mut array = ['a', 'b', 'c', 'd'];
+    m.reorder(&mut array);
+    assert_eq!(array, ['b', 'c', 'a', 'd']);
+
+    let mut deque = std::collections::VecDeque::from(['a', 'b', 'c', 'd']);
+    deque.rotate_left(1);
+    m.reorder(&mut deque);
+    assert_eq!(deque, ['c', 'd', 'b', 'a']);
+
+    let mut small: smallvec::SmallVec<[char; 4]> = smallvec::smallvec!['a', 'b', 'c', 'd'];
+    m.reorder(&mut small);
+    assert_eq!(small.as_slice(), ['b', 'c', 'a', 'd']);
+}
+
+#[test]
+#[cfg(feature = "indexmap")]
+fn reorder_index_map() {
+    let m = ReorderDndMove::new(3, (0, BeforeOrAfter::Before));
+
+    let mut map = indexmap::IndexMap::from([('a', 1), ('b', 2), ('c', 3), ('d', 4)]);
+    m.reorder(&mut map);
+    assert_eq!(map.keys().collect::<String>(), "dabc");
+
+    let mut set = indexmap::IndexSet::from(['a', 'b', 'c', 'd']);
+    m.reorder(&mut set);
+    assert_eq!(set.iter().collect::<String>(), "dabc");
+
+    let by_key = ReorderDndMove::new('a', ('c', BeforeOrAfter::After));
+    assert!(by_key.reorder_by_key(&mut map, |&k| k));
+    assert_eq!(map.keys().collect::<String>(), "dbca");
+}
+
+#[test]
+fn reorder_by_key() {
+    let mut v = vec![(1, "one"), (2, "two"), (3, "three"), (4, "four")];
+    // For example, only the odd elements are shown.
+    let m = ReorderDndMove::new(3, (1, BeforeOrAfter::Before));
+    assert!(m.reorder_by_key(&mut v, |&(k, _)| k));
+    assert_eq!(v, [(3, "three"), (1, "one"), (2, "two"), (4, "four")]);
+
+    let m = ReorderDndMove::new(5, (1, BeforeOrAfter::Before));
+    assert!(!m.reorder_by_key(&mut v, |&(k, _)| k));
+    assert_eq!(v, [(3, "three"), (1, "one"), (2, "two"), (4, "four")]);
+}
+
+#[test]
+fn inverse_moves() {
+    use BeforeOrAfter::{After, Before};
+
+    let original = vec!['a', 'b', 'c', 'd', 'e'];
+    for i in 0..5 {
+        for j in 0..5 {
+            for side in [Before, After] {
+                let m = ReorderDndMove::new(i, (j, side));
+                let mut v = original.clone();
+                m.reorder(&mut v);
+                m.inverse().reorder(&mut v);
+                assert_eq!(v, original, "{m:?}");
+            }
+        }
+    }
+
+    let m = SwapDndMove::new(1, 3);
+    let mut v = original.clone();
+    m.swap(&mut v);
+    m.inverse().swap(&mut v);
+    assert_eq!(v, original);
+
+    let m = TreeDndMove::new(
+        TreeNode {
+            node: 'x',
+            parent: None,
+            index: 0,
+        },
+        TreeTarget {
+            parent: Some('y'),
+            index: 2,
+        },
+    )
+    .tree_move();
+    let inverse = m.inverse();
+    assert_eq!((inverse.old_parent, inverse.old_index), (Some('y'), 2));
+    assert_eq!((inverse.new_parent, inverse.index), (None, 0));
+}
+
+#[test]
+fn insertion_index() {
+    use BeforeOrAfter::{After, Before};
+
+    let original = vec!['a', 'b', 'c', 'd', 'e'];
+    for i in 0..5 {
+        for j in 0..5 {
+            for side in [Before, After] {
+                let m = ReorderDndMove::new(i, (j, side));
+                let mut expected = original.clone();
+                m.reorder(&mut expected);
+                let mut v = original.clone();
+                let elem = v.remove(i);
+                v.insert(m.insertion_index(v.len() + 1), elem);
+                assert_eq!(v, expected, "{m:?}");
+            }
+        }
+    }
+
+    // `a` after `c` in `[a, b, c, d]`
+    let m = ReorderDndMove::new(0, (2, After));
+    assert_eq!(m.insertion_index(4), 2);
+}
+
+#[test]
+fn is_noop() {
+    use BeforeOrAfter::{After, Before};
+
+    let original = vec!['a', 'b', 'c', 'd'];
+    for i in 0..4 {
+        for j in 0..4 {
+            for side in [Before, After] {
+                let m = ReorderDndMove::new(i, (j, side));
+                let mut v = original.clone();
+                m.reorder(&mut v);
+                assert_eq!(m.is_noop(), v == original, "{m:?}");
+            }
+        }
+    }
+}
+
+#[test]
+fn map_response() {
+    type Target = ((usize, Option<usize>), BeforeOrAfter);
+    let done: DndResponse<usize, Target> =
+        DndResponse::DoneDragging(DndMove::new(3, ((1, Some(2)), BeforeOrAfter::After)));
+    let mid: DndResponse<usize, Target> =
+        DndResponse::MidDrag(DndMove::new(3, Some(((1, None), BeforeOrAfter::Before))));
+
+    // Only keep targets that are cards, not empty columns.
+    let card = |((column, card), side): Target| Some(((column, card?), side));
+    assert_eq!(
+        done.and_then_target(card),
+        DndResponse::DoneDragging(DndMove::new(3, ((1, 2), BeforeOrAfter::After))),
+    );
+    assert_eq!(
+        mid.and_then_target(card),
+        DndResponse::MidDrag(DndMove::new(3, None)),
+    );
+    assert_eq!(
+        done.map_payload(|i| i * 2)
+            .map_target(|((column, _), _)| column),
+        DndResponse::DoneDragging(DndMove::new(6, 1)),
+    );
+    assert_eq!(
+        DndResponse::<usize, Target>::Cancelled(3).map_payload(|i| i + 1),
+        DndResponse::Cancelled(4),
+    );
+    assert_eq!(done.and_then_target(|_| None::<()>), DndResponse::Inactive);
+}
+
+#[test]
+fn reorderable_list_one_call() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            |ui, (items, changed): &mut (Vec<(&'static str, bool)>, bool)| {
+                *changed |= reorderable_list(ui, "list", items, |ui, (name, checked)| {
+                    ui.checkbox(checked, *name);
+                });
+            },
+            (vec![("a", false), ("b", false), ("c", false)], false),
+        );
+    harness.run();
+
+    // Rows can be edited.
+    harness.get_by_label("b").click();
+    harness.run();
+    assert_eq!(harness.state().0, [("a", false), ("b", true), ("c", false)]);
+    assert!(!harness.state().1);
+
+    let handle = (harness.get_all_by_label("Drag to reorder, or press Alt and an arrow key"))
+        .next()
+        .map(|handle| handle.rect().center());
+    let start = handle.expect("no drag handle");
+    let target = harness.get_by_label("c").rect().center_bottom() - vec2(0.0, 1.0);
+    let target = egui::pos2(start.x, target.y);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(harness.state().0, [("b", true), ("c", false), ("a", false)]);
+    assert!(harness.state().1);
+}
+
+#[test]
+fn trash_zone() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 160.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                dnd.trash_zone(ui);
+                match dnd.finish(ui) {
+                    DndResponse::DoneDragging(r) => r.reorder(items),
+                    DndResponse::Deleted(i) => {
+                        items.remove(i);
+                    }
+                    _ => (),
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+    assert!(harness.query_by_label("Drop here to delete").is_none());
+
+    let start = harness.get_by_label("b").rect().center();
+    start_drag(&mut harness, start);
+    harness.hover_at(start + vec2(0.0, 2.0));
+    harness.run();
+    // The trash zone only appears while dragging.
+    let end = harness.get_by_label("Drop here to delete").rect().center();
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(*harness.state(), ["a", "c"]);
+    assert!(harness.query_by_label("Drop here to delete").is_none());
+}
+
+#[test]
+fn trash_zone_uses_payload_position() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(120.0, 280.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "list");
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| {
+                        (ui.add_sized(vec2(100.0, 60.0), egui::Label::new(item)), ())
+                    });
+                }
+                dnd.trash_zone(ui);
+                if let DndResponse::Deleted(i) = dnd.finish(ui) {
+                    items.remove(i);
+                }
+            },
+            vec!["a", "b", "c"],
+        );
+    harness.run();
+
+    // Grab the bottom of the object so that the payload is above the pointer.
+    let start = harness.get_by_label("a").rect().center_bottom() - vec2(0.0, 2.0);
+    start_drag(&mut harness, start);
+    harness.hover_at(start + vec2(0.0, 2.0));
+    harness.run();
+    let trash = harness.get_by_label("Drop here to delete").rect();
+    let end = trash.center_top() + vec2(0.0, 2.0);
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    assert_eq!(
+        harness.state().len(),
+        3,
+        "payload was not over the trash zone"
+    );
+}
+
+#[test]
+fn trash_zone_custom_text() {
+    let mut harness = Harness::new_ui_state(
+        |ui, items: &mut Vec<&'static str>| {
+            let mut dnd = Dnd::new(ui.ctx(), "list").with_text(DndText {
+                trash_zone_description: "Déposer ici pour supprimer".into(),
+                ..DndText::default()
+            });
+            for (i, &item) in items.iter().enumerate() {
+                dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+            }
+            dnd.trash_zone(ui);
+            dnd.finish(ui);
+        },
+        vec!["a", "b"],
+    );
+    harness.run();
+
+    let start = harness.get_by_label("b").rect().center();
+    start_drag(&mut harness, start);
+    harness.hover_at(start + vec2(0.0, 2.0));
+    harness.run();
+    assert!(
+        harness
+            .query_by_label("Déposer ici pour supprimer")
+            .is_some()
+    );
+    assert!(harness.query_by_label("Drop here to delete").is_none());
+}
+
+#[test]
+fn tab_bar() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 60.0))
+        .build_ui_state(
+            |ui, (tabs, active): &mut (Vec<&'static str>, usize)| {
+                let r = TabBar::new("tabs")
+                    .closable(true)
+                    .show(ui, tabs, *active, |tab| (*tab).into());
+                r.apply(tabs, active);
+            },
+            (vec!["One", "Two", "Three"], 0),
+        );
+    harness.run();
+
+    harness.get_by_label("Two").click();
+    harness.run();
+    assert_eq!(harness.state().1, 1);
+
+    // The active tab stays selected after reordering.
+    let target = harness.get_by_label("Three").rect().right_center() - vec2(1.0, 0.0);
+    drag(&mut harness, "Two", target);
+    assert_eq!(*harness.state(), (vec!["One", "Three", "Two"], 2));
+
+    harness
+        .get_all_by_label("×")
+        .next()
+        .expect("no close button")
+        .click();
+    harness.run();
+    assert_eq!(*harness.state(), (vec!["Three", "Two"], 1));
+}
+
+#[test]
+fn drag_source_factory() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(240.0, 120.0))
+        .build_ui_state(
+            |ui, document: &mut Vec<String>| {
+                let mut dnd = Dnd::new(ui.ctx(), "palette");
+                ui.horizontal(|ui| {
+                    ui.vertical(|ui| {
+                        for kind in ["Note", "Task"] {
+                            dnd.drag_source_factory(
+                                ui,
+                                kind,
+                                || format!("{kind} {}", document.len() + 1),
+                                |ui, _| (ui.button(kind), ()),
+                            );
+                        }
+                    });
+                    let r = ui.add_sized([100.0, 100.0], egui::Label::new("Document"));
+                    dnd.drop_zone(ui, &r, ());
+                });
+                if let Some(m) = dnd.finish(ui).if_done_dragging() {
+                    document.push(m.payload);
+                }
+            },
+            vec![],
+        );
+    harness.run();
+
+    for kind in ["Note", "Task", "Note"] {
+        let start = harness.get_by_label(kind).rect().center();
+        let end = harness.get_by_label("Document").rect().center();
+        start_drag(&mut harness, start);
+        move_to(&mut harness, end);
+        // The source stays in place while a copy follows the pointer.
+        assert_eq!(harness.get_all_by_label(kind).count(), 2);
+        harness.drop_at(end);
+        harness.run();
+        // The source is never removed.
+        harness.get_by_label(kind);
+    }
+    assert_eq!(*harness.state(), ["Note 1", "Task 2", "Note 3"]);
+}
+
+/// Returns a harness for a reorderable list that records screen reader
+/// announcements.
+fn announcements_harness(text: DndText) -> Harness<'static, (Vec<&'static str>, Vec<String>)> {
+    Harness::builder()
+        .with_size(vec2(120.0, 120.0))
+        .build_ui_state(
+            move |ui, (items, announcements): &mut (Vec<&'static str>, Vec<String>)| {
+                let mut dnd = Dnd::new(ui.ctx(), "list").with_text(text.clone());
+                for (i, &item) in items.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                    r.reorder(items);
+                }
+                ui.output(|output| {
+                    for event in &output.events {
+                        if let egui::output::OutputEvent::ValueChanged(info) = event
+                            && let Some(label) = &info.label
+                            && announcements.last() != Some(label)
+                        {
+                            announcements.push(label.clone());
+                        }
+                    }
+                });
+            },
+            (vec!["a", "b", "c"], vec![]),
+        )
+}
+
+#[test]
+fn drag_announcements() {
+    let mut harness = announcements_harness(DndText::default());
+    harness.run();
+
+    let target = harness.get_by_label("c").rect().center_bottom();
+    drag(&mut harness, "a", target);
+    let (items, announcements) = harness.state();
+    assert_eq!(*items, ["b", "c", "a"]);
+    assert_eq!(
+        announcements.first().map(String::as_str),
+        Some("Picked up item 1 of 3")
+    );
+    assert!(announcements.contains(&"Moved after item 3".to_owned()));
+    assert_eq!(announcements.last().map(String::as_str), Some("Dropped"));
+}
+
+#[test]
+fn drag_announcements_middle_item() {
+    let mut harness = announcements_harness(DndText::default());
+    harness.run();
+
+    let target = harness.get_by_label("a").rect().center_top();
+    drag(&mut harness, "b", target);
+    let (items, announcements) = harness.state();
+    assert_eq!(*items, ["b", "a", "c"]);
+    assert_eq!(
+        announcements.first().map(String::as_str),
+        Some("Picked up item 2 of 3")
+    );
+    assert!(announcements.contains(&"Moved before item 1".to_owned()));
+}
+
+#[test]
+fn drag_announcements_accesskit() {
+    let mut harness = announcements_harness(DndText::default());
+    harness.run();
+
+    // Announcements are live regions in the AccessKit tree, which only exist
+    // on the frame of the announcement.
+    let start = harness.get_by_label("a").rect().center();
+    start_drag(&mut harness, start);
+    let mut found = false;
+    for _ in 0..8 {
+        harness.hover_at(start + vec2(0.0, 2.0));
+        harness.step();
+        let live_region = egui_kittest::kittest::by()
+            .label_contains("Picked up item 1 of 3")
+            .predicate(|node| node.live() == egui::accesskit::Live::Polite);
+        found |= harness.query(live_region).is_some();
+    }
+    assert!(found);
+    harness.drop_at(start);
+    harness.run();
+}
+
+#[test]
+fn drag_announcements_custom_text() {
+    let mut harness = announcements_harness(DndText {
+        picked_up_item: "Élément {index} sur {count} saisi".into(),
+        moved_before_item: "Déplacé avant l'élément {index}".into(),
+        dropped: "Déposé".into(),
+        ..DndText::default()
+    });
+    harness.run();
+
+    let target = harness.get_by_label("a").rect().center_top();
+    drag(&mut harness, "b", target);
+    let (_, announcements) = harness.state();
+    assert_eq!(
+        announcements.first().map(String::as_str),
+        Some("Élément 2 sur 3 saisi")
+    );
+    assert!(announcements.contains(&"Déplacé avant l'élément 1".to_owned()));
+    assert_eq!(announcements.last().map(String::as_str), Some("Déposé"));
+}
+
+#[derive(Debug, Clone, PartialEq)]
+struct KanbanColumn {
+    title: &'static str,
+    cards: Vec<&'static str>,
+}
+impl AsRef<[&'static str]> for KanbanColumn {
+    fn as_ref(&self) -> &[&'static str] {
+        &self.cards
+    }
+}
+impl AsMut<Vec<&'static str>> for KanbanColumn {
+    fn as_mut(&mut self) -> &mut Vec<&'static str> {
+        &mut self.cards
+    }
+}
+
+#[test]
+fn kanban() {
+    let column = |title, cards| KanbanColumn { title, cards };
+    let mut harness = Harness::builder()
+        .with_size(vec2(400.0, 200.0))
+        .build_ui_state(
+            |ui, columns: &mut Vec<KanbanColumn>| {
+                Kanban::new("board")
+                    .column_width(100.0)
+                    .show(
+                        ui,
+                        columns,
+                        |ui, _, column| {
+                            ui.strong(column.title);
+                        },
+                        |ui, card| {
+                            ui.label(*card);
+                        },
+                    )
+                    .apply(columns);
+            },
+            vec![
+                column("Todo", vec!["a", "b"]),
+                column("Doing", vec!["c"]),
+                column("Done", vec![]),
+            ],
+        );
+    harness.run();
+
+    // Drag into an empty column.
+    let target = harness.get_by_label("Done").rect().center_bottom() + vec2(0.0, 20.0);
+    drag(&mut harness, "a", target);
+    assert_eq!(harness.state()[0].cards, ["b"]);
+    assert_eq!(harness.state()[2].cards, ["a"]);
+
+    // Drag between columns.
+    let target = harness.get_by_label("c").rect().center_bottom();
+    drag(&mut harness, "b", target);
+    assert_eq!(harness.state()[0].cards, [] as [&str; 0]);
+    assert_eq!(harness.state()[1].cards, ["c", "b"]);
+
+    // Reorder columns using the handle in the header.
+    let handle = harness
+        .get_all_by_label("Drag to reorder, or press Alt and an arrow key")
+        .next()
+        .expect("no column handle");
+    let start = handle.rect().center();
+    let end = harness.get_by_label("Done").rect().right_center() + vec2(20.0, 0.0);
+    start_drag(&mut harness, start);
+    move_to(&mut harness, end);
+    harness.drop_at(end);
+    harness.run();
+    let titles: Vec<_> = harness.state().iter().map(|c| c.title).collect();
+    assert_eq!(titles, ["Doing", "Done", "Todo"]);
+}
+
+#[test]
+fn wrapped_reorder_drop_zones() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(130.0, 120.0))
+        .build_ui_state(
+            |ui, items: &mut Vec<&'static str>| {
+                ui.set_max_width(120.0);
+                ui.horizontal_wrapped(|ui| {
+                    let mut dnd = ReorderDnd::new(ui.ctx(), "wrapped");
+                    for (i, &item) in items.iter().enumerate() {
+                        let r = dnd.draggable(ui, i, |ui, _| {
+                            let button =
+                                egui::Button::new(item).wrap_mode(egui::TextWrapMode::Extend);
+                            (ui.add(button), ())
+                        });
+                        dnd.reorder_drop_zone_before_after(ui, &r.response, i);
+                    }
+                    if let Some(r) = dnd.finish(ui).if_done_dragging() {
+                        r.reorder(items);
+                    }
+                });
+            },
+            vec!["aaaaaa", "bbbbbbbbbbbb", "c"],
+        );
+    harness.run();
+
+    // "bbbbbbbbbbbb" wraps, leaving space at the end of the first row.
+    let a = harness.get_by_label("aaaaaa").rect();
+    let b = harness.get_by_label("bbbbbbbbbbbb").rect();
+    assert!(b.top() > a.bottom());
+    assert!(b.right() > a.right() + 20.0);
+
+    // Dropping past the end of the first row inserts at the line break, even
+    // though "bbbbbbbbbbbb" is closer.
+    let target = egui::pos2(b.right() - 5.0, a.center().y);
+    let start = harness.get_by_label("c").rect().center();
+    start_drag(&mut harness, start);
+    move_to(&mut harness, target);
+    harness.drop_at(target);
+    harness.run();
+    assert_eq!(*harness.state(), ["aaaaaa", "c", "bbbbbbbbbbbb"]);
+}
+
+#[test]
+fn drop_zone_in_window() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(300.0, 200.0))
+        .build_ui_state(
+            |ui, dropped: &mut Vec<&'static str>| {
+                let mut dnd = Dnd::new(ui.ctx(), "layers");
+                dnd.draggable(ui, "payload", |ui, _| (ui.button("Drag me"), ()));
+                let r = ui.add_sized(ui.available_size(), egui::Label::new("Panel"));
+                dnd.drop_zone(ui, &r, "panel");
+                egui::Window::new("Inspector")
+                    .fixed_pos([150.0, 50.0])
+                    .fixed_size([100.0, 100.0])
+                    .show(ui.ctx(), |ui| {
+                        let r = ui.add_sized([60.0, 30.0], egui::Label::new("Window zone"));
+                        dnd.drop_zone(ui, &r, "window");
+                    });
+                if let Some(m) = dnd.finish(ui).if_done_dragging() {
+                    dropped.push(m.target);
+                }
+            },
+            vec![],
+        );
+    harness.run();
+
+    let zone = harness.get_by_label("Window zone").rect();
+    // Inside the window but not on its drop zone, over the panel's drop zone
+    let covered = zone.right_center() + vec2(30.0, 0.0);
+    for target in [zone.center(), covered] {
+        let start = harness.get_by_label("Drag me").rect().center();
+        start_drag(&mut harness, start);
+        move_to(&mut harness, target);
+        harness.drop_at(target);
+        harness.run();
+    }
+    assert_eq!(*harness.state(), ["window"]);
+}
+
+#[test]
+fn drag_between_viewports_in_group() {
+    let ctx = egui::Context::default();
+    let second = egui::ViewportId::from_hash_of("second");
+    let viewport_rect = |viewport| match viewport {
+        egui::ViewportId::ROOT => {
+            egui::Rect::from_min_size(egui::pos2(0.0, 0.0), vec2(200.0, 200.0))
+        }
+        _ => egui::Rect::from_min_size(egui::pos2(300.0, 0.0), vec2(200.0, 200.0)),
+    };
+    let mut lists = [vec!["a", "b"], vec!["x"]];
+    let run = |viewport, events: Vec<egui::Event>, lists: &mut [Vec<&'static str>; 2]| {
+        let mut raw_input = egui::RawInput {
+            viewport_id: viewport,
+            screen_rect: Some(egui::Rect::from_min_size(
+                egui::Pos2::ZERO,
+                vec2(200.0, 200.0),
+            )),
+            events,
+            ..Default::default()
+        };
+        for v in [egui::ViewportId::ROOT, second] {
+            raw_input.viewports.insert(
+                v,
+                egui::ViewportInfo {
+                    inner_rect: Some(viewport_rect(v)),
+                    ..Default::default()
+                },
+            );
+        }
+        let _ = ctx.run_ui(raw_input, |ui| {
+            let [source, receiver] = lists;
+            if viewport == egui::ViewportId::ROOT {
+                let mut dnd =
+                    ReorderDnd::new(ui.ctx(), "source").with_group("lists", |_: &'static str| None);
+                for (i, &item) in source.iter().enumerate() {
+                    dnd.reorderable(ui, i, |ui, _| (ui.label(item), ()));
+                }
+                if let DndResponse::DroppedElsewhere(m) = dnd.finish(ui) {
+                    source.remove(m.payload);
+                }
+            } else {
+                // The source may have already removed the payload.
+                let mut dnd = Dnd::<&'static str, usize>::new(ui.ctx(), "receiver")
+                    .with_group("lists", |i: usize| ["a", "b"].get(i).copied());
+                let r = ui.add_sized([100.0, 100.0], egui::Label::new(receiver[0]));
+                dnd.drop_zone(ui, &r, 0);
+                if let Some(m) = dnd.finish(ui).if_done_dragging() {
+                    receiver.insert(m.target, m.payload);
+                }
+            }
+        });
+    };
+
+    let moved = |pos| egui::Event::PointerMoved(pos);
+    let button = |pos, pressed| egui::Event::PointerButton {
+        pos,
+        button: egui::PointerButton::Primary,
+        pressed,
+        modifiers: egui::Modifiers::NONE,
+    };
+    // Measure the source.
+    run(egui::ViewportId::ROOT, vec![], &mut lists);
+    run(egui::ViewportId::ROOT, vec![], &mut lists);
+    let start = egui::pos2(4.0, 8.0);
+    // Drop onto the receiver in the second viewport, which is at x=300 on the
+    // screen.
+    let target = egui::pos2(350.0, 20.0);
+    run(egui::ViewportId::ROOT, vec![moved(start)], &mut lists);
+    run(
+        egui::ViewportId::ROOT,
+        vec![button(start, true)],
+        &mut lists,
+    );
+    for t in [0.02, 0.25, 0.5, 0.75, 1.0] {
+        run(
+            egui::ViewportId::ROOT,
+            vec![moved(start.lerp(target, t))],
+            &mut lists,
+        );
+        run(second, vec![], &mut lists);
+    }
+    run(
+        egui::ViewportId::ROOT,
+        vec![button(target, false)],
+        &mut lists,
+    );
+    run(second, vec![], &mut lists);
+    run(egui::ViewportId::ROOT, vec![], &mut lists);
+    assert_eq!(lists, [vec!["b"], vec!["a", "x"]]);
+}
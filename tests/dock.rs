@@ -0,0 +1,93 @@
+//! Layout and interaction tests for [`hcegui::dock`].
+
+#![cfg(all(feature = "dock", feature = "egui_0_34"))]
+
+use egui::vec2;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable;
+use hcegui::dock::{DockArea, DockLayout, DockNode, DockPosition};
+
+fn three_panels() -> DockLayout<&'static str> {
+    DockLayout::new(DockNode::left_right(
+        DockNode::Panel("a"),
+        DockNode::top_bottom(DockNode::Panel("b"), DockNode::Panel("c")),
+    ))
+}
+
+#[test]
+fn move_panel_splits_target() {
+    let mut layout = three_panels();
+    assert!(layout.move_panel(&"c", &"a", DockPosition::Top));
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::left_right(
+            DockNode::top_bottom(DockNode::Panel("c"), DockNode::Panel("a")),
+            DockNode::Panel("b"),
+        )),
+    );
+}
+
+#[test]
+fn move_panel_to_center_swaps() {
+    let mut layout = three_panels();
+    assert!(layout.move_panel(&"a", &"c", DockPosition::Center));
+    assert_eq!(layout.panels(), [&"c", &"b", &"a"]);
+}
+
+#[test]
+fn move_panel_onto_itself_does_nothing() {
+    let mut layout = three_panels();
+    assert!(!layout.move_panel(&"b", &"b", DockPosition::Left));
+    assert!(!layout.move_panel(&"b", &"z", DockPosition::Left));
+    assert_eq!(layout, three_panels());
+}
+
+#[test]
+fn remove_panel_collapses_split() {
+    let mut layout = three_panels();
+    assert_eq!(layout.remove_panel(&"b"), Some("b"));
+    assert_eq!(
+        layout,
+        DockLayout::new(DockNode::left_right(
+            DockNode::Panel("a"),
+            DockNode::Panel("c"),
+        )),
+    );
+}
+
+#[test]
+fn drag_title_onto_hint() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(400.0, 300.0))
+        .build_ui_state(
+            |ui, layout: &mut DockLayout<&'static str>| {
+                DockArea::new("dock").show(ui, layout, |p| p.to_string(), |_, _| ());
+            },
+            three_panels(),
+        );
+    harness.run();
+
+    // Drop "a" onto the right-hand hint of "c", which fills the bottom right
+    // quarter of the area.
+    let start = harness.get_by_label("a").rect().center();
+    let c_center = egui::pos2(300.0, 225.0);
+    let to = c_center + vec2(32.0, 0.0);
+    harness.hover_at(start);
+    harness.run();
+    harness.drag_at(start);
+    harness.run();
+    for t in [0.25, 0.5, 0.75, 1.0] {
+        harness.hover_at(start.lerp(to, t));
+        harness.run();
+    }
+    harness.drop_at(to);
+    harness.run();
+
+    assert_eq!(
+        *harness.state(),
+        DockLayout::new(DockNode::top_bottom(
+            DockNode::Panel("b"),
+            DockNode::left_right(DockNode::Panel("c"), DockNode::Panel("a")),
+        )),
+    );
+}
@@ -0,0 +1,25 @@
+//! Tests for [`hcegui::inspector`].
+
+#![cfg(all(feature = "inspector", feature = "dnd", feature = "egui_0_34"))]
+
+use egui::vec2;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable;
+use hcegui::dnd::Dnd;
+
+#[test]
+fn shows_dnd_environments() {
+    let mut harness = Harness::builder()
+        .with_size(vec2(400.0, 400.0))
+        .build_ui(|ui| {
+            let mut dnd: Dnd<&str, &str> = Dnd::new(ui.ctx(), "inspected");
+            dnd.draggable(ui, "payload", |ui, _| (ui.label("Drag me"), ()));
+            dnd.finish(ui);
+
+            hcegui::inspector::inspector_ui(ui);
+        });
+    harness.run();
+
+    harness.get_by_label("Drag and drop (1)");
+    harness.get_by_label(&egui::Id::new("inspected").short_debug_format());
+}
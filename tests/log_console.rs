@@ -0,0 +1,104 @@
+//! Interaction tests for [`hcegui::log_console`].
+
+#![cfg(all(feature = "log_console", feature = "egui_0_34"))]
+
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable;
+use hcegui::log_console::{LogBuffer, LogConsole, LogLevel};
+
+fn console_harness() -> Harness<'static, LogConsole> {
+    let buffer = LogBuffer::new(100);
+    buffer.push(LogLevel::Info, "app", "starting up");
+    buffer.push(LogLevel::Error, "app", "\x1b[31mdisk full\x1b[0m");
+    Harness::new_ui_state(
+        |ui, console: &mut LogConsole| console.show(ui),
+        LogConsole::new(buffer),
+    )
+}
+
+#[test]
+fn level_filter_hides_messages() {
+    let mut harness = console_harness();
+    harness.run();
+    assert!(harness.query_by_label("starting up").is_some());
+
+    harness.get_by_label("INFO 1").click();
+    harness.run();
+    assert!(harness.query_by_label("starting up").is_none());
+    assert!(harness.query_by_label("disk full").is_some());
+}
+
+#[test]
+fn search_ignores_escape_codes() {
+    let mut harness = console_harness();
+    harness.run();
+
+    let search_box = egui::accesskit::Role::TextInput;
+    harness.get_by_role(search_box).focus();
+    harness.run();
+    harness.get_by_role(search_box).type_text("disk");
+    harness.run();
+    assert!(harness.query_by_label("starting up").is_none());
+    assert!(harness.query_by_label("disk full").is_some());
+}
+
+#[test]
+fn buffer_discards_oldest() {
+    let buffer = LogBuffer::new(2);
+    for i in 0..3 {
+        buffer.push(LogLevel::Info, "app", format!("message {i}"));
+    }
+    let messages: Vec<String> = buffer.entries().into_iter().map(|e| e.message).collect();
+    assert_eq!(messages, ["message 1", "message 2"]);
+}
+
+#[test]
+fn default_buffer_keeps_messages() {
+    let buffer = LogBuffer::default();
+    for i in 0..100 {
+        buffer.push(LogLevel::Info, "app", format!("message {i}"));
+    }
+    assert_eq!(buffer.len(), 100);
+}
+
+#[test]
+fn console_shows_newest_after_discarding() {
+    let buffer = LogBuffer::new(2);
+    buffer.push(LogLevel::Info, "app", "message 0");
+    let mut harness = Harness::new_ui_state(
+        |ui, console: &mut LogConsole| console.show(ui),
+        LogConsole::new(buffer.clone()),
+    );
+    harness.run();
+    for i in 1..4 {
+        buffer.push(LogLevel::Info, "app", format!("message {i}"));
+    }
+    harness.run();
+    assert!(harness.query_by_label("message 1").is_none());
+    assert!(harness.query_by_label("message 2").is_some());
+    assert!(harness.query_by_label("message 3").is_some());
+}
+
+#[cfg(feature = "tracing")]
+#[test]
+fn tracing_layer_captures_events() {
+    use tracing_subscriber::layer::SubscriberExt;
+
+    let buffer = LogBuffer::new(100);
+    let subscriber =
+        tracing_subscriber::registry().with(buffer.tracing_layer(tracing::Level::INFO));
+    tracing::subscriber::with_default(subscriber, || {
+        tracing::info!(target: "app", count = 3, "loaded files");
+        tracing::debug!(target: "app", "ignored");
+        tracing::error!(target: "net", "connection lost");
+    });
+
+    let lines: Vec<String> = buffer.entries().iter().map(|e| e.to_plain_text()).collect();
+    assert_eq!(
+        lines,
+        [
+            "[INFO] app: loaded files count=3",
+            "[ERROR] net: connection lost"
+        ],
+    );
+}
@@ -0,0 +1,52 @@
+//! Parsing tests for [`hcegui::theme`].
+
+#![cfg(all(feature = "theme", feature = "dnd", feature = "ansi"))]
+
+use hcegui::theme::{Theme, ThemeError};
+
+#[test]
+fn partial_toml_keeps_defaults() {
+    let theme = Theme::from_toml(
+        "[dnd]\n\
+         payload_opacity = 0.5\n\
+         \n\
+         [ansi]\n\
+         red = [255, 80, 80, 255]\n",
+    )
+    .expect("valid theme");
+
+    assert_eq!(theme.dnd.payload_opacity, 0.5);
+    assert_eq!(theme.dnd.drop_zone_stroke_width, 2.0);
+    assert_eq!(
+        theme.ansi.red,
+        Some(egui::Color32::from_rgba_premultiplied(255, 80, 80, 255)),
+    );
+    assert_eq!(theme.ansi.yellow, None);
+}
+
+#[test]
+fn json_round_trip() {
+    let mut theme = Theme::default();
+    theme.dnd.reorder_stroke_width = 4.0;
+    theme.ansi.light_mode_brightness = 0.25;
+
+    let json = serde_json::to_string(&theme).expect("serializable theme");
+    let parsed = Theme::from_json(&json).expect("valid theme");
+    assert_eq!(parsed.dnd.reorder_stroke_width, 4.0);
+    assert_eq!(parsed.ansi.light_mode_brightness, 0.25);
+
+    let parsed = Theme::from_toml(&theme.to_toml()).expect("valid theme");
+    assert_eq!(parsed.dnd.reorder_stroke_width, 4.0);
+}
+
+#[test]
+fn invalid_theme_is_an_error() {
+    assert!(matches!(
+        Theme::from_toml("[dnd]\npayload_opacity = \"opaque\""),
+        Err(ThemeError::Toml(_)),
+    ));
+    assert!(matches!(
+        Theme::load("does/not/exist.toml"),
+        Err(ThemeError::Io(_)),
+    ));
+}
@@ -0,0 +1,213 @@
+//! Interaction tests for [`hcegui::util`].
+
+#![cfg(feature = "egui_0_34")]
+
+use std::time::Duration;
+
+use egui::Key;
+use egui_kittest::Harness;
+use egui_kittest::kittest::Queryable;
+use hcegui::util::{ConfirmButton, ListNav, SavedLayout};
+
+/// Shows a [`ConfirmButton`] with a 2-second timeout and a label to click
+/// elsewhere. The state counts confirmed clicks.
+fn confirm_button_harness() -> Harness<'static, u32> {
+    Harness::new_ui_state(
+        |ui, clicks: &mut u32| {
+            let button = ConfirmButton::new("Delete")
+                .confirm_text("Really delete?")
+                .timeout(Duration::from_secs(2));
+            if ui.add(button).clicked() {
+                *clicks += 1;
+            }
+            ui.label("Elsewhere");
+        },
+        0,
+    )
+}
+
+/// Clicks the widget labeled `label` and steps until the button shows the
+/// result. This takes 4 steps: one each to move, press, and release the
+/// pointer, and one more to show the result.
+fn click(harness: &mut Harness<'_, u32>, label: &str) {
+    harness.get_by_label(label).click();
+    harness.step();
+    harness.step();
+}
+
+#[test]
+fn confirm_button_second_click() {
+    let mut harness = confirm_button_harness();
+    click(&mut harness, "Delete");
+    assert_eq!(*harness.state(), 0);
+    assert!(harness.query_by_label("Delete").is_none());
+
+    click(&mut harness, "Really delete?");
+    assert_eq!(*harness.state(), 1);
+    assert!(harness.query_by_label("Delete").is_some());
+}
+
+#[test]
+fn confirm_button_timeout() {
+    let mut harness = confirm_button_harness();
+    click(&mut harness, "Delete");
+    assert!(harness.query_by_label("Really delete?").is_some());
+
+    // Each step is 0.25 seconds.
+    for _ in 0..8 {
+        harness.step();
+    }
+    click(&mut harness, "Delete");
+    assert_eq!(
+        *harness.state(),
+        0,
+        "click after timeout should only re-arm"
+    );
+    assert!(harness.query_by_label("Really delete?").is_some());
+}
+
+#[test]
+fn confirm_button_click_elsewhere() {
+    let mut harness = confirm_button_harness();
+    click(&mut harness, "Delete");
+
+    click(&mut harness, "Elsewhere");
+    assert!(harness.query_by_label("Delete").is_some());
+
+    click(&mut harness, "Delete");
+    assert_eq!(*harness.state(), 0, "click after reset should only re-arm");
+}
+
+const FRUITS: [&str; 8] = [
+    "apple",
+    "banana",
+    "blackberry",
+    "blueberry",
+    "cherry",
+    "date",
+    "elderberry",
+    "fig",
+];
+
+/// Shows a focused list of [`FRUITS`] with [`ListNav`] and a page size of 3.
+/// The state is the highlighted index.
+fn list_nav_harness(highlighted: Option<usize>) -> Harness<'static, Option<usize>> {
+    Harness::new_ui_state(
+        |ui, highlighted: &mut Option<usize>| {
+            let r = ui.vertical(|ui| {
+                for (i, fruit) in FRUITS.iter().enumerate() {
+                    if ui
+                        .selectable_label(*highlighted == Some(i), *fruit)
+                        .clicked()
+                    {
+                        *highlighted = Some(i);
+                    }
+                }
+            });
+            let sense = egui::Sense::focusable_noninteractive();
+            let list_response = ui.interact(r.response.rect, egui::Id::new("list"), sense);
+            list_response.request_focus();
+            let item_text = |i: usize| FRUITS[i].to_owned();
+            ListNav::new().page_size(3).show(
+                ui,
+                &list_response,
+                highlighted,
+                FRUITS.len(),
+                item_text,
+            );
+        },
+        highlighted,
+    )
+}
+
+/// Presses `key` and returns the highlighted index afterwards.
+fn press(harness: &mut Harness<'_, Option<usize>>, key: Key) -> Option<usize> {
+    harness.key_press(key);
+    harness.run();
+    *harness.state()
+}
+
+/// Types `text` and returns the highlighted index afterwards.
+fn type_text(harness: &mut Harness<'_, Option<usize>>, text: &str) -> Option<usize> {
+    harness.event(egui::Event::Text(text.to_owned()));
+    harness.run();
+    *harness.state()
+}
+
+/// Waits long enough for the next typed text to start a new type-ahead search.
+fn wait_for_type_ahead_timeout(harness: &mut Harness<'_, Option<usize>>) {
+    // Each step is 0.25 seconds.
+    for _ in 0..5 {
+        harness.step();
+    }
+}
+
+#[test]
+fn list_nav_arrow_keys() {
+    let mut harness = list_nav_harness(None);
+    assert_eq!(press(&mut harness, Key::ArrowDown), Some(0));
+    assert_eq!(press(&mut harness, Key::ArrowDown), Some(1));
+    assert_eq!(press(&mut harness, Key::ArrowUp), Some(0));
+    assert_eq!(press(&mut harness, Key::ArrowUp), Some(0));
+
+    let mut harness = list_nav_harness(None);
+    assert_eq!(press(&mut harness, Key::ArrowUp), Some(7));
+    assert_eq!(press(&mut harness, Key::ArrowDown), Some(7));
+}
+
+#[test]
+fn list_nav_home_end_page() {
+    let mut harness = list_nav_harness(Some(2));
+    assert_eq!(press(&mut harness, Key::End), Some(7));
+    assert_eq!(press(&mut harness, Key::PageUp), Some(4));
+    assert_eq!(press(&mut harness, Key::PageUp), Some(1));
+    assert_eq!(press(&mut harness, Key::PageUp), Some(0));
+    assert_eq!(press(&mut harness, Key::PageDown), Some(3));
+    assert_eq!(press(&mut harness, Key::PageDown), Some(6));
+    assert_eq!(press(&mut harness, Key::PageDown), Some(7));
+    assert_eq!(press(&mut harness, Key::Home), Some(0));
+}
+
+#[test]
+fn list_nav_type_ahead_cycles() {
+    let mut harness = list_nav_harness(Some(0));
+    assert_eq!(type_text(&mut harness, "b"), Some(1));
+    wait_for_type_ahead_timeout(&mut harness);
+    assert_eq!(type_text(&mut harness, "b"), Some(2));
+    wait_for_type_ahead_timeout(&mut harness);
+    assert_eq!(type_text(&mut harness, "b"), Some(3));
+    wait_for_type_ahead_timeout(&mut harness);
+    assert_eq!(type_text(&mut harness, "B"), Some(1), "should wrap around");
+    wait_for_type_ahead_timeout(&mut harness);
+    assert_eq!(type_text(&mut harness, "z"), Some(1), "no match");
+}
+
+#[test]
+fn list_nav_type_ahead_prefix() {
+    let mut harness = list_nav_harness(Some(0));
+    assert_eq!(type_text(&mut harness, "b"), Some(1));
+    assert_eq!(type_text(&mut harness, "l"), Some(2));
+    assert_eq!(type_text(&mut harness, "u"), Some(3));
+    wait_for_type_ahead_timeout(&mut harness);
+    assert_eq!(type_text(&mut harness, "e"), Some(6));
+}
+
+#[test]
+fn saved_layout_eq_ignores_shown_windows() {
+    let mut harness = Harness::new_ui_state(
+        |ui, layout: &mut SavedLayout| {
+            let mut open = true;
+            let window = egui::Window::new("Tools");
+            layout.show_window(ui.ctx(), "tools", &mut open, window, |ui| ui.label("Hi"));
+        },
+        SavedLayout::new(),
+    );
+    harness.run();
+
+    let shown = harness.state().clone();
+    let mut loaded = SavedLayout::new();
+    loaded.windows = shown.windows.clone();
+    assert_eq!(shown, loaded);
+    loaded.windows.clear();
+    assert_ne!(shown, loaded);
+}